@@ -1,15 +1,26 @@
 //! Error handling.
 
-use crate::uom::si::f64::Ratio;
+use crate::io::{FluidParam, FluidTrivialParam, HumidAirParam, Phase, ReferenceState};
+use crate::substance::BinaryMixKind;
+use crate::uom::si::f64::{Ratio, ThermodynamicTemperature};
 use crate::uom::si::ratio::percent;
 use thiserror::Error;
 
 /// CoolProp internal error.
-#[derive(Error, Debug, Clone)]
-#[error("{0}")]
-pub struct CoolPropError(pub(crate) String);
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum CoolPropError {
+    /// CoolProp failed to perform the calculation
+    /// _(contains the message reported by CoolProp)_.
+    #[error("{0}")]
+    CalculationFailed(String),
+
+    /// CoolProp returned a non-finite result, i.e.,
+    /// the requested output is not available for the current state.
+    #[error("{0}")]
+    UnavailableOutput(String),
+}
 
-/// Error during creation of [`BinaryMix`](crate::substance::BinaryMix).
+/// Error during creation or modification of [`BinaryMix`](crate::substance::BinaryMix).
 #[derive(Error, Debug, Clone, PartialEq)]
 pub enum BinaryMixError {
     /// Specified fraction is invalid.
@@ -27,9 +38,13 @@ pub enum BinaryMixError {
         /// Maximum possible value.
         max: Ratio,
     },
+
+    /// The substance is not a binary mixture.
+    #[error("Fraction can be set only for binary mixtures, but the substance is {0}!")]
+    NotBinaryMix(String),
 }
 
-/// Error during creation of [`CustomMix`](crate::substance::CustomMix).
+/// Error during creation or modification of [`CustomMix`](crate::substance::CustomMix).
 #[derive(Error, Debug, Clone, Eq, PartialEq)]
 pub enum CustomMixError {
     /// The specified components are not enough.
@@ -47,4 +62,180 @@ pub enum CustomMixError {
     /// The sum of the specified fractions is invalid.
     #[error("The sum of the specified fractions must be equal to 100 %!")]
     InvalidFractionsSum,
+
+    /// The specified tolerance of the fractions sum is invalid.
+    #[error("The tolerance of the fractions sum must be positive and finite!")]
+    InvalidTolerance,
+
+    /// The number of the specified fractions doesn't match the number of components.
+    #[error("Expected {expected} fractions (one per component), but {specified} were specified!")]
+    FractionsCountMismatch {
+        /// Number of components.
+        expected: usize,
+        /// Number of the specified fractions.
+        specified: usize,
+    },
+
+    /// The substance is not a custom mixture.
+    #[error("Fractions can be set only for custom mixtures, but the substance is {0}!")]
+    NotCustomMix(String),
+
+    /// The overridden molar mass of the component is invalid.
+    #[error("Molar mass must be positive and finite!")]
+    InvalidMolarMass,
+
+    /// The same component is specified more than once
+    /// _(e.g., with different overridden molar masses)_.
+    #[error("Component `{0}` is specified more than once!")]
+    DuplicateComponent(String),
+}
+
+/// Error during parsing of [`Substance`](crate::substance::Substance).
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum SubstanceError {
+    /// There is no substance with the specified name.
+    #[error("Substance `{0}` is not found!")]
+    NotFound(String),
+
+    /// The specified binary mixture requires a fraction.
+    #[error(
+        "Binary mixture `{0:?}` requires a fraction! \
+        Use `Fluid::binary` or the `{0:?}[fraction]` notation instead."
+    )]
+    FractionRequired(BinaryMixKind),
+}
+
+/// Error during conversion of two inputs
+/// to [`FluidInputPair`](crate::io::FluidInputPair).
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+pub enum FluidInputPairError {
+    /// The same input parameter is specified twice.
+    #[error("Specified inputs have the same key `{0:?}`!")]
+    DuplicateKey(FluidParam),
+
+    /// The specified input parameters can't be used together.
+    #[error("Specified input pair (`{0:?}`, `{1:?}`) is not supported!")]
+    Unsupported(FluidParam, FluidParam),
+}
+
+/// Error during [`Fluid::update`](crate::fluid::Fluid::update).
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FluidStateError {
+    /// The specified inputs can't be used together
+    /// or aren't supported for the substance.
+    #[error("Specified inputs (`{0:?}`, `{1:?}`) are invalid!")]
+    InvalidInputPair(FluidParam, FluidParam),
+
+    /// Specified input value is implausible for its key
+    /// _(e.g., negative pressure, quality outside `[0; 1]`
+    /// or temperature above the maximum one of the substance)_.
+    #[error("Specified input value `{1:?}` of `{0:?}` is invalid!")]
+    InvalidInput(FluidParam, f64),
+
+    /// Specified input value is not finite _(`NaN` or infinite)_.
+    #[error("Specified input value of `{0:?}` is not finite!")]
+    NonFiniteInput(FluidParam),
+
+    /// The specified inputs are not enough to define the thermodynamic state.
+    #[error("At least 2 inputs must be provided!")]
+    NotEnoughInputs,
+
+    /// Specified temperature is above the critical temperature,
+    /// so there is no saturation state.
+    #[error(
+        "Specified temperature ({:?} K) is above the critical temperature ({:?} K)!",
+        .specified.value,
+        .critical.value
+    )]
+    SupercriticalTemperature {
+        /// Specified temperature.
+        specified: ThermodynamicTemperature,
+        /// Critical temperature.
+        critical: ThermodynamicTemperature,
+    },
+
+    /// Specified reference state can't be set for the substance.
+    #[error("Specified reference state `{0:?}` is not supported for the substance!")]
+    UnsupportedReferenceState(ReferenceState),
+
+    /// Failed to update the thermodynamic state.
+    #[error("Failed to update the fluid state due to unsupported inputs or invalid state! {0}")]
+    UpdateFailed(CoolPropError),
+
+    /// Failed to update the thermodynamic state in all attempts
+    /// _(contains the imposed phase state and the error of each attempt)_.
+    #[error("Failed to update the fluid state in all attempts! {}", format_attempts(.0))]
+    AllAttemptsFailed(Vec<(Phase, CoolPropError)>),
+
+    /// The requested state is not supported for the substance
+    /// _(e.g., saturation states of incompressible substances)_.
+    #[error("Requested state is not supported for the substance `{0}`!")]
+    UnsupportedSubstance(String),
+
+    /// Output of the thermodynamic state is not available
+    /// _(e.g., the one required to define the new state
+    /// or requested for each state in [`Fluid::update_many`](crate::fluid::Fluid::update_many))_.
+    #[error("Required output of the thermodynamic state is not available! {0}")]
+    UnavailableOutput(FluidOutputError),
+}
+
+fn format_attempts(attempts: &[(Phase, CoolPropError)]) -> String {
+    attempts
+        .iter()
+        .map(|(phase, error)| format!("[{phase}] {error}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Error during calculation of the [`Fluid`](crate::fluid::Fluid) output parameter value.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FluidOutputError {
+    /// Specified output parameter is not available.
+    #[error("Specified output parameter `{0:?}` is not available!")]
+    UnavailableOutput(FluidParam),
+
+    /// Specified trivial output parameter is not available.
+    #[error("Specified trivial output parameter `{0:?}` is not available!")]
+    UnavailableTrivialOutput(FluidTrivialParam),
+
+    /// Specified parameter can't be used for the partial derivative calculation.
+    #[error("Specified parameter `{0:?}` is not differentiable!")]
+    NonDifferentiableParam(FluidParam),
+
+    /// Failed to calculate the output parameter value.
+    #[error("Failed to calculate the output value of `{0:?}`! {1}")]
+    CalculationFailed(FluidParam, CoolPropError),
+
+    /// Failed to calculate the trivial output parameter value.
+    #[error("Failed to calculate the trivial output value of `{0:?}`! {1}")]
+    TrivialCalculationFailed(FluidTrivialParam, CoolPropError),
+
+    /// Failed to obtain the mole fractions of the mixture components
+    /// _(e.g., if the molar mass of any component is not available)_.
+    #[error("Failed to obtain the mole fractions of the mixture! {0}")]
+    UnavailableMoleFractions(CoolPropError),
+}
+
+/// Error during [`HumidAir::update`](crate::humid_air::HumidAir::update).
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum HumidAirStateError {
+    /// The specified inputs can't be used together
+    /// _(e.g., duplicated keys or no pressure among them)_.
+    #[error("Specified inputs (`{0:?}`, `{1:?}`, `{2:?}`) are invalid!")]
+    InvalidInputs(HumidAirParam, HumidAirParam, HumidAirParam),
+
+    /// Failed to update the thermodynamic state.
+    #[error(
+        "Failed to update the humid air state due to unsupported inputs or invalid state! {0}"
+    )]
+    UpdateFailed(CoolPropError),
+}
+
+/// Error during calculation of the [`HumidAir`](crate::humid_air::HumidAir)
+/// output parameter value.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum HumidAirOutputError {
+    /// Failed to calculate the output parameter value.
+    #[error("Failed to calculate the output value of `{0:?}`! {1}")]
+    CalculationFailed(HumidAirParam, CoolPropError),
 }
@@ -1,11 +1,52 @@
-use crate::error::CoolPropError;
-use crate::io::{FluidInput, FluidInputPair, FluidParam};
+use crate::error::{CoolPropError, FluidInputPairError, FluidOutputError};
+use crate::io::{FluidInput, FluidInputPair, FluidParam, FluidTrivialParam};
 use crate::native::AbstractState;
+use crate::substance::{BackendName, Substance};
 use crate::Remember;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::hash::Hash;
 
+pub(crate) fn new_backend(substance: &Substance) -> Result<AbstractState, CoolPropError> {
+    new_custom_backend(substance, substance.backend_name())
+}
+
+pub(crate) fn new_custom_backend(
+    substance: &Substance,
+    backend_name: &str,
+) -> Result<AbstractState, CoolPropError> {
+    let mut backend = AbstractState::new(backend_name, substance.name())?;
+    match substance {
+        Substance::BinaryMix(binary_mix) => backend.set_fractions(&[binary_mix.fraction.value])?,
+        Substance::CustomMix(custom_mix) => backend.set_fractions(&custom_mix.mole_fractions()?)?,
+        _ => {}
+    }
+    Ok(backend)
+}
+
+/// Converts the CoolProp error of the output calculation into a [`FluidOutputError`]
+/// _(non-finite results are reported as unavailable outputs)_.
+pub(crate) fn output_error(key: FluidParam, error: CoolPropError) -> FluidOutputError {
+    match error {
+        CoolPropError::UnavailableOutput(_) => FluidOutputError::UnavailableOutput(key),
+        CoolPropError::CalculationFailed(_) => FluidOutputError::CalculationFailed(key, error),
+    }
+}
+
+/// Converts the CoolProp error of the trivial output calculation into a [`FluidOutputError`]
+/// _(non-finite results are reported as unavailable trivial outputs)_.
+pub(crate) fn trivial_output_error(
+    key: FluidTrivialParam,
+    error: CoolPropError,
+) -> FluidOutputError {
+    match error {
+        CoolPropError::UnavailableOutput(_) => FluidOutputError::UnavailableTrivialOutput(key),
+        CoolPropError::CalculationFailed(_) => {
+            FluidOutputError::TrivialCalculationFailed(key, error)
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub(crate) struct FluidUpdateRequest(pub FluidInputPair, pub f64, pub f64);
 
@@ -26,10 +67,10 @@ impl From<FluidUpdateRequest> for (FluidInput, FluidInput) {
 }
 
 impl TryFrom<(FluidInput, FluidInput)> for FluidUpdateRequest {
-    type Error = strum::ParseError;
+    type Error = FluidInputPairError;
 
     fn try_from(value: (FluidInput, FluidInput)) -> Result<Self, Self::Error> {
-        let key = FluidInputPair::try_from((value.0.key, value.1.key))?;
+        let key = FluidInputPair::try_from(value)?;
         let (value1, value2) =
             if <(FluidParam, FluidParam)>::from(key) == (value.0.key, value.1.key) {
                 (value.0.si_value, value.1.si_value)
@@ -0,0 +1,213 @@
+use crate::error::FluidStateError;
+use crate::fluid::common::new_custom_backend;
+use crate::fluid::Fluid;
+use crate::io::{Phase, ReferenceState};
+use crate::substance::{BackendName, Substance};
+use crate::UndefinedState;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// Configuration of the [`Fluid`] instance, which is applied before any updates.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::fluid::FluidConfig;
+/// use rfluids::io::Phase;
+/// use rfluids::substance::Pure;
+///
+/// let config = FluidConfig {
+///     phase: Some(Phase::Gas),
+///     ..FluidConfig::new(Pure::Nitrogen)
+/// };
+/// assert!(config.reference_state.is_none());
+/// ```
+///
+/// # See also
+///
+/// - [`Fluid::configured`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FluidConfig {
+    /// Substance.
+    pub substance: Substance,
+    /// Imposed phase state _(see [`Fluid::specify_phase`])_.
+    pub phase: Option<Phase>,
+    /// Reference state _(see [`Fluid::set_reference_state`])_.
+    pub reference_state: Option<ReferenceState>,
+    /// CoolProp backend name _(e.g., `"PR"` or `"BICUBIC&HEOS"`)_
+    /// instead of the [default one](BackendName::backend_name) for the substance.
+    pub backend_name: Option<String>,
+}
+
+impl FluidConfig {
+    /// Creates and returns a new [`FluidConfig`] instance
+    /// with the specified substance and default settings.
+    ///
+    /// # Args
+    ///
+    /// - `substance` -- substance.
+    pub fn new(substance: impl Into<Substance>) -> Self {
+        Self {
+            substance: substance.into(),
+            phase: None,
+            reference_state: None,
+            backend_name: None,
+        }
+    }
+}
+
+impl Fluid<UndefinedState> {
+    /// Creates and returns a new [`Fluid`] instance
+    /// with [`UndefinedState`] type parameter and the specified configuration.
+    ///
+    /// # Args
+    ///
+    /// - `config` -- configuration.
+    ///
+    /// # Errors
+    ///
+    /// If the specified backend or reference state is not supported for the substance,
+    /// a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::{Fluid, FluidConfig};
+    /// use rfluids::io::{FluidInput, Phase};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let nitrogen = Fluid::configured(FluidConfig {
+    ///     phase: Some(Phase::Gas),
+    ///     backend_name: Some("PR".into()),
+    ///     ..FluidConfig::new(Pure::Nitrogen)
+    /// })
+    /// .unwrap();
+    /// assert_eq!(nitrogen.imposed_phase(), Some(Phase::Gas));
+    /// let result = nitrogen.update(
+    ///     FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///     FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    /// );
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn configured(config: FluidConfig) -> Result<Self, FluidStateError> {
+        let backend_name = config
+            .backend_name
+            .as_deref()
+            .unwrap_or(config.substance.backend_name());
+        let backend = new_custom_backend(&config.substance, backend_name)
+            .map_err(FluidStateError::UpdateFailed)?;
+        let mut fluid = Fluid {
+            substance: config.substance,
+            backend,
+            update_request: None,
+            imposed_phase: None,
+            trivial_outputs: HashMap::new(),
+            outputs: HashMap::new(),
+            state: PhantomData,
+        };
+        if let Some(reference) = config.reference_state {
+            fluid.set_reference_state(reference)?;
+        }
+        if let Some(phase) = config.phase {
+            fluid.specify_phase(phase);
+        }
+        Ok(fluid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{FluidInput, FluidParam};
+    use crate::native::ReferenceStateGuard;
+    use crate::substance::{IncompPure, Pure, Refrigerant};
+    use crate::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+    use crate::uom::si::pressure::atmosphere;
+    use crate::uom::si::ratio::ratio;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn new_returns_default_settings() {
+        let result = FluidConfig::new(Pure::Water);
+        assert_eq!(result.substance, Pure::Water.into());
+        assert!(result.phase.is_none());
+        assert!(result.reference_state.is_none());
+        assert!(result.backend_name.is_none());
+    }
+
+    #[test]
+    fn configured_applies_all_settings() {
+        let _guard = ReferenceStateGuard::new("R152a");
+        let sut = Fluid::configured(FluidConfig {
+            phase: Some(Phase::TwoPhase),
+            reference_state: Some(ReferenceState::IIR),
+            ..FluidConfig::new(Refrigerant::R152a)
+        })
+        .unwrap();
+        assert_eq!(sut.imposed_phase(), Some(Phase::TwoPhase));
+        let mut result = sut
+            .update(
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(0.0)),
+                FluidInput::quality(Ratio::new::<ratio>(0.0)),
+            )
+            .unwrap();
+        assert_eq!(result.phase().unwrap(), Phase::TwoPhase);
+        assert_relative_eq!(
+            result.output(FluidParam::HMass).unwrap(),
+            200e3,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn configured_with_custom_backend_uses_it() {
+        let mut sut = Fluid::configured(FluidConfig {
+            backend_name: Some("PR".into()),
+            ..FluidConfig::new(Pure::Nitrogen)
+        })
+        .unwrap()
+        .update(
+            FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+        )
+        .unwrap();
+        let mut reference = Fluid::from(Pure::Nitrogen)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        let result = sut.density().unwrap();
+        assert_ne!(result, reference.density().unwrap());
+        assert_relative_eq!(
+            result.value,
+            reference.density().unwrap().value,
+            max_relative = 1e-2
+        );
+    }
+
+    #[test]
+    fn configured_invalid_backend_returns_err() {
+        let result = Fluid::configured(FluidConfig {
+            backend_name: Some("Hello, World!".into()),
+            ..FluidConfig::new(Pure::Water)
+        });
+        assert!(matches!(result, Err(FluidStateError::UpdateFailed(_))));
+    }
+
+    #[test]
+    fn configured_unsupported_reference_state_returns_err() {
+        let result = Fluid::configured(FluidConfig {
+            reference_state: Some(ReferenceState::IIR),
+            ..FluidConfig::new(IncompPure::Water)
+        });
+        assert_eq!(
+            result.unwrap_err(),
+            FluidStateError::UnsupportedReferenceState(ReferenceState::IIR)
+        );
+    }
+}
@@ -0,0 +1,2019 @@
+use crate::error::{FluidOutputError, FluidStateError};
+use crate::fluid::common::{output_error, FluidUpdateRequest};
+use crate::fluid::{Fluid, FluidState};
+use crate::io::{FluidInput, FluidParam, Phase};
+use crate::substance::Substance;
+use crate::uom::si::available_energy::joule_per_kilogram;
+use crate::uom::si::diffusion_coefficient::square_meter_per_second;
+use crate::uom::si::dynamic_viscosity::pascal_second;
+use crate::uom::si::f64::{
+    AvailableEnergy, DiffusionCoefficient, DynamicViscosity, Length, MassDensity,
+    MolarConcentration, MolarEnergy, MolarHeatCapacity, Pressure, RadiantExposure, Ratio,
+    SpecificHeatCapacity, SpecificVolume, TemperatureCoefficient, ThermalConductivity,
+    ThermodynamicTemperature, Velocity,
+};
+use crate::uom::si::mass_density::kilogram_per_cubic_meter;
+use crate::uom::si::molar_concentration::mole_per_cubic_meter;
+use crate::uom::si::molar_energy::joule_per_mole;
+use crate::uom::si::molar_heat_capacity::joule_per_kelvin_mole;
+use crate::uom::si::pressure::pascal;
+use crate::uom::si::radiant_exposure::joule_per_square_meter;
+use crate::uom::si::ratio::ratio;
+use crate::uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use crate::uom::si::specific_volume::cubic_meter_per_kilogram;
+use crate::uom::si::temperature_coefficient::per_kelvin;
+use crate::uom::si::thermal_conductivity::watt_per_meter_kelvin;
+use crate::uom::si::thermodynamic_temperature::kelvin;
+use crate::uom::si::velocity::meter_per_second;
+use crate::{DefinedState, Remember, UndefinedState};
+use std::collections::HashMap;
+
+impl Fluid<DefinedState> {
+    /// Output parameter value _(in SI units)_.
+    ///
+    /// # Args
+    ///
+    /// - `key` -- output parameter key.
+    ///
+    /// # Errors
+    ///
+    /// - If the calculation result is not finite _(i.e., the specified output
+    ///   is not available for the substance or its current thermodynamic state)_,
+    ///   a [`FluidOutputError::UnavailableOutput`] is returned.
+    /// - If CoolProp reports an error during the calculation,
+    ///   a [`FluidOutputError::CalculationFailed`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, Ratio};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::quality(Ratio::new::<percent>(100.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_relative_eq!(water.output(FluidParam::CpMass).unwrap(), 2079.937085633241);
+    /// ```
+    pub fn output(&mut self, key: FluidParam) -> Result<f64, FluidOutputError> {
+        self.ensure_defined(key)?;
+        self.outputs
+            .remember(&self.backend, key)
+            .map_err(|e| output_error(key, e))
+    }
+
+    /// Specified output parameters values _(in SI units)_.
+    ///
+    /// Each value is calculated via [`Fluid::output`] _(so it's cached)_,
+    /// and outputs that are not available for the substance
+    /// or its current thermodynamic state are omitted.
+    ///
+    /// # Args
+    ///
+    /// - `keys` -- output parameters keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::IncompPure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(IncompPure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let result = water.all_outputs(&[FluidParam::DMass, FluidParam::Z]);
+    /// assert!(result.contains_key(&FluidParam::DMass));
+    /// assert!(!result.contains_key(&FluidParam::Z));
+    /// ```
+    pub fn all_outputs(&mut self, keys: &[FluidParam]) -> HashMap<FluidParam, f64> {
+        keys.iter()
+            .filter_map(|&key| self.output(key).ok().map(|value| (key, value)))
+            .collect()
+    }
+
+    /// First partial derivative value _(in SI units)_.
+    ///
+    /// # Args
+    ///
+    /// - `of` -- key of the parameter being differentiated.
+    /// - `wrt` -- key of the parameter with respect to which the derivative is taken.
+    /// - `at_constant` -- key of the parameter held constant.
+    ///
+    /// # Errors
+    ///
+    /// If any of the specified parameters is not differentiable
+    /// _(e.g., transport properties or [`Phase`](FluidParam::Phase))_,
+    /// or the calculation fails for the current thermodynamic state,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// To calculate the Joule-Thomson coefficient _(∂T/∂P at constant H)_
+    /// of water vapor at _1 atm_ and _200 °C_:
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(200.0)),
+    ///     )
+    ///     .unwrap();
+    /// let result = water
+    ///     .partial_deriv(FluidParam::T, FluidParam::P, FluidParam::HMass)
+    ///     .unwrap();
+    /// assert!(result > 0.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Partial derivatives](https://coolprop.github.io/CoolProp/coolprop/LowLevelAPI.html#partial-derivatives)
+    pub fn partial_deriv(
+        &mut self,
+        of: FluidParam,
+        wrt: FluidParam,
+        at_constant: FluidParam,
+    ) -> Result<f64, FluidOutputError> {
+        self.ensure_defined(of)?;
+        for key in [of, wrt, at_constant] {
+            if !Self::is_differentiable(key) {
+                return Err(FluidOutputError::NonDifferentiableParam(key));
+            }
+        }
+        self.backend
+            .first_partial_deriv(of, wrt, at_constant)
+            .map_err(|e| FluidOutputError::CalculationFailed(of, e))
+    }
+
+    /// Second partial derivative value _(in SI units)_.
+    ///
+    /// # Args
+    ///
+    /// - `of` -- key of the parameter being differentiated.
+    /// - `wrt1` -- key of the parameter with respect to which
+    ///   the first derivative is taken.
+    /// - `constant1` -- key of the parameter held constant in the first derivative.
+    /// - `wrt2` -- key of the parameter with respect to which
+    ///   the second derivative is taken.
+    /// - `constant2` -- key of the parameter held constant in the second derivative.
+    ///
+    /// # Errors
+    ///
+    /// If any of the specified parameters is not differentiable
+    /// _(e.g., transport properties or [`Phase`](FluidParam::Phase))_,
+    /// or the calculation fails for the current thermodynamic state,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// To calculate the curvature of the isotherm _(∂²P/∂ρ² at constant T)_
+    /// of water vapor at _1 atm_ and _200 °C_:
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(200.0)),
+    ///     )
+    ///     .unwrap();
+    /// let result = water
+    ///     .second_partial_deriv(
+    ///         FluidParam::P,
+    ///         FluidParam::DMass,
+    ///         FluidParam::T,
+    ///         FluidParam::DMass,
+    ///         FluidParam::T,
+    ///     )
+    ///     .unwrap();
+    /// assert!(result < 0.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Partial derivatives](https://coolprop.github.io/CoolProp/coolprop/LowLevelAPI.html#partial-derivatives)
+    pub fn second_partial_deriv(
+        &mut self,
+        of: FluidParam,
+        wrt1: FluidParam,
+        constant1: FluidParam,
+        wrt2: FluidParam,
+        constant2: FluidParam,
+    ) -> Result<f64, FluidOutputError> {
+        self.ensure_defined(of)?;
+        for key in [of, wrt1, constant1, wrt2, constant2] {
+            if !Self::is_differentiable(key) {
+                return Err(FluidOutputError::NonDifferentiableParam(key));
+            }
+        }
+        self.backend
+            .second_partial_deriv(of, wrt1, constant1, wrt2, constant2)
+            .map_err(|e| FluidOutputError::CalculationFailed(of, e))
+    }
+
+    /// First derivative along the saturation curve _(in SI units)_.
+    ///
+    /// # Args
+    ///
+    /// - `of` -- key of the parameter being differentiated.
+    /// - `wrt` -- key of the parameter with respect to which the derivative is taken.
+    ///
+    /// # Errors
+    ///
+    /// If any of the specified parameters is not differentiable
+    /// _(e.g., transport properties or [`Phase`](FluidParam::Phase))_,
+    /// the current thermodynamic state is not saturated
+    /// _(i.e., its phase is not [`TwoPhase`](Phase::TwoPhase))_,
+    /// or the calculation fails, a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// To calculate the slope of the saturation curve _(dP/dT)_
+    /// of water at _1 atm_:
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, Ratio};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::quality(Ratio::new::<percent>(0.0)),
+    ///     )
+    ///     .unwrap();
+    /// let result = water
+    ///     .saturation_deriv(FluidParam::P, FluidParam::T)
+    ///     .unwrap();
+    /// assert!(result > 0.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Partial derivatives](https://coolprop.github.io/CoolProp/coolprop/LowLevelAPI.html#partial-derivatives)
+    pub fn saturation_deriv(
+        &mut self,
+        of: FluidParam,
+        wrt: FluidParam,
+    ) -> Result<f64, FluidOutputError> {
+        self.ensure_defined(of)?;
+        for key in [of, wrt] {
+            if !Self::is_differentiable(key) {
+                return Err(FluidOutputError::NonDifferentiableParam(key));
+            }
+        }
+        if self.phase()? != Phase::TwoPhase {
+            return Err(FluidOutputError::UnavailableOutput(of));
+        }
+        self.backend
+            .first_saturation_deriv(of, wrt)
+            .map_err(|e| FluidOutputError::CalculationFailed(of, e))
+    }
+
+    /// Phase state.
+    ///
+    /// It's queried directly from the backend;
+    /// if the backend doesn't support it, the keyed output is used instead.
+    pub fn phase(&mut self) -> Result<Phase, FluidOutputError> {
+        if !self.outputs.contains_key(&FluidParam::Phase) {
+            if let Ok(phase) = self.backend.phase() {
+                self.outputs.insert(FluidParam::Phase, phase.into());
+            }
+        }
+        Phase::try_from(self.output(FluidParam::Phase)?)
+            .map_err(|_| FluidOutputError::UnavailableOutput(FluidParam::Phase))
+    }
+
+    /// Returns `true` if the phase state is [`Liquid`](Phase::Liquid)
+    /// _(`false` if it's not available)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert!(water.is_liquid());
+    /// assert!(!water.is_gas());
+    /// assert!(!water.is_two_phase());
+    /// assert!(!water.is_supercritical());
+    /// ```
+    pub fn is_liquid(&mut self) -> bool {
+        self.phase().is_ok_and(|phase| phase == Phase::Liquid)
+    }
+
+    /// Returns `true` if the phase state is [`Gas`](Phase::Gas)
+    /// _(`false` if it's not available)_.
+    pub fn is_gas(&mut self) -> bool {
+        self.phase().is_ok_and(|phase| phase == Phase::Gas)
+    }
+
+    /// Returns `true` if the phase state is [`TwoPhase`](Phase::TwoPhase)
+    /// _(`false` if it's not available)_.
+    pub fn is_two_phase(&mut self) -> bool {
+        self.phase().is_ok_and(|phase| phase == Phase::TwoPhase)
+    }
+
+    /// Returns `true` if the phase state is [`Supercritical`](Phase::Supercritical),
+    /// [`SupercriticalGas`](Phase::SupercriticalGas)
+    /// or [`SupercriticalLiquid`](Phase::SupercriticalLiquid)
+    /// _(`false` if it's not available)_.
+    pub fn is_supercritical(&mut self) -> bool {
+        self.phase().is_ok_and(|phase| {
+            matches!(
+                phase,
+                Phase::Supercritical | Phase::SupercriticalGas | Phase::SupercriticalLiquid
+            )
+        })
+    }
+
+    /// Output parameter value _(in SI units)_ along with the phase state
+    /// for the same thermodynamic state.
+    ///
+    /// If any of them is not cached yet, both are read from the backend
+    /// in a single call _(see
+    /// [`AbstractState::keyed_output_and_phase`](crate::native::AbstractState::keyed_output_and_phase))_,
+    /// so they always correspond to the same thermodynamic state, and then cached.
+    ///
+    /// # Args
+    ///
+    /// - `key` -- output parameter key.
+    ///
+    /// # Errors
+    ///
+    /// If the specified output or phase state is not available for the substance
+    /// or its current thermodynamic state, a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam, Phase};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let (density, phase) = water.output_and_phase(FluidParam::DMass).unwrap();
+    /// assert_relative_eq!(density, 998.2071504679284, max_relative = 1e-9);
+    /// assert_eq!(phase, Phase::Liquid);
+    /// ```
+    pub fn output_and_phase(&mut self, key: FluidParam) -> Result<(f64, Phase), FluidOutputError> {
+        self.ensure_defined(key)?;
+        if !self.outputs.contains_key(&key) || !self.outputs.contains_key(&FluidParam::Phase) {
+            if let Ok((value, phase)) = self.backend.keyed_output_and_phase(key) {
+                self.outputs.insert(key, value);
+                self.outputs.insert(FluidParam::Phase, phase.into());
+            }
+        }
+        Ok((self.output(key)?, self.phase()?))
+    }
+
+    /// Snapshot of the main thermodynamic properties
+    /// _(pressure, temperature, vapor quality, mass density,
+    /// mass specific enthalpy and mass specific entropy)_
+    /// and the specified additional outputs.
+    ///
+    /// The vapor quality is the raw CoolProp value,
+    /// so it's out of the range from 0 to 1 for single-phase states.
+    /// All values are calculated via [`Fluid::output`] _(so they're cached)_.
+    ///
+    /// # Args
+    ///
+    /// - `params` -- additional output parameters keys
+    ///   _(their values are stored in [`FluidState::outputs`])_.
+    ///
+    /// # Errors
+    ///
+    /// If any of the main properties or additional outputs is not available
+    /// for the current thermodynamic state, a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::mass_density::kilogram_per_cubic_meter;
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let state = water.snapshot(&[FluidParam::CpMass]).unwrap();
+    /// assert_relative_eq!(
+    ///     state.density.get::<kilogram_per_cubic_meter>(),
+    ///     998.2071504679284,
+    ///     max_relative = 1e-9
+    /// );
+    /// assert_relative_eq!(
+    ///     state.outputs[&FluidParam::CpMass],
+    ///     4184.050924523541,
+    ///     max_relative = 1e-9
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FluidState`]
+    pub fn snapshot(&mut self, params: &[FluidParam]) -> Result<FluidState, FluidOutputError> {
+        Ok(FluidState {
+            pressure: self.pressure()?,
+            temperature: self.temperature()?,
+            quality: Ratio::new::<ratio>(self.output(FluidParam::Q)?),
+            density: self.density()?,
+            enthalpy: self.specific_enthalpy()?,
+            entropy: self.specific_entropy()?,
+            outputs: params
+                .iter()
+                .map(|&key| Ok((key, self.output(key)?)))
+                .collect::<Result<_, FluidOutputError>>()?,
+        })
+    }
+
+    /// Fingerprint of the physical state
+    /// _(64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+    /// hash of the substance, its composition, temperature and mass density)_.
+    ///
+    /// Temperature, mass density and fractions are rounded
+    /// to 8 significant digits before hashing, so identical physical states
+    /// reached via different inputs have the same fingerprint.
+    /// The hash function is fixed, so the fingerprint doesn't depend
+    /// on the Rust version or the build.
+    ///
+    /// **NB.** Rounding is not a tolerance-based comparison:
+    /// two nearly identical states whose values lie on different sides
+    /// of a rounding boundary have different fingerprints. So equal fingerprints mean the same state,
+    /// but different fingerprints don't guarantee different states.
+    ///
+    /// # Errors
+    ///
+    /// - If temperature or mass density is not available
+    ///   for the current thermodynamic state, a [`FluidOutputError`] is returned.
+    /// - If mole fractions of the custom mixture can't be obtained,
+    ///   a [`FluidOutputError::UnavailableMoleFractions`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::available_energy::joule_per_kilogram;
+    /// use rfluids::uom::si::f64::{AvailableEnergy, Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let enthalpy = water.output(FluidParam::HMass).unwrap();
+    /// let mut same_water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::enthalpy(AvailableEnergy::new::<joule_per_kilogram>(enthalpy)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(water.fingerprint(), same_water.fingerprint());
+    /// ```
+    pub fn fingerprint(&mut self) -> Result<u64, FluidOutputError> {
+        let mut fields = vec![self.substance.name().to_string()];
+        match &self.substance {
+            Substance::BinaryMix(mix) => fields.push(rounded(mix.fraction.value)),
+            Substance::CustomMix(mix) => fields.extend(
+                mix.mole_fractions()
+                    .map_err(FluidOutputError::UnavailableMoleFractions)?
+                    .into_iter()
+                    .map(rounded),
+            ),
+            _ => {}
+        }
+        fields.push(rounded(self.output(FluidParam::T)?));
+        fields.push(rounded(self.output(FluidParam::DMass)?));
+        Ok(fnv1a(fields.join("|").as_bytes()))
+    }
+
+    /// Compact one-line summary of the current thermodynamic state
+    /// _(e.g., for logging)_.
+    ///
+    /// Contains the substance name, pressure, temperature, phase state and mass density.
+    /// Any of them that can't be calculated is shown as `"n/a"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     water.describe(),
+    ///     "Water @ 101325 Pa, 293.15 K, Liquid, rho=998.2 kg/m3"
+    /// );
+    /// ```
+    pub fn describe(&mut self) -> String {
+        fn or_na<T>(value: Result<T, FluidOutputError>, f: impl FnOnce(T) -> String) -> String {
+            value.map_or_else(|_| "n/a".into(), f)
+        }
+        let pressure = or_na(self.pressure(), |p| format!("{:.0} Pa", p.value));
+        let temperature = or_na(self.temperature(), |t| format!("{:.2} K", t.value));
+        let phase = or_na(self.phase(), |p| p.to_string());
+        let density = or_na(self.density(), |d| format!("{:.1} kg/m3", d.value));
+        format!(
+            "{} @ {pressure}, {temperature}, {phase}, rho={density}",
+            self.substance.name()
+        )
+    }
+
+    /// JSON representation of the current state
+    /// with the substance name, the specified inputs and the available outputs
+    /// _(all values are in SI units, keys are CoolProp parameter names)_.
+    ///
+    /// Unavailable outputs are omitted.
+    ///
+    /// # Args
+    ///
+    /// - `keys` -- output parameters keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let result = water.to_json(&[FluidParam::DMass, FluidParam::SurfaceTension]);
+    /// assert_eq!(result["substance"], "Water");
+    /// assert_eq!(result["inputs"]["P"], 101325.0);
+    /// assert!(result["outputs"]["Dmass"].is_f64());
+    /// assert!(result["outputs"].get("surface_tension").is_none());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&mut self, keys: &[FluidParam]) -> serde_json::Value {
+        let inputs = self
+            .update_request
+            .map(|request| {
+                let (input1, input2): (FluidInput, FluidInput) = request.into();
+                serde_json::Map::from_iter(
+                    [input1, input2]
+                        .map(|input| (input.key.as_ref().to_string(), input.si_value.into())),
+                )
+            })
+            .unwrap_or_default();
+        let outputs = self
+            .all_outputs(keys)
+            .into_iter()
+            .map(|(key, value)| (key.as_ref().to_string(), value.into()))
+            .collect::<serde_json::Map<_, _>>();
+        serde_json::json!({
+            "substance": self.substance.name(),
+            "inputs": inputs,
+            "outputs": outputs,
+        })
+    }
+
+    /// Pressure _(key: [`P`](FluidParam::P))_.
+    pub fn pressure(&mut self) -> Result<Pressure, FluidOutputError> {
+        Ok(Pressure::new::<pascal>(self.output(FluidParam::P)?))
+    }
+
+    /// Temperature _(key: [`T`](FluidParam::T))_.
+    pub fn temperature(&mut self) -> Result<ThermodynamicTemperature, FluidOutputError> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            self.output(FluidParam::T)?,
+        ))
+    }
+
+    /// Vapor quality _(key: [`Q`](FluidParam::Q))_.
+    ///
+    /// Returns [`None`] outside the two-phase region
+    /// _(CoolProp returns out of range values, e.g. `-1`, for single-phase states)_
+    /// or if the quality is not available for the substance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut wet_steam = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::quality(Ratio::new::<percent>(30.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_relative_eq!(wet_steam.quality().unwrap().get::<percent>(), 30.0);
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(water.quality(), None);
+    /// ```
+    pub fn quality(&mut self) -> Option<Ratio> {
+        self.output(FluidParam::Q)
+            .ok()
+            .filter(|value| (0.0..=1.0).contains(value))
+            .map(Ratio::new::<ratio>)
+    }
+
+    /// Mass density _(key: [`DMass`](FluidParam::DMass))_.
+    pub fn density(&mut self) -> Result<MassDensity, FluidOutputError> {
+        Ok(MassDensity::new::<kilogram_per_cubic_meter>(
+            self.output(FluidParam::DMass)?,
+        ))
+    }
+
+    /// Specific volume _(reciprocal of the mass density)_.
+    ///
+    /// # Errors
+    ///
+    /// If the mass density is not available _(or equal to zero)_
+    /// for the current thermodynamic state, a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::specific_volume::cubic_meter_per_kilogram;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_relative_eq!(
+    ///     water.specific_volume().unwrap().get::<cubic_meter_per_kilogram>(),
+    ///     1.0 / 998.2071504679284,
+    ///     max_relative = 1e-9
+    /// );
+    /// ```
+    pub fn specific_volume(&mut self) -> Result<SpecificVolume, FluidOutputError> {
+        let density = self.output(FluidParam::DMass)?;
+        if density == 0.0 {
+            return Err(FluidOutputError::UnavailableOutput(FluidParam::DMass));
+        }
+        Ok(SpecificVolume::new::<cubic_meter_per_kilogram>(
+            1.0 / density,
+        ))
+    }
+
+    /// Mass specific enthalpy _(key: [`HMass`](FluidParam::HMass))_.
+    pub fn specific_enthalpy(&mut self) -> Result<AvailableEnergy, FluidOutputError> {
+        Ok(AvailableEnergy::new::<joule_per_kilogram>(
+            self.output(FluidParam::HMass)?,
+        ))
+    }
+
+    /// Mass specific entropy _(key: [`SMass`](FluidParam::SMass))_.
+    pub fn specific_entropy(&mut self) -> Result<SpecificHeatCapacity, FluidOutputError> {
+        Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            self.output(FluidParam::SMass)?,
+        ))
+    }
+
+    /// Mass specific internal energy _(key: [`UMass`](FluidParam::UMass))_.
+    pub fn specific_internal_energy(&mut self) -> Result<AvailableEnergy, FluidOutputError> {
+        Ok(AvailableEnergy::new::<joule_per_kilogram>(
+            self.output(FluidParam::UMass)?,
+        ))
+    }
+
+    /// Mass specific Gibbs energy _(key: [`GMass`](FluidParam::GMass))_.
+    pub fn specific_gibbs_energy(&mut self) -> Result<AvailableEnergy, FluidOutputError> {
+        Ok(AvailableEnergy::new::<joule_per_kilogram>(
+            self.output(FluidParam::GMass)?,
+        ))
+    }
+
+    /// Mass specific Helmholtz energy
+    /// _(key: [`HelmholtzMass`](FluidParam::HelmholtzMass))_.
+    pub fn specific_helmholtz_energy(&mut self) -> Result<AvailableEnergy, FluidOutputError> {
+        Ok(AvailableEnergy::new::<joule_per_kilogram>(
+            self.output(FluidParam::HelmholtzMass)?,
+        ))
+    }
+
+    /// Molar density _(key: [`DMolar`](FluidParam::DMolar))_.
+    pub fn molar_density(&mut self) -> Result<MolarConcentration, FluidOutputError> {
+        Ok(MolarConcentration::new::<mole_per_cubic_meter>(
+            self.output(FluidParam::DMolar)?,
+        ))
+    }
+
+    /// Molar specific enthalpy _(key: [`HMolar`](FluidParam::HMolar))_.
+    pub fn molar_enthalpy(&mut self) -> Result<MolarEnergy, FluidOutputError> {
+        Ok(MolarEnergy::new::<joule_per_mole>(
+            self.output(FluidParam::HMolar)?,
+        ))
+    }
+
+    /// Molar specific entropy _(key: [`SMolar`](FluidParam::SMolar))_.
+    pub fn molar_entropy(&mut self) -> Result<MolarHeatCapacity, FluidOutputError> {
+        Ok(MolarHeatCapacity::new::<joule_per_kelvin_mole>(
+            self.output(FluidParam::SMolar)?,
+        ))
+    }
+
+    /// Molar specific internal energy _(key: [`UMolar`](FluidParam::UMolar))_.
+    pub fn molar_internal_energy(&mut self) -> Result<MolarEnergy, FluidOutputError> {
+        Ok(MolarEnergy::new::<joule_per_mole>(
+            self.output(FluidParam::UMolar)?,
+        ))
+    }
+
+    /// Molar specific Gibbs energy _(key: [`GMolar`](FluidParam::GMolar))_.
+    pub fn molar_gibbs_energy(&mut self) -> Result<MolarEnergy, FluidOutputError> {
+        Ok(MolarEnergy::new::<joule_per_mole>(
+            self.output(FluidParam::GMolar)?,
+        ))
+    }
+
+    /// Molar specific Helmholtz energy
+    /// _(key: [`HelmholtzMolar`](FluidParam::HelmholtzMolar))_.
+    pub fn molar_helmholtz_energy(&mut self) -> Result<MolarEnergy, FluidOutputError> {
+        Ok(MolarEnergy::new::<joule_per_mole>(
+            self.output(FluidParam::HelmholtzMolar)?,
+        ))
+    }
+
+    /// Mass specific constant pressure specific heat
+    /// _(key: [`CpMass`](FluidParam::CpMass))_.
+    ///
+    /// **NB.** For two-phase states, CoolProp may return very large or infinite values,
+    /// since the specific heat diverges there.
+    pub fn cp(&mut self) -> Result<SpecificHeatCapacity, FluidOutputError> {
+        Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            self.output(FluidParam::CpMass)?,
+        ))
+    }
+
+    /// Mass specific constant volume specific heat
+    /// _(key: [`CvMass`](FluidParam::CvMass))_.
+    ///
+    /// **NB.** For two-phase states, CoolProp may return very large or infinite values,
+    /// since the specific heat diverges there.
+    pub fn cv(&mut self) -> Result<SpecificHeatCapacity, FluidOutputError> {
+        Ok(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+            self.output(FluidParam::CvMass)?,
+        ))
+    }
+
+    /// Speed of sound _(key: [`SoundSpeed`](FluidParam::SoundSpeed))_.
+    pub fn speed_of_sound(&mut self) -> Result<Velocity, FluidOutputError> {
+        Ok(Velocity::new::<meter_per_second>(
+            self.output(FluidParam::SoundSpeed)?,
+        ))
+    }
+
+    /// Dynamic viscosity _(key: [`DynamicViscosity`](FluidParam::DynamicViscosity))_.
+    pub fn dynamic_viscosity(&mut self) -> Result<DynamicViscosity, FluidOutputError> {
+        Ok(DynamicViscosity::new::<pascal_second>(
+            self.output(FluidParam::DynamicViscosity)?,
+        ))
+    }
+
+    /// Kinematic viscosity _(dynamic viscosity divided by mass density)_.
+    ///
+    /// **NB.** [`DiffusionCoefficient`] is used as the return type,
+    /// since it has the same dimension _(m²/s)_ and `uom` has no dedicated quantity.
+    ///
+    /// # Errors
+    ///
+    /// If either the dynamic viscosity or the mass density is not available
+    /// for the substance or its current thermodynamic state,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_relative_eq!(
+    ///     water.kinematic_viscosity().unwrap().value,
+    ///     1.0035e-6,
+    ///     max_relative = 1e-3
+    /// );
+    /// ```
+    pub fn kinematic_viscosity(&mut self) -> Result<DiffusionCoefficient, FluidOutputError> {
+        Ok(DiffusionCoefficient::new::<square_meter_per_second>(
+            self.output(FluidParam::DynamicViscosity)? / self.output(FluidParam::DMass)?,
+        ))
+    }
+
+    /// Thermal conductivity _(key: [`Conductivity`](FluidParam::Conductivity))_.
+    ///
+    /// # Errors
+    ///
+    /// If there is no thermal conductivity correlation for the substance,
+    /// a [`FluidOutputError`] is returned.
+    pub fn thermal_conductivity(&mut self) -> Result<ThermalConductivity, FluidOutputError> {
+        Ok(ThermalConductivity::new::<watt_per_meter_kelvin>(
+            self.output(FluidParam::Conductivity)?,
+        ))
+    }
+
+    /// Surface tension _(key: [`SurfaceTension`](FluidParam::SurfaceTension))_.
+    ///
+    /// **NB.** [`RadiantExposure`] is used as the return type,
+    /// since it has the same dimension _(N/m = J/m²)_ and `uom` has no dedicated quantity.
+    ///
+    /// # Errors
+    ///
+    /// CoolProp defines the surface tension only along the saturation curve,
+    /// so if the current phase state is not [`Phase::TwoPhase`]
+    /// _(or there is no surface tension correlation for the substance)_,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::error::FluidOutputError;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut saturated_water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::quality(Ratio::new::<percent>(0.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert!(saturated_water.surface_tension().is_ok());
+    ///
+    /// let mut liquid_water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     liquid_water.surface_tension(),
+    ///     Err(FluidOutputError::UnavailableOutput(FluidParam::SurfaceTension))
+    /// );
+    /// ```
+    pub fn surface_tension(&mut self) -> Result<RadiantExposure, FluidOutputError> {
+        if self.phase()? != Phase::TwoPhase {
+            return Err(FluidOutputError::UnavailableOutput(
+                FluidParam::SurfaceTension,
+            ));
+        }
+        Ok(RadiantExposure::new::<joule_per_square_meter>(
+            self.output(FluidParam::SurfaceTension)?,
+        ))
+    }
+
+    /// Prandtl number _(key: [`Prandtl`](FluidParam::Prandtl), dimensionless)_.
+    ///
+    /// # Errors
+    ///
+    /// If there is no viscosity or thermal conductivity correlation for the substance,
+    /// a [`FluidOutputError`] is returned.
+    pub fn prandtl_number(&mut self) -> Result<f64, FluidOutputError> {
+        self.output(FluidParam::Prandtl)
+    }
+
+    /// Reynolds number for the specified flow conditions
+    /// _(`rho * v * L / mu`, dimensionless)_.
+    ///
+    /// # Args
+    ///
+    /// - `velocity` -- flow velocity.
+    /// - `length` -- characteristic length _(e.g., inner diameter of the pipe)_.
+    ///
+    /// # Errors
+    ///
+    /// If either the dynamic viscosity or the mass density is not available
+    /// for the substance or its current thermodynamic state,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Length, Pressure, ThermodynamicTemperature, Velocity};
+    /// use rfluids::uom::si::length::millimeter;
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    /// use rfluids::uom::si::velocity::meter_per_second;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let result = water
+    ///     .reynolds_number(
+    ///         Velocity::new::<meter_per_second>(1.0),
+    ///         Length::new::<millimeter>(100.0),
+    ///     )
+    ///     .unwrap();
+    /// assert_relative_eq!(result, 99_650.0, max_relative = 1e-3);
+    /// ```
+    pub fn reynolds_number(
+        &mut self,
+        velocity: Velocity,
+        length: Length,
+    ) -> Result<f64, FluidOutputError> {
+        let reynolds: Ratio = self.density()? * velocity * length / self.dynamic_viscosity()?;
+        Ok(reynolds.get::<ratio>())
+    }
+
+    /// Compressibility factor _(key: [`Z`](FluidParam::Z), dimensionless)_.
+    ///
+    /// # Errors
+    ///
+    /// For incompressible substances, a [`FluidOutputError`] is returned.
+    pub fn compressibility_factor(&mut self) -> Result<f64, FluidOutputError> {
+        self.output(FluidParam::Z)
+    }
+
+    /// Isobaric expansion coefficient
+    /// _(key: [`IsobaricExpansionCoefficient`](FluidParam::IsobaricExpansionCoefficient))_.
+    ///
+    /// # Errors
+    ///
+    /// If the output is not available for the substance or its current thermodynamic state,
+    /// a [`FluidOutputError`] is returned.
+    pub fn isobaric_expansion_coefficient(
+        &mut self,
+    ) -> Result<TemperatureCoefficient, FluidOutputError> {
+        Ok(TemperatureCoefficient::new::<per_kelvin>(
+            self.output(FluidParam::IsobaricExpansionCoefficient)?,
+        ))
+    }
+
+    /// Isothermal compressibility
+    /// _(key: [`IsothermalCompressibility`](FluidParam::IsothermalCompressibility), SI units: 1/Pa)_.
+    ///
+    /// # Errors
+    ///
+    /// If the output is not available for the substance or its current thermodynamic state,
+    /// a [`FluidOutputError`] is returned.
+    pub fn isothermal_compressibility(&mut self) -> Result<f64, FluidOutputError> {
+        self.output(FluidParam::IsothermalCompressibility)
+    }
+
+    /// Isentropic exponent _(dimensionless)_,
+    /// calculated from the speed of sound as `n = a² · ρ / P`.
+    ///
+    /// Unlike the heat capacity ratio `cp / cv`, it describes the isentropic process
+    /// `P · vⁿ = const` for real gases; for ideal gases they are the same.
+    ///
+    /// # Errors
+    ///
+    /// If any of the required outputs is not available for the substance
+    /// or its current thermodynamic state, a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::kilopascal;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut air = Fluid::from(Pure::Air)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<kilopascal>(10.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_relative_eq!(air.isentropic_exponent().unwrap(), 1.4, max_relative = 1e-3);
+    /// ```
+    pub fn isentropic_exponent(&mut self) -> Result<f64, FluidOutputError> {
+        let sound_speed = self.output(FluidParam::SoundSpeed)?;
+        Ok(sound_speed.powi(2) * self.output(FluidParam::DMass)? / self.output(FluidParam::P)?)
+    }
+
+    /// Updates the thermodynamic state in place,
+    /// without changing the type parameter.
+    ///
+    /// It's useful for tight loops, since it avoids
+    /// [`reset`](Fluid::reset) followed by [`update`](Fluid::update).
+    /// Cached outputs are cleared on success.
+    ///
+    /// # Args
+    ///
+    /// - `input1` -- first input property.
+    /// - `input2` -- second input property.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`FluidStateError`] is returned
+    /// and the current thermodynamic state is restored.
+    /// If it can't be restored either, the thermodynamic state is lost:
+    /// all outputs return [`FluidOutputError::UnavailableOutput`]
+    /// until the next successful update.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, Phase};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(water.phase().unwrap(), Phase::Liquid);
+    /// water
+    ///     .update_in_place(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(120.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(water.phase().unwrap(), Phase::Gas);
+    /// ```
+    pub fn update_in_place(
+        &mut self,
+        input1: FluidInput,
+        input2: FluidInput,
+    ) -> Result<(), FluidStateError> {
+        let request = FluidUpdateRequest::try_from((input1, input2))
+            .map_err(|_| FluidStateError::InvalidInputPair(input1.key, input2.key))?;
+        if let Err(e) = self.backend.update(request.0, request.1, request.2) {
+            let restored = self.update_request.is_some_and(|previous| {
+                self.backend
+                    .update(previous.0, previous.1, previous.2)
+                    .is_ok()
+            });
+            if !restored {
+                self.outputs.clear();
+                self.update_request = None;
+            }
+            return Err(FluidStateError::UpdateFailed(e));
+        }
+        self.outputs.clear();
+        self.update_request = Some(request);
+        Ok(())
+    }
+
+    /// Resets the thermodynamic state and returns itself
+    /// with [`UndefinedState`] type parameter.
+    ///
+    /// Cached trivial outputs are kept, since they don't depend on the thermodynamic state.
+    pub fn reset(mut self) -> Fluid<UndefinedState> {
+        self.outputs.clear();
+        self.update_request = None;
+        self.into_state()
+    }
+
+    fn ensure_defined(&self, key: FluidParam) -> Result<(), FluidOutputError> {
+        match self.update_request {
+            Some(_) => Ok(()),
+            None => Err(FluidOutputError::UnavailableOutput(key)),
+        }
+    }
+
+    fn is_differentiable(key: FluidParam) -> bool {
+        matches!(
+            key,
+            FluidParam::T
+                | FluidParam::P
+                | FluidParam::Tau
+                | FluidParam::Delta
+                | FluidParam::DMolar
+                | FluidParam::HMolar
+                | FluidParam::SMolar
+                | FluidParam::CpMolar
+                | FluidParam::CvMolar
+                | FluidParam::UMolar
+                | FluidParam::GMolar
+                | FluidParam::DMass
+                | FluidParam::HMass
+                | FluidParam::SMass
+                | FluidParam::CpMass
+                | FluidParam::CvMass
+                | FluidParam::UMass
+                | FluidParam::GMass
+                | FluidParam::SoundSpeed
+        )
+    }
+}
+
+fn rounded(value: f64) -> String {
+    format!("{value:.7e}")
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{FluidInput, FluidInputPair, FluidTrivialParam};
+    use crate::substance::{BinaryMix, BinaryMixKind, IncompPure, Pure};
+    use crate::uom::si::available_energy::joule_per_kilogram;
+    use crate::uom::si::f64::AvailableEnergy;
+    use crate::uom::si::length::meter;
+    use crate::uom::si::pressure::{atmosphere, kilopascal};
+    use crate::uom::si::ratio::percent;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use approx::assert_relative_eq;
+    use rstest::*;
+
+    fn water() -> Fluid {
+        Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap()
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn to_json_returns_substance_inputs_and_available_outputs() {
+        let mut sut = water();
+        let density = sut.density().unwrap().value;
+        let result = sut.to_json(&[FluidParam::DMass, FluidParam::SurfaceTension]);
+        assert_eq!(
+            result,
+            serde_json::json!({
+                "substance": "Water",
+                "inputs": { "P": 101325.0, "T": 293.15 },
+                "outputs": { "Dmass": density },
+            })
+        );
+    }
+
+    #[test]
+    fn describe_returns_compact_summary() {
+        let mut sut = water();
+        assert_eq!(
+            sut.describe(),
+            "Water @ 101325 Pa, 293.15 K, Liquid, rho=998.2 kg/m3"
+        );
+    }
+
+    #[test]
+    fn describe_unavailable_outputs_are_shown_as_na() {
+        let mut sut = Fluid::from(IncompPure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        let result = sut.describe();
+        assert!(result.starts_with("Water @ 101325 Pa, 293.15 K, "));
+        assert!(result.contains("n/a"));
+    }
+
+    #[test]
+    fn fingerprint_of_same_state_reached_via_different_inputs_is_same() {
+        let mut sut = water();
+        let enthalpy = sut.output(FluidParam::HMass).unwrap();
+        let mut other = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::enthalpy(AvailableEnergy::new::<joule_per_kilogram>(enthalpy)),
+            )
+            .unwrap();
+        assert_eq!(sut.fingerprint(), other.fingerprint());
+    }
+
+    #[test]
+    fn fingerprint_of_different_states_is_different() {
+        let mut sut = water();
+        let mut other = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(21.0)),
+            )
+            .unwrap();
+        assert_ne!(sut.fingerprint(), other.fingerprint());
+    }
+
+    #[rstest]
+    #[case("", 0xcbf2_9ce4_8422_2325)]
+    #[case("a", 0xaf63_dc4c_8601_ec8c)]
+    #[case("foobar", 0x8594_4171_f739_67e8)]
+    fn fnv1a_returns_reference_values(#[case] input: &str, #[case] expected: u64) {
+        assert_eq!(fnv1a(input.as_bytes()), expected);
+    }
+
+    #[test]
+    fn fingerprint_of_different_compositions_is_different() {
+        let binary_mix = |fraction| {
+            Fluid::from(
+                BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(fraction)).unwrap(),
+            )
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap()
+        };
+        assert_ne!(
+            binary_mix(40.0).fingerprint(),
+            binary_mix(50.0).fingerprint()
+        );
+    }
+
+    #[test]
+    fn output_returns_cached_value() {
+        let mut sut = water();
+        let result = sut.output(FluidParam::DMass).unwrap();
+        assert_relative_eq!(result, 998.2071504679284, max_relative = 1e-9);
+        assert_eq!(sut.outputs[&FluidParam::DMass], result);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "must have a thermodynamic state")]
+    fn into_defined_state_without_state_panics_in_debug() {
+        let _ = Fluid::from(Pure::Water).into_defined_state();
+    }
+
+    #[test]
+    fn output_without_state_returns_err() {
+        let mut sut: Fluid = Fluid::from(Pure::Water).into_state();
+        assert_eq!(
+            sut.output(FluidParam::DMass),
+            Err(FluidOutputError::UnavailableOutput(FluidParam::DMass))
+        );
+    }
+
+    #[test]
+    fn output_unavailable_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        assert!(matches!(
+            sut.output(FluidParam::Z),
+            Err(FluidOutputError::CalculationFailed(FluidParam::Z, _))
+        ));
+        assert!(matches!(
+            sut.compressibility_factor(),
+            Err(FluidOutputError::CalculationFailed(FluidParam::Z, _))
+        ));
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(0.5)]
+    #[case(1.0)]
+    fn quality_of_two_phase_state_returns_some(#[case] quality: f64) {
+        let mut sut = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::quality(Ratio::new::<ratio>(quality)),
+            )
+            .unwrap();
+        assert_relative_eq!(sut.quality().unwrap().value, quality);
+    }
+
+    #[test]
+    fn quality_of_single_phase_state_returns_none() {
+        let mut sut = water();
+        assert_eq!(sut.quality(), None);
+        assert!(sut.outputs.contains_key(&FluidParam::Q));
+    }
+
+    #[test]
+    fn all_outputs_returns_cached_available_values() {
+        let mut sut = water();
+        let result = sut.all_outputs(&[FluidParam::DMass, FluidParam::HMass, FluidParam::T]);
+        assert_eq!(result.len(), 3);
+        assert_eq!(result, sut.outputs);
+        assert_relative_eq!(result[&FluidParam::T], 293.15, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn all_outputs_omits_unavailable_values() {
+        let mut sut = water();
+        let result = sut.all_outputs(&[FluidParam::DMass, FluidParam::SurfaceTension]);
+        assert_eq!(result.len(), 1);
+        assert!(result.contains_key(&FluidParam::DMass));
+    }
+
+    #[test]
+    fn output_and_phase_returns_consistent_values() {
+        let mut sut = water();
+        let (value, phase) = sut.output_and_phase(FluidParam::DMass).unwrap();
+        assert_eq!(value, sut.output(FluidParam::DMass).unwrap());
+        assert_eq!(phase, sut.phase().unwrap());
+        assert_eq!(phase, Phase::Liquid);
+        assert_eq!(sut.outputs.len(), 2);
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn output_and_phase_reads_both_values_in_single_call() {
+        let mut sut = water();
+        let calls = sut.backend.stats().calls;
+        sut.output_and_phase(FluidParam::DMass).unwrap();
+        assert_eq!(sut.backend.stats().calls, calls + 1);
+        sut.output_and_phase(FluidParam::DMass).unwrap();
+        assert_eq!(sut.backend.stats().calls, calls + 1);
+    }
+
+    #[test]
+    fn snapshot_returns_consistent_values() {
+        let mut sut = water();
+        let result = sut.snapshot(&[FluidParam::CpMass]).unwrap();
+        assert_eq!(result.pressure, sut.pressure().unwrap());
+        assert_eq!(result.temperature, sut.temperature().unwrap());
+        assert_eq!(result.density, sut.density().unwrap());
+        assert_eq!(result.enthalpy, sut.specific_enthalpy().unwrap());
+        assert_eq!(result.entropy, sut.specific_entropy().unwrap());
+        assert_eq!(result.quality.value, sut.output(FluidParam::Q).unwrap());
+        assert_eq!(
+            result.outputs,
+            HashMap::from([(FluidParam::CpMass, sut.output(FluidParam::CpMass).unwrap())])
+        );
+    }
+
+    #[test]
+    fn snapshot_unavailable_output_returns_err() {
+        let mut sut = water();
+        assert!(matches!(
+            sut.snapshot(&[FluidParam::DMass, FluidParam::SurfaceTension]),
+            Err(FluidOutputError::CalculationFailed(
+                FluidParam::SurfaceTension,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn output_and_phase_unavailable_output_returns_err() {
+        let mut sut = water();
+        assert!(matches!(
+            sut.output_and_phase(FluidParam::SurfaceTension),
+            Err(FluidOutputError::CalculationFailed(
+                FluidParam::SurfaceTension,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn partial_deriv_valid_params_returns_ok() {
+        let mut sut = water();
+        let result = sut
+            .partial_deriv(FluidParam::HMass, FluidParam::T, FluidParam::P)
+            .unwrap();
+        assert_relative_eq!(result, sut.output(FluidParam::CpMass).unwrap());
+    }
+
+    #[rstest]
+    #[case(FluidParam::DynamicViscosity, FluidParam::T, FluidParam::P)]
+    #[case(FluidParam::HMass, FluidParam::Phase, FluidParam::P)]
+    #[case(FluidParam::HMass, FluidParam::T, FluidParam::Q)]
+    fn partial_deriv_non_differentiable_param_returns_err(
+        #[case] of: FluidParam,
+        #[case] wrt: FluidParam,
+        #[case] at_constant: FluidParam,
+    ) {
+        let mut sut = water();
+        let invalid = [of, wrt, at_constant]
+            .into_iter()
+            .find(|&key| !Fluid::is_differentiable(key))
+            .unwrap();
+        assert_eq!(
+            sut.partial_deriv(of, wrt, at_constant),
+            Err(FluidOutputError::NonDifferentiableParam(invalid))
+        );
+    }
+
+    #[test]
+    fn second_partial_deriv_valid_params_returns_ok() {
+        let mut sut = water();
+        let result = sut
+            .second_partial_deriv(
+                FluidParam::HMass,
+                FluidParam::T,
+                FluidParam::P,
+                FluidParam::T,
+                FluidParam::P,
+            )
+            .unwrap();
+        assert_relative_eq!(
+            result,
+            sut.partial_deriv(FluidParam::CpMass, FluidParam::T, FluidParam::P)
+                .unwrap(),
+            max_relative = 1e-6
+        );
+    }
+
+    #[rstest]
+    #[case(FluidParam::DynamicViscosity, FluidParam::T, FluidParam::P)]
+    #[case(FluidParam::HMass, FluidParam::Phase, FluidParam::P)]
+    #[case(FluidParam::HMass, FluidParam::T, FluidParam::Q)]
+    fn second_partial_deriv_non_differentiable_param_returns_err(
+        #[case] of: FluidParam,
+        #[case] wrt2: FluidParam,
+        #[case] constant2: FluidParam,
+    ) {
+        let mut sut = water();
+        let invalid = [of, wrt2, constant2]
+            .into_iter()
+            .find(|&key| !Fluid::is_differentiable(key))
+            .unwrap();
+        assert_eq!(
+            sut.second_partial_deriv(of, FluidParam::T, FluidParam::P, wrt2, constant2),
+            Err(FluidOutputError::NonDifferentiableParam(invalid))
+        );
+    }
+
+    #[test]
+    fn second_partial_deriv_same_wrt_and_constant_returns_err() {
+        let mut sut = water();
+        let result = sut.second_partial_deriv(
+            FluidParam::HMass,
+            FluidParam::P,
+            FluidParam::P,
+            FluidParam::T,
+            FluidParam::P,
+        );
+        assert!(matches!(
+            result,
+            Err(FluidOutputError::CalculationFailed(FluidParam::HMass, _))
+        ));
+    }
+
+    #[test]
+    fn saturation_deriv_matches_clausius_clapeyron() {
+        let saturated = |quality| {
+            Fluid::from(Pure::Water)
+                .update(
+                    FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                    FluidInput::quality(Ratio::new::<ratio>(quality)),
+                )
+                .unwrap()
+        };
+        let (mut liquid, mut vapor) = (saturated(0.0), saturated(1.0));
+        let latent_heat =
+            vapor.output(FluidParam::HMass).unwrap() - liquid.output(FluidParam::HMass).unwrap();
+        let specific_volume_change =
+            1.0 / vapor.density().unwrap().value - 1.0 / liquid.density().unwrap().value;
+        let temperature = liquid.temperature().unwrap().value;
+        let result = liquid
+            .saturation_deriv(FluidParam::P, FluidParam::T)
+            .unwrap();
+        assert_relative_eq!(
+            result,
+            latent_heat / (temperature * specific_volume_change),
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn saturation_deriv_non_differentiable_param_returns_err() {
+        let mut sut = water();
+        assert_eq!(
+            sut.saturation_deriv(FluidParam::P, FluidParam::Q),
+            Err(FluidOutputError::NonDifferentiableParam(FluidParam::Q))
+        );
+    }
+
+    #[test]
+    fn saturation_deriv_single_phase_returns_err() {
+        let mut sut = water();
+        assert_eq!(
+            sut.saturation_deriv(FluidParam::P, FluidParam::T),
+            Err(FluidOutputError::UnavailableOutput(FluidParam::P))
+        );
+    }
+
+    #[test]
+    fn partial_deriv_same_wrt_and_at_constant_returns_err() {
+        let mut sut = water();
+        let result = sut.partial_deriv(FluidParam::HMass, FluidParam::P, FluidParam::P);
+        assert!(matches!(
+            result,
+            Err(FluidOutputError::CalculationFailed(FluidParam::HMass, _))
+        ));
+    }
+
+    #[test]
+    fn typed_outputs_return_expected_values() {
+        let mut sut = water();
+        assert_eq!(sut.phase().unwrap(), Phase::Liquid);
+        assert_relative_eq!(sut.pressure().unwrap().value, 101325.0, max_relative = 1e-6);
+        assert_relative_eq!(
+            sut.temperature().unwrap().value,
+            293.15,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            sut.density().unwrap().value,
+            998.2071504679284,
+            max_relative = 1e-9
+        );
+        assert_eq!(
+            sut.cp().unwrap().value,
+            sut.output(FluidParam::CpMass).unwrap()
+        );
+        assert_eq!(
+            sut.cv().unwrap().value,
+            sut.output(FluidParam::CvMass).unwrap()
+        );
+        assert!(sut.cp().unwrap() > sut.cv().unwrap());
+        assert_relative_eq!(
+            sut.speed_of_sound().unwrap().value,
+            1482.3466,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            sut.kinematic_viscosity().unwrap().value,
+            sut.dynamic_viscosity().unwrap().value / sut.density().unwrap().value
+        );
+        assert_relative_eq!(
+            sut.prandtl_number().unwrap(),
+            sut.cp().unwrap().value * sut.dynamic_viscosity().unwrap().value
+                / sut.thermal_conductivity().unwrap().value,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn energy_outputs_round_trip_with_inputs() {
+        let mut sut = water();
+        let pressure = FluidInput::pressure(sut.pressure().unwrap());
+        for input in [
+            FluidInput::enthalpy(sut.specific_enthalpy().unwrap()),
+            FluidInput::entropy(sut.specific_entropy().unwrap()),
+            FluidInput::internal_energy(sut.specific_internal_energy().unwrap()),
+        ] {
+            let mut result = Fluid::from(Pure::Water).update(pressure, input).unwrap();
+            assert_relative_eq!(
+                result.temperature().unwrap().value,
+                sut.temperature().unwrap().value,
+                max_relative = 1e-6
+            );
+        }
+        assert!(sut.outputs.contains_key(&FluidParam::UMass));
+    }
+
+    #[test]
+    fn specific_volume_is_reciprocal_of_density_and_cached() {
+        let mut sut = water();
+        let result = sut.specific_volume().unwrap();
+        assert!(sut.outputs.contains_key(&FluidParam::DMass));
+        assert_relative_eq!(
+            result.value * sut.density().unwrap().value,
+            1.0,
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn molar_outputs_are_consistent_with_mass_outputs() {
+        let mut sut = water();
+        let molar_mass = sut.molar_mass().unwrap().value;
+        assert_relative_eq!(
+            sut.molar_density().unwrap().value * molar_mass,
+            sut.density().unwrap().value,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            sut.molar_enthalpy().unwrap().value / molar_mass,
+            sut.specific_enthalpy().unwrap().value,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            sut.molar_entropy().unwrap().value / molar_mass,
+            sut.specific_entropy().unwrap().value,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            sut.molar_internal_energy().unwrap().value / molar_mass,
+            sut.specific_internal_energy().unwrap().value,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            sut.molar_gibbs_energy().unwrap().value / molar_mass,
+            sut.specific_gibbs_energy().unwrap().value,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            sut.molar_helmholtz_energy().unwrap().value / molar_mass,
+            sut.specific_helmholtz_energy().unwrap().value,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn thermodynamic_potentials_are_consistent_with_definitions() {
+        let mut sut = water();
+        let temperature = sut.temperature().unwrap().value;
+        let entropy = sut.specific_entropy().unwrap().value;
+        assert_relative_eq!(
+            sut.specific_gibbs_energy().unwrap().value,
+            sut.specific_enthalpy().unwrap().value - temperature * entropy,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            sut.specific_helmholtz_energy().unwrap().value,
+            sut.specific_internal_energy().unwrap().value - temperature * entropy,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn kinematic_viscosity_unavailable_returns_err() {
+        let mut sut = Fluid::from(Pure::Krypton)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        assert!(matches!(
+            sut.kinematic_viscosity(),
+            Err(FluidOutputError::CalculationFailed(
+                FluidParam::DynamicViscosity,
+                _
+            ))
+        ));
+    }
+
+    #[rstest]
+    #[case(1.0, 20.0, [true, false, false, false])]
+    #[case(1.0, 200.0, [false, true, false, false])]
+    #[case(250.0, 200.0, [false, false, false, true])]
+    #[case(250.0, 400.0, [false, false, false, true])]
+    #[case(100.0, 400.0, [false, false, false, true])]
+    fn phase_predicates_return_expected_values(
+        #[case] pressure: f64,
+        #[case] temperature: f64,
+        #[case] expected: [bool; 4],
+    ) {
+        let mut sut = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(pressure)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(
+                    temperature,
+                )),
+            )
+            .unwrap();
+        assert_eq!(
+            [
+                sut.is_liquid(),
+                sut.is_gas(),
+                sut.is_two_phase(),
+                sut.is_supercritical()
+            ],
+            expected
+        );
+    }
+
+    #[test]
+    fn phase_predicates_of_two_phase_state_return_expected_values() {
+        let mut sut = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::quality(Ratio::new::<percent>(50.0)),
+            )
+            .unwrap();
+        assert!(sut.is_two_phase());
+        assert!(!sut.is_liquid() && !sut.is_gas() && !sut.is_supercritical());
+    }
+
+    #[test]
+    fn reynolds_number_is_consistent_with_kinematic_viscosity() {
+        let mut sut = water();
+        let (velocity, length) = (
+            Velocity::new::<meter_per_second>(2.0),
+            Length::new::<meter>(0.05),
+        );
+        assert_relative_eq!(
+            sut.reynolds_number(velocity, length).unwrap(),
+            velocity.value * length.value / sut.kinematic_viscosity().unwrap().value,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn reynolds_number_unavailable_viscosity_returns_err() {
+        let mut sut = Fluid::from(Pure::Krypton)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        assert!(matches!(
+            sut.reynolds_number(
+                Velocity::new::<meter_per_second>(1.0),
+                Length::new::<meter>(0.1)
+            ),
+            Err(FluidOutputError::CalculationFailed(
+                FluidParam::DynamicViscosity,
+                _
+            ))
+        ));
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(0.5)]
+    #[case(1.0)]
+    fn surface_tension_of_two_phase_state_returns_ok(#[case] quality: f64) {
+        let mut sut = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+                FluidInput::quality(Ratio::new::<ratio>(quality)),
+            )
+            .unwrap();
+        assert_relative_eq!(
+            sut.surface_tension().unwrap().value,
+            0.07275,
+            max_relative = 1e-3
+        );
+    }
+
+    #[test]
+    fn surface_tension_of_single_phase_state_returns_err() {
+        let mut sut = water();
+        assert_eq!(
+            sut.surface_tension(),
+            Err(FluidOutputError::UnavailableOutput(
+                FluidParam::SurfaceTension
+            ))
+        );
+        assert!(!sut.outputs.contains_key(&FluidParam::SurfaceTension));
+    }
+
+    #[test]
+    fn thermal_conductivity_unavailable_returns_err() {
+        let mut sut = Fluid::from(Pure::Cyclohexane)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        assert!(sut.dynamic_viscosity().is_ok());
+        assert!(matches!(
+            sut.thermal_conductivity(),
+            Err(FluidOutputError::CalculationFailed(
+                FluidParam::Conductivity,
+                _
+            ))
+        ));
+        assert!(matches!(
+            sut.prandtl_number(),
+            Err(FluidOutputError::CalculationFailed(FluidParam::Prandtl, _))
+        ));
+    }
+
+    #[rstest]
+    #[case(Pure::Nitrogen)]
+    #[case(Pure::Argon)]
+    fn isentropic_exponent_of_ideal_gas_equals_heat_capacity_ratio(#[case] substance: Pure) {
+        let mut sut = Fluid::from(substance)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        let heat_capacity_ratio =
+            sut.output(FluidParam::CpMass).unwrap() / sut.output(FluidParam::CvMass).unwrap();
+        assert_relative_eq!(
+            sut.isentropic_exponent().unwrap(),
+            heat_capacity_ratio,
+            max_relative = 1e-3
+        );
+    }
+
+    #[test]
+    fn expansion_coefficients_of_ideal_gas_are_reciprocals_of_temperature_and_pressure() {
+        let mut sut = Fluid::from(Pure::Air)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(10.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        assert_relative_eq!(
+            sut.isobaric_expansion_coefficient().unwrap().value,
+            1.0 / sut.temperature().unwrap().value,
+            max_relative = 1e-3
+        );
+        assert_relative_eq!(
+            sut.isothermal_compressibility().unwrap(),
+            1.0 / sut.pressure().unwrap().value,
+            max_relative = 1e-3
+        );
+        assert!(sut
+            .outputs
+            .contains_key(&FluidParam::IsothermalCompressibility));
+    }
+
+    #[test]
+    fn isentropic_exponent_of_real_gas_differs_from_heat_capacity_ratio() {
+        let mut sut = Fluid::from(Pure::CarbonDioxide)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(7500.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(35.0)),
+            )
+            .unwrap();
+        let heat_capacity_ratio =
+            sut.output(FluidParam::CpMass).unwrap() / sut.output(FluidParam::CvMass).unwrap();
+        let result = sut.isentropic_exponent().unwrap();
+        assert!(sut.compressibility_factor().unwrap() < 0.8);
+        assert!(sut.outputs.contains_key(&FluidParam::Z));
+        assert!((result - heat_capacity_ratio).abs() / heat_capacity_ratio > 0.1);
+    }
+
+    #[test]
+    fn update_in_place_valid_inputs_updates_state_and_clears_outputs() {
+        let mut sut = water();
+        sut.output(FluidParam::DMass).unwrap();
+        let input1 = FluidInput::pressure(Pressure::new::<atmosphere>(2.0));
+        let input2 = FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(50.0));
+        sut.update_in_place(input1, input2).unwrap();
+        assert!(sut.outputs.is_empty());
+        assert_eq!(
+            sut.update_request,
+            Some(FluidUpdateRequest::try_from((input1, input2)).unwrap())
+        );
+        let mut expected = Fluid::from(Pure::Water).update(input1, input2).unwrap();
+        assert_eq!(sut.density().unwrap(), expected.density().unwrap());
+    }
+
+    #[test]
+    fn update_in_place_same_inputs_returns_err() {
+        let mut sut = water();
+        let input = FluidInput::pressure(Pressure::new::<atmosphere>(1.0));
+        assert_eq!(
+            sut.update_in_place(input, input).unwrap_err(),
+            FluidStateError::InvalidInputPair(FluidParam::P, FluidParam::P)
+        );
+    }
+
+    #[test]
+    fn update_in_place_invalid_inputs_keeps_current_state() {
+        let mut sut = water();
+        let density = sut.density().unwrap();
+        let result = sut.update_in_place(
+            FluidInput::pressure(Pressure::new::<atmosphere>(-1.0)),
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+        );
+        assert!(matches!(result, Err(FluidStateError::UpdateFailed(_))));
+        sut.outputs.clear();
+        assert_eq!(sut.density().unwrap(), density);
+    }
+
+    #[test]
+    fn update_in_place_invalid_inputs_and_unrestorable_state_clears_state() {
+        let mut sut = water();
+        sut.output(FluidParam::DMass).unwrap();
+        sut.update_request = Some(FluidUpdateRequest(FluidInputPair::PT, -1.0, 293.15));
+        let result = sut.update_in_place(
+            FluidInput::pressure(Pressure::new::<atmosphere>(-1.0)),
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+        );
+        assert!(matches!(result, Err(FluidStateError::UpdateFailed(_))));
+        assert!(sut.update_request.is_none());
+        assert!(sut.outputs.is_empty());
+        assert_eq!(
+            sut.output(FluidParam::DMass),
+            Err(FluidOutputError::UnavailableOutput(FluidParam::DMass))
+        );
+        sut.update_in_place(
+            FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+        )
+        .unwrap();
+        assert_eq!(sut.density().unwrap(), water().density().unwrap());
+    }
+
+    #[test]
+    fn reset_clears_outputs_but_keeps_trivial_outputs() {
+        let mut sut = water();
+        sut.output(FluidParam::DMass).unwrap();
+        sut.trivial_output(FluidTrivialParam::MolarMass).unwrap();
+        let result = sut.reset();
+        assert!(result.outputs.is_empty());
+        assert!(result.update_request.is_none());
+        assert!(result
+            .trivial_outputs
+            .contains_key(&FluidTrivialParam::MolarMass));
+    }
+}
@@ -1,14 +1,40 @@
 //! Thermophysical properties of substances.
 
 mod common;
+mod config;
+mod defined;
+mod nozzle;
+mod overdetermined;
+mod process;
+mod property;
+mod saturation;
+mod shared;
+mod table;
+mod undefined;
 
-use crate::fluid::common::FluidUpdateRequest;
-use crate::io::{FluidParam, FluidTrivialParam};
-use crate::native::AbstractState;
+pub use config::*;
+pub use nozzle::*;
+pub use overdetermined::*;
+pub use property::*;
+pub use saturation::*;
+pub use shared::*;
+pub use table::*;
+
+use crate::error::{CoolPropError, FluidOutputError, FluidStateError, SubstanceError};
+use crate::fluid::common::{new_backend, trivial_output_error, FluidUpdateRequest};
+use crate::io::{FluidInputPair, FluidParam, FluidTrivialParam, Phase, ReferenceState};
+use crate::native::{AbstractState, CoolProp};
 use crate::substance::*;
-use crate::{DefinedState, UndefinedState};
+use crate::uom::si::f64::{MassDensity, MolarMass, Pressure, Ratio, ThermodynamicTemperature};
+use crate::uom::si::mass_density::kilogram_per_cubic_meter;
+use crate::uom::si::molar_mass::kilogram_per_mole;
+use crate::uom::si::pressure::pascal;
+use crate::uom::si::thermodynamic_temperature::kelvin;
+use crate::{DefinedState, Remember, UndefinedState};
 use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
 
 /// Provider of thermophysical properties of substances.
 ///
@@ -18,7 +44,8 @@ use std::marker::PhantomData;
 /// - incompressible pure substances _([`IncompPure`])_;
 /// - refrigerants _([`Refrigerant`])_;
 /// - predefined mixtures _([`PredefinedMix`])_;
-/// - incompressible binary mixtures _([`BinaryMix`])_.
+/// - incompressible binary mixtures _([`BinaryMix`])_;
+/// - custom mixtures _([`CustomMix`])_.
 ///
 /// It implements the [typestate pattern](https://en.wikipedia.org/wiki/Typestate_analysis)
 /// and has one generic type parameter `S` _(state type, [`DefinedState`] or [`UndefinedState`])_.
@@ -30,21 +57,541 @@ pub struct Fluid<S = DefinedState> {
     pub substance: Substance,
     backend: AbstractState,
     update_request: Option<FluidUpdateRequest>,
+    imposed_phase: Option<Phase>,
     trivial_outputs: HashMap<FluidTrivialParam, f64>,
     outputs: HashMap<FluidParam, f64>,
     state: PhantomData<S>,
 }
 
+impl<S> Fluid<S> {
+    /// Trivial output parameter value _(in SI units)_.
+    ///
+    /// Trivial outputs don't depend on the thermodynamic state,
+    /// so they are available regardless of the state type parameter.
+    ///
+    /// # Args
+    ///
+    /// - `key` -- trivial output parameter key.
+    ///
+    /// # Errors
+    ///
+    /// - If the calculation result is not finite _(i.e., the specified output
+    ///   is not available for the substance)_,
+    ///   a [`FluidOutputError::UnavailableTrivialOutput`] is returned.
+    /// - If CoolProp reports an error during the calculation,
+    ///   a [`FluidOutputError::TrivialCalculationFailed`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidTrivialParam;
+    /// use rfluids::substance::Pure;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// let result = water.trivial_output(FluidTrivialParam::TCritical).unwrap();
+    /// assert_relative_eq!(result, 647.096);
+    /// ```
+    pub fn trivial_output(&mut self, key: FluidTrivialParam) -> Result<f64, FluidOutputError> {
+        self.trivial_outputs
+            .remember(&self.backend, key)
+            .map_err(|e| trivial_output_error(key, e))
+    }
+
+    /// All trivial output parameters values _(in SI units)_,
+    /// which don't depend on the thermodynamic state.
+    ///
+    /// Each available value is cached, and each unavailable one
+    /// is represented by a [`FluidOutputError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidTrivialParam;
+    /// use rfluids::substance::Pure;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// let result = water.all_trivial_outputs();
+    /// assert_relative_eq!(
+    ///     *result[&FluidTrivialParam::TCritical].as_ref().unwrap(),
+    ///     647.096
+    /// );
+    /// ```
+    pub fn all_trivial_outputs(
+        &mut self,
+    ) -> HashMap<FluidTrivialParam, Result<f64, FluidOutputError>> {
+        FluidTrivialParam::iter()
+            .map(|key| (key, self.trivial_output(key)))
+            .collect()
+    }
+
+    /// Critical point temperature _(key: [`TCritical`](FluidTrivialParam::TCritical))_.
+    ///
+    /// # Errors
+    ///
+    /// For substances without a critical point _(e.g., incompressible substances)_,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::thermodynamic_temperature::kelvin;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// let result = water.critical_temperature().unwrap();
+    /// assert_relative_eq!(result.get::<kelvin>(), 647.096);
+    /// ```
+    pub fn critical_temperature(&mut self) -> Result<ThermodynamicTemperature, FluidOutputError> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            self.trivial_output(FluidTrivialParam::TCritical)?,
+        ))
+    }
+
+    /// Critical point pressure _(key: [`PCritical`](FluidTrivialParam::PCritical))_.
+    ///
+    /// # Errors
+    ///
+    /// For substances without a critical point _(e.g., incompressible substances)_,
+    /// a [`FluidOutputError`] is returned.
+    pub fn critical_pressure(&mut self) -> Result<Pressure, FluidOutputError> {
+        Ok(Pressure::new::<pascal>(
+            self.trivial_output(FluidTrivialParam::PCritical)?,
+        ))
+    }
+
+    /// Critical point mass density
+    /// _(key: [`DMassCritical`](FluidTrivialParam::DMassCritical))_.
+    ///
+    /// # Errors
+    ///
+    /// For substances without a critical point _(e.g., incompressible substances)_,
+    /// a [`FluidOutputError`] is returned.
+    pub fn critical_density(&mut self) -> Result<MassDensity, FluidOutputError> {
+        Ok(MassDensity::new::<kilogram_per_cubic_meter>(
+            self.trivial_output(FluidTrivialParam::DMassCritical)?,
+        ))
+    }
+
+    /// Molar mass _(key: [`MolarMass`](FluidTrivialParam::MolarMass))_.
+    ///
+    /// # Errors
+    ///
+    /// If the molar mass is not available for the substance
+    /// _(e.g., incompressible substances)_,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::molar_mass::kilogram_per_mole;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// let result = water.molar_mass().unwrap();
+    /// assert_relative_eq!(result.get::<kilogram_per_mole>(), 0.018015268);
+    /// ```
+    pub fn molar_mass(&mut self) -> Result<MolarMass, FluidOutputError> {
+        Ok(MolarMass::new::<kilogram_per_mole>(
+            self.trivial_output(FluidTrivialParam::MolarMass)?,
+        ))
+    }
+
+    /// Acentric factor
+    /// _(key: [`AcentricFactor`](FluidTrivialParam::AcentricFactor), dimensionless)_.
+    ///
+    /// # Errors
+    ///
+    /// If the acentric factor is not available for the substance
+    /// _(e.g., incompressible substances)_,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// assert_relative_eq!(water.acentric_factor().unwrap(), 0.3442920843);
+    /// ```
+    pub fn acentric_factor(&mut self) -> Result<f64, FluidOutputError> {
+        self.trivial_output(FluidTrivialParam::AcentricFactor)
+    }
+
+    /// Minimum temperature _(key: [`TMin`](FluidTrivialParam::TMin))_.
+    ///
+    /// **NB.** For mixtures, it's calculated for the specified composition
+    /// _(fractions are set when the instance is created)_.
+    ///
+    /// # Errors
+    ///
+    /// If the minimum temperature is not available for the substance,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::thermodynamic_temperature::kelvin;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// let result = water.min_temperature().unwrap();
+    /// assert_relative_eq!(result.get::<kelvin>(), 273.16);
+    /// ```
+    pub fn min_temperature(&mut self) -> Result<ThermodynamicTemperature, FluidOutputError> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            self.trivial_output(FluidTrivialParam::TMin)?,
+        ))
+    }
+
+    /// Maximum temperature _(key: [`TMax`](FluidTrivialParam::TMax))_.
+    ///
+    /// **NB.** For mixtures, it's calculated for the specified composition
+    /// _(fractions are set when the instance is created)_.
+    ///
+    /// # Errors
+    ///
+    /// If the maximum temperature is not available for the substance,
+    /// a [`FluidOutputError`] is returned.
+    pub fn max_temperature(&mut self) -> Result<ThermodynamicTemperature, FluidOutputError> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            self.trivial_output(FluidTrivialParam::TMax)?,
+        ))
+    }
+
+    /// Maximum pressure _(key: [`PMax`](FluidTrivialParam::PMax))_.
+    ///
+    /// # Errors
+    ///
+    /// If the maximum pressure is not available for the substance
+    /// _(e.g., incompressible substances)_,
+    /// a [`FluidOutputError`] is returned.
+    pub fn max_pressure(&mut self) -> Result<Pressure, FluidOutputError> {
+        Ok(Pressure::new::<pascal>(
+            self.trivial_output(FluidTrivialParam::PMax)?,
+        ))
+    }
+
+    /// Triple point temperature _(key: [`TTriple`](FluidTrivialParam::TTriple))_.
+    ///
+    /// # Errors
+    ///
+    /// For substances without a triple point
+    /// _(e.g., incompressible substances or mixtures)_,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::thermodynamic_temperature::kelvin;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// let result = water.triple_point_temperature().unwrap();
+    /// assert_relative_eq!(result.get::<kelvin>(), 273.16);
+    /// ```
+    pub fn triple_point_temperature(
+        &mut self,
+    ) -> Result<ThermodynamicTemperature, FluidOutputError> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            self.trivial_output(FluidTrivialParam::TTriple)?,
+        ))
+    }
+
+    /// Triple point pressure _(key: [`PTriple`](FluidTrivialParam::PTriple))_.
+    ///
+    /// # Errors
+    ///
+    /// For substances without a triple point
+    /// _(e.g., incompressible substances or mixtures)_,
+    /// a [`FluidOutputError`] is returned.
+    pub fn triple_point_pressure(&mut self) -> Result<Pressure, FluidOutputError> {
+        Ok(Pressure::new::<pascal>(
+            self.trivial_output(FluidTrivialParam::PTriple)?,
+        ))
+    }
+
+    /// Fraction of the [`BinaryMix`] _(or [`None`] for any other substance)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::{BinaryMix, BinaryMixKind, Pure};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let propylene_glycol = Fluid::from(
+    ///     BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap(),
+    /// );
+    /// assert_eq!(propylene_glycol.fraction(), Some(Ratio::new::<percent>(40.0)));
+    /// assert_eq!(Fluid::from(Pure::Water).fraction(), None);
+    /// ```
+    pub fn fraction(&self) -> Option<Ratio> {
+        match &self.substance {
+            Substance::BinaryMix(binary_mix) => Some(binary_mix.fraction),
+            _ => None,
+        }
+    }
+
+    /// Components of the [`CustomMix`] and their fractions
+    /// _(or [`None`] for any other substance)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::{CustomMix, Pure};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let components = HashMap::from([
+    ///     (Pure::Water.into(), Ratio::new::<percent>(80.0)),
+    ///     (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+    /// ]);
+    /// let mix = Fluid::from(CustomMix::mole_based(components.clone()).unwrap());
+    /// assert_eq!(mix.fractions(), Some(&components));
+    /// assert_eq!(Fluid::from(Pure::Water).fractions(), None);
+    /// ```
+    pub fn fractions(&self) -> Option<&HashMap<CustomMixComponent, Ratio>> {
+        match &self.substance {
+            Substance::CustomMix(custom_mix) => Some(custom_mix.components()),
+            _ => None,
+        }
+    }
+
+    /// Imposed phase state _(if any)_.
+    ///
+    /// # See also
+    ///
+    /// - [`Fluid::specify_phase`]
+    /// - [`Fluid::unspecify_phase`]
+    pub fn imposed_phase(&self) -> Option<Phase> {
+        self.imposed_phase
+    }
+
+    /// Sets the reference state _(zero points of enthalpy and entropy)_.
+    ///
+    /// The current thermodynamic state _(if any)_ is kept physically the same:
+    /// it's recalculated from its molar density and temperature
+    /// _(which don't depend on the reference state)_, and these become its new inputs.
+    /// Cached energy-related outputs are invalidated
+    /// _(see [`Fluid::invalidate_energy_cache`])_.
+    ///
+    /// **NB.** In CoolProp, the reference state is _process-global_ for the substance.
+    /// This call changes it for the whole process _(including other threads)_
+    /// until it's set again, i.e., it affects all instances of the same substance
+    /// _([`Fluid`], [`AbstractState`]
+    /// or [`CoolProp`] calls)_ created after this call.
+    /// Already existing instances keep their reference state.
+    ///
+    /// # Args
+    ///
+    /// - `reference` -- reference state.
+    ///
+    /// # Errors
+    ///
+    /// If the specified reference state is not supported for the substance
+    /// _(e.g., incompressible substances or mixtures)_,
+    /// a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam, ReferenceState};
+    /// use rfluids::substance::Refrigerant;
+    /// use rfluids::uom::si::f64::{Ratio, ThermodynamicTemperature};
+    /// use rfluids::uom::si::ratio::percent;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut r134a = Fluid::from(Refrigerant::R134a)
+    ///     .update(
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(0.0)),
+    ///         FluidInput::quality(Ratio::new::<percent>(0.0)),
+    ///     )
+    ///     .unwrap();
+    /// r134a.set_reference_state(ReferenceState::IIR).unwrap();
+    /// assert_relative_eq!(
+    ///     r134a.output(FluidParam::HMass).unwrap(),
+    ///     200e3,
+    ///     max_relative = 1e-6
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Reference states](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#reference-states)
+    pub fn set_reference_state(
+        &mut self,
+        reference: ReferenceState,
+    ) -> Result<(), FluidStateError> {
+        if matches!(
+            self.substance,
+            Substance::IncompPure(_) | Substance::BinaryMix(_)
+        ) {
+            return Err(FluidStateError::UnsupportedReferenceState(reference));
+        }
+        let request = match self.update_request {
+            Some(_) => Some(FluidUpdateRequest(
+                FluidInputPair::DMolarT,
+                self.backend
+                    .keyed_output(FluidParam::DMolar)
+                    .map_err(FluidStateError::UpdateFailed)?,
+                self.backend
+                    .keyed_output(FluidParam::T)
+                    .map_err(FluidStateError::UpdateFailed)?,
+            )),
+            None => None,
+        };
+        CoolProp::set_reference_state(self.substance.name(), reference)
+            .map_err(|_| FluidStateError::UnsupportedReferenceState(reference))?;
+        let mut backend = self
+            .backend
+            .try_clone()
+            .map_err(FluidStateError::UpdateFailed)?;
+        if let Some(request) = request {
+            backend
+                .update(request.0, request.1, request.2)
+                .map_err(FluidStateError::UpdateFailed)?;
+        }
+        self.backend = backend;
+        self.update_request = request;
+        self.invalidate_energy_cache();
+        Ok(())
+    }
+
+    /// Invalidates cached energy-related outputs
+    /// _(enthalpy, entropy, internal energy, Gibbs and Helmholtz energies
+    /// in both molar and mass units)_, which depend on the reference state.
+    ///
+    /// All other cached outputs _(e.g., density)_ are kept intact.
+    pub fn invalidate_energy_cache(&mut self) {
+        self.outputs.retain(|key, _| {
+            !matches!(
+                key,
+                FluidParam::HMolar
+                    | FluidParam::HMass
+                    | FluidParam::SMolar
+                    | FluidParam::SMass
+                    | FluidParam::UMolar
+                    | FluidParam::UMass
+                    | FluidParam::GMolar
+                    | FluidParam::GMass
+                    | FluidParam::HelmholtzMolar
+                    | FluidParam::HelmholtzMass
+            )
+        });
+    }
+
+    fn undefined_clone(&self) -> Result<Fluid<UndefinedState>, CoolPropError> {
+        Ok(Fluid {
+            substance: self.substance.clone(),
+            backend: self.backend.try_clone()?,
+            update_request: None,
+            imposed_phase: self.imposed_phase,
+            trivial_outputs: self.trivial_outputs.clone(),
+            outputs: HashMap::new(),
+            state: PhantomData,
+        })
+    }
+
+    fn impose_backend_phase(&mut self, phase: Option<Phase>) {
+        match phase {
+            Some(phase) => self
+                .backend
+                .specify_phase(phase)
+                .expect("All phase states must be supported by CoolProp!"),
+            None => self.backend.unspecify_phase(),
+        }
+    }
+
+    fn restore_imposed_phase(&mut self) {
+        // CoolProp overrides the phase of the current state when the phase is imposed,
+        // so the actual one is cached beforehand
+        if self.imposed_phase.is_some() {
+            if let Ok(phase) = self.backend.phase() {
+                self.outputs.insert(FluidParam::Phase, phase.into());
+            }
+        }
+        self.impose_backend_phase(self.imposed_phase);
+    }
+
+    fn into_state<T>(self) -> Fluid<T> {
+        Fluid {
+            substance: self.substance,
+            backend: self.backend,
+            update_request: self.update_request,
+            imposed_phase: self.imposed_phase,
+            trivial_outputs: self.trivial_outputs,
+            outputs: self.outputs,
+            state: PhantomData,
+        }
+    }
+
+    fn into_defined_state(self) -> Fluid<DefinedState> {
+        debug_assert!(
+            self.update_request.is_some(),
+            "Fluid with `DefinedState` type parameter must have a thermodynamic state!"
+        );
+        self.into_state()
+    }
+}
+
+/// Two instances are equal if they have the same substance
+/// and are updated with the same inputs _(input pair and values in SI units)_.
+///
+/// The backend and cached outputs are ignored.
+///
+/// **NB.** Input values are compared exactly, so the same thermodynamic state
+/// defined by other inputs _(or by values with rounding errors)_ is not considered equal.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::fluid::Fluid;
+/// use rfluids::io::FluidInput;
+/// use rfluids::substance::Pure;
+/// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+/// use rfluids::uom::si::pressure::atmosphere;
+/// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+///
+/// let pressure = FluidInput::pressure(Pressure::new::<atmosphere>(1.0));
+/// let temperature =
+///     FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+/// let mut water = Fluid::from(Pure::Water).update(pressure, temperature).unwrap();
+/// let same_water = Fluid::from(Pure::Water).update(temperature, pressure).unwrap();
+/// water.density().unwrap();
+/// assert_eq!(water, same_water);
+/// ```
+impl<S> PartialEq for Fluid<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.substance == other.substance && self.update_request == other.update_request
+    }
+}
+
 impl From<Substance> for Fluid<UndefinedState> {
     fn from(value: Substance) -> Self {
-        let mut backend = AbstractState::new(value.backend_name(), value).unwrap();
-        if let Substance::BinaryMix(binary_mix) = value {
-            backend.set_fractions(&[binary_mix.fraction.value]).unwrap();
-        }
         Self {
+            backend: new_backend(&value).unwrap(),
             substance: value,
-            backend,
             update_request: None,
+            imposed_phase: None,
             trivial_outputs: HashMap::new(),
             outputs: HashMap::new(),
             state: PhantomData,
@@ -82,10 +629,435 @@ impl From<BinaryMix> for Fluid<UndefinedState> {
     }
 }
 
+impl From<CustomMix> for Fluid<UndefinedState> {
+    fn from(value: CustomMix) -> Self {
+        Substance::from(value).into()
+    }
+}
+
+impl TryFrom<&str> for Fluid<UndefinedState> {
+    type Error = SubstanceError;
+
+    /// Creates and returns a new [`Fluid`] instance
+    /// with [`UndefinedState`] type parameter
+    /// by the substance name _(see [`Substance::try_from`])_.
+    ///
+    /// # Errors
+    ///
+    /// If there is no substance with the specified name,
+    /// a [`SubstanceError::NotFound`] is returned.
+    /// If the specified name is a [`BinaryMixKind`] without a fraction,
+    /// a [`SubstanceError::FractionRequired`] is returned
+    /// _(use [`Fluid::binary`] instead)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::error::SubstanceError;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::{BinaryMixKind, Pure};
+    ///
+    /// let water = Fluid::try_from("Water").unwrap();
+    /// assert_eq!(water.substance, Pure::Water.into());
+    /// assert_eq!(
+    ///     Fluid::try_from("MPG").unwrap_err(),
+    ///     SubstanceError::FractionRequired(BinaryMixKind::MPG)
+    /// );
+    /// ```
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match Substance::try_from(value) {
+            Ok(substance) => Ok(substance.into()),
+            Err(e) => {
+                Err(BinaryMixKind::from_str(value.trim())
+                    .map_or(e, SubstanceError::FractionRequired))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use strum::IntoEnumIterator;
+    use crate::io::FluidInput;
+    use crate::native::ReferenceStateGuard;
+    use crate::uom::si::available_energy::joule_per_kilogram;
+    use crate::uom::si::f64::AvailableEnergy;
+    use crate::uom::si::ratio::{percent, ratio};
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use approx::assert_relative_eq;
+    use rstest::*;
+
+    #[rstest]
+    #[case("Water", Pure::Water.into())]
+    #[case("R32", Refrigerant::R32.into())]
+    #[case(
+        "MPG[0.4]",
+        BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap().into()
+    )]
+    fn try_from_valid_name_returns_ok(#[case] name: &str, #[case] expected: Substance) {
+        let result = Fluid::try_from(name).unwrap();
+        assert_eq!(result.substance, expected);
+    }
+
+    #[test]
+    fn try_from_invalid_name_returns_err() {
+        assert_eq!(
+            Fluid::try_from("Hello, World!").unwrap_err(),
+            SubstanceError::NotFound("Hello, World!".into())
+        );
+    }
+
+    #[test]
+    fn try_from_binary_mix_kind_without_fraction_returns_err() {
+        assert_eq!(
+            Fluid::try_from(" mpg ").unwrap_err(),
+            SubstanceError::FractionRequired(BinaryMixKind::MPG)
+        );
+    }
+
+    #[test]
+    fn eq_ignores_backend_and_cached_outputs() {
+        let input1 = FluidInput::pressure(Pressure::new::<pascal>(101325.0));
+        let input2 = FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+        let mut sut = Fluid::from(Pure::Water).update(input1, input2).unwrap();
+        sut.output(FluidParam::DMass).unwrap();
+        sut.trivial_output(FluidTrivialParam::MolarMass).unwrap();
+        assert_eq!(
+            sut,
+            Fluid::from(Pure::Water).update(input2, input1).unwrap()
+        );
+        assert_eq!(Fluid::from(Pure::Water), Fluid::from(Pure::Water));
+    }
+
+    #[test]
+    fn ne_for_other_substance_or_state() {
+        let input1 = FluidInput::pressure(Pressure::new::<pascal>(101325.0));
+        let input2 = FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+        let sut = Fluid::from(Pure::Water).update(input1, input2).unwrap();
+        assert_ne!(
+            sut,
+            Fluid::from(Pure::Ethanol).update(input1, input2).unwrap()
+        );
+        assert_ne!(
+            sut,
+            Fluid::from(Pure::Water)
+                .update(
+                    input1,
+                    FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(30.0))
+                )
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn trivial_output_returns_cached_value() {
+        let mut sut = Fluid::from(Pure::Water);
+        let result = sut.trivial_output(FluidTrivialParam::MolarMass).unwrap();
+        assert_relative_eq!(result, 0.018015268);
+        assert_eq!(sut.trivial_outputs[&FluidTrivialParam::MolarMass], result);
+    }
+
+    #[test]
+    fn trivial_output_unavailable_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water);
+        assert!(matches!(
+            sut.trivial_output(FluidTrivialParam::TCritical),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::TCritical,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn all_trivial_outputs_returns_each_trivial_param() {
+        let mut sut = Fluid::from(Pure::Water);
+        let result = sut.all_trivial_outputs();
+        assert_eq!(result.len(), FluidTrivialParam::iter().count());
+        assert_relative_eq!(
+            *result[&FluidTrivialParam::TCritical].as_ref().unwrap(),
+            647.096
+        );
+        assert_relative_eq!(
+            *result[&FluidTrivialParam::MolarMass].as_ref().unwrap(),
+            0.018015268
+        );
+        assert_eq!(
+            sut.trivial_outputs.len(),
+            result.values().filter(|r| r.is_ok()).count()
+        );
+    }
+
+    #[test]
+    fn all_trivial_outputs_of_incomp_contains_errs() {
+        let mut sut = Fluid::from(IncompPure::Water);
+        let result = sut.all_trivial_outputs();
+        assert!(matches!(
+            result[&FluidTrivialParam::MolarMass],
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::MolarMass,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn acentric_factor_returns_expected_value() {
+        let mut sut = Fluid::from(Pure::Water);
+        assert_relative_eq!(sut.acentric_factor().unwrap(), 0.3442920843);
+        assert!(sut
+            .trivial_outputs
+            .contains_key(&FluidTrivialParam::AcentricFactor));
+    }
+
+    #[test]
+    fn acentric_factor_of_incomp_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water);
+        assert!(matches!(
+            sut.acentric_factor(),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::AcentricFactor,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn operating_limits_return_expected_values() {
+        let mut sut = Fluid::from(Pure::Water);
+        assert_relative_eq!(sut.min_temperature().unwrap().value, 273.16);
+        assert_relative_eq!(sut.max_temperature().unwrap().value, 2000.0);
+        assert_relative_eq!(sut.max_pressure().unwrap().value, 1e9);
+    }
+
+    #[test]
+    fn operating_limits_of_binary_mix_are_temperature_only() {
+        let mut sut = Fluid::from(
+            BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap(),
+        );
+        assert!(sut.min_temperature().unwrap() < sut.max_temperature().unwrap());
+        assert!(matches!(
+            sut.max_pressure(),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::PMax,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn triple_point_returns_expected_values() {
+        let mut sut = Fluid::from(Pure::Water);
+        assert_relative_eq!(sut.triple_point_temperature().unwrap().value, 273.16);
+        assert_relative_eq!(
+            sut.triple_point_pressure().unwrap().value,
+            611.6548008968684,
+            max_relative = 1e-9
+        );
+        assert_eq!(sut.trivial_outputs.len(), 2);
+    }
+
+    #[test]
+    fn triple_point_of_incomp_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water);
+        assert!(matches!(
+            sut.triple_point_temperature(),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::TTriple,
+                _
+            ))
+        ));
+        assert!(matches!(
+            sut.triple_point_pressure(),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::PTriple,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn fraction_of_binary_mix_returns_its_fraction() {
+        let fraction = Ratio::new::<percent>(40.0);
+        let sut = Fluid::from(BinaryMix::try_new(BinaryMixKind::MPG, fraction).unwrap());
+        assert_eq!(sut.fraction(), Some(fraction));
+        assert_eq!(sut.fractions(), None);
+        assert_eq!(Fluid::from(Pure::Water).fraction(), None);
+    }
+
+    #[test]
+    fn fractions_of_custom_mix_returns_components() {
+        let mix = CustomMix::mass_based(HashMap::from([
+            (Pure::Water.into(), Ratio::new::<percent>(60.0)),
+            (Pure::Ethanol.into(), Ratio::new::<percent>(40.0)),
+        ]))
+        .unwrap();
+        let sut = Fluid::from(mix.clone());
+        assert_eq!(sut.fractions(), Some(mix.components()));
+        assert_eq!(sut.fraction(), None);
+        assert_eq!(Fluid::from(Pure::Water).fractions(), None);
+    }
+
+    #[test]
+    fn critical_point_returns_expected_values() {
+        let mut sut = Fluid::from(Pure::Water);
+        assert_relative_eq!(sut.critical_temperature().unwrap().value, 647.096);
+        assert_relative_eq!(sut.critical_pressure().unwrap().value, 22.064e6);
+        assert_relative_eq!(sut.critical_density().unwrap().value, 322.0);
+        assert_eq!(sut.trivial_outputs.len(), 3);
+    }
+
+    #[test]
+    fn critical_point_of_incomp_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water);
+        assert!(matches!(
+            sut.critical_temperature(),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::TCritical,
+                _
+            ))
+        ));
+        assert!(matches!(
+            sut.critical_pressure(),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::PCritical,
+                _
+            ))
+        ));
+        assert!(matches!(
+            sut.critical_density(),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::DMassCritical,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn molar_mass_returns_cached_value() {
+        let mut sut = Fluid::from(Pure::Water);
+        assert_relative_eq!(sut.molar_mass().unwrap().value, 0.018015268);
+        assert!(sut
+            .trivial_outputs
+            .contains_key(&FluidTrivialParam::MolarMass));
+    }
+
+    #[test]
+    fn molar_mass_of_incomp_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water);
+        assert!(matches!(
+            sut.molar_mass(),
+            Err(FluidOutputError::TrivialCalculationFailed(
+                FluidTrivialParam::MolarMass,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn set_reference_state_recalculates_state_and_invalidates_energy_outputs() {
+        let _guard = ReferenceStateGuard::new("R1243zf");
+        let mut sut = Fluid::from(Refrigerant::R1243zf)
+            .update(
+                FluidInput::pressure(Pressure::new::<pascal>(101325.0)),
+                FluidInput::quality(Ratio::new::<ratio>(0.0)),
+            )
+            .unwrap();
+        let default = sut.output(FluidParam::HMass).unwrap();
+        let density = sut.output(FluidParam::DMass).unwrap();
+        sut.set_reference_state(ReferenceState::NBP).unwrap();
+        assert!(!sut.outputs.contains_key(&FluidParam::HMass));
+        assert_eq!(sut.outputs[&FluidParam::DMass], density);
+        let result = sut.output(FluidParam::HMass).unwrap();
+        sut.set_reference_state(ReferenceState::Default).unwrap();
+        assert_relative_eq!(result, 0.0, epsilon = 1e-6);
+        assert_relative_eq!(
+            sut.output(FluidParam::HMass).unwrap(),
+            default,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn set_reference_state_keeps_state_defined_by_energy_input() {
+        let _guard = ReferenceStateGuard::new("R1233zd(E)");
+        let mut sut = Fluid::from(Refrigerant::R1233zdE)
+            .update(
+                FluidInput::pressure(Pressure::new::<pascal>(101325.0)),
+                FluidInput::enthalpy(AvailableEnergy::new::<joule_per_kilogram>(300e3)),
+            )
+            .unwrap();
+        let temperature = sut.output(FluidParam::T).unwrap();
+        let density = sut.output(FluidParam::DMolar).unwrap();
+        sut.set_reference_state(ReferenceState::NBP).unwrap();
+        let result = sut.output(FluidParam::T).unwrap();
+        sut.set_reference_state(ReferenceState::Default).unwrap();
+        assert_relative_eq!(result, temperature);
+        let request = sut.update_request.unwrap();
+        assert_eq!(request.0, FluidInputPair::DMolarT);
+        assert_relative_eq!(request.1, density, max_relative = 1e-12);
+        assert_relative_eq!(request.2, temperature, max_relative = 1e-12);
+        assert_relative_eq!(
+            sut.output(FluidParam::HMass).unwrap(),
+            300e3,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn set_reference_state_keeps_density_cached_and_recomputes_enthalpy() {
+        let _guard = ReferenceStateGuard::new("R1234ze(E)");
+        let mut sut = Fluid::from(Refrigerant::R1234zeE)
+            .update(
+                FluidInput::pressure(Pressure::new::<pascal>(101325.0)),
+                FluidInput::enthalpy(AvailableEnergy::new::<joule_per_kilogram>(400e3)),
+            )
+            .unwrap();
+        let density = sut.output(FluidParam::DMass).unwrap();
+        sut.set_reference_state(ReferenceState::NBP).unwrap();
+        assert_eq!(sut.outputs[&FluidParam::DMass], density);
+        let enthalpy = sut.output(FluidParam::HMass).unwrap();
+        let mut backend = new_backend(&sut.substance).unwrap();
+        backend
+            .update(FluidInputPair::HMassP, enthalpy, 101325.0)
+            .unwrap();
+        let expected = backend.keyed_output(FluidParam::DMass).unwrap();
+        sut.set_reference_state(ReferenceState::Default).unwrap();
+        assert!((enthalpy - 400e3).abs() > 1.0);
+        assert_relative_eq!(density, expected, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn invalidate_energy_cache_keeps_other_outputs() {
+        let mut sut = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<pascal>(101325.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        let density = sut.output(FluidParam::DMass).unwrap();
+        let enthalpy = sut.output(FluidParam::HMass).unwrap();
+        sut.output(FluidParam::SMolar).unwrap();
+        sut.output(FluidParam::UMass).unwrap();
+        sut.invalidate_energy_cache();
+        assert_eq!(sut.outputs.len(), 1);
+        assert_eq!(sut.outputs[&FluidParam::DMass], density);
+        assert_eq!(sut.output(FluidParam::HMass).unwrap(), enthalpy);
+        assert_eq!(sut.outputs.len(), 2);
+    }
+
+    #[rstest]
+    #[case(IncompPure::Water.into())]
+    #[case(PredefinedMix::Ekofisk.into())]
+    fn set_reference_state_unsupported_substance_returns_err(#[case] substance: Substance) {
+        let mut sut = Fluid::from(substance);
+        assert_eq!(
+            sut.set_reference_state(ReferenceState::IIR),
+            Err(FluidStateError::UnsupportedReferenceState(
+                ReferenceState::IIR
+            ))
+        );
+    }
 
     #[test]
     fn from_each_pure_does_not_panic() {
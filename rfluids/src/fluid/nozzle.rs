@@ -0,0 +1,198 @@
+use crate::error::{CoolPropError, FluidStateError};
+use crate::fluid::common::new_backend;
+use crate::fluid::Fluid;
+use crate::io::{FluidInput, FluidInputPair, FluidParam};
+use crate::native::AbstractState;
+use crate::uom::si::f64::{Pressure, SpecificHeatCapacity, Velocity};
+use crate::uom::si::pressure::pascal;
+use crate::uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use crate::uom::si::velocity::meter_per_second;
+use crate::DefinedState;
+
+/// Result of the isentropic nozzle expansion.
+#[derive(Debug)]
+pub struct NozzleResult {
+    /// `true` if the flow chokes
+    /// _(i.e., Mach 1 is reached before the back pressure)_.
+    pub choked: bool,
+    /// Throat state
+    /// _(sonic state if the flow chokes, otherwise the same as the exit state)_.
+    pub throat: Fluid,
+    /// Throat velocity.
+    pub throat_velocity: Velocity,
+    /// Exit state _(after the isentropic expansion to the back pressure)_.
+    pub exit: Fluid,
+    /// Exit velocity.
+    pub exit_velocity: Velocity,
+}
+
+impl Fluid<DefinedState> {
+    /// Isentropic nozzle expansion from the current state
+    /// _(considered as the stagnation state)_ to the specified back pressure.
+    ///
+    /// The current thermodynamic state and cached outputs are not affected.
+    ///
+    /// # Args
+    ///
+    /// - `back_pressure` -- back pressure _(should be lower than the current pressure)_.
+    ///
+    /// # Errors
+    ///
+    /// If the isentropic expansion fails
+    /// _(e.g., for incompressible substances or invalid back pressure)_,
+    /// a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::kilopascal;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let air = Fluid::from(Pure::Air)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<kilopascal>(500.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let result = air
+    ///     .nozzle_expand(Pressure::new::<kilopascal>(100.0))
+    ///     .unwrap();
+    /// assert!(result.choked);
+    /// assert!(result.exit_velocity > result.throat_velocity);
+    /// ```
+    pub fn nozzle_expand(&self, back_pressure: Pressure) -> Result<NozzleResult, FluidStateError> {
+        let stagnation = |key| {
+            self.backend
+                .keyed_output(key)
+                .map_err(FluidStateError::UpdateFailed)
+        };
+        let (pressure, enthalpy, entropy) = (
+            stagnation(FluidParam::P)?,
+            stagnation(FluidParam::HMass)?,
+            stagnation(FluidParam::SMass)?,
+        );
+        let mut backend = new_backend(&self.substance).map_err(FluidStateError::UpdateFailed)?;
+        let mut expand =
+            |p| expanded(&mut backend, enthalpy, entropy, p).map_err(FluidStateError::UpdateFailed);
+        let (exit_velocity, exit_mach) = expand(back_pressure.value)?;
+        let choked = exit_mach > 1.0;
+        let (throat_pressure, throat_velocity) = if choked {
+            let (mut low, mut high) = (back_pressure.value, pressure);
+            while (high - low) / high > 1e-9 {
+                let middle = 0.5 * (low + high);
+                if expand(middle)?.1 > 1.0 {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            (high, expand(high)?.0)
+        } else {
+            (back_pressure.value, exit_velocity)
+        };
+        let state = |p| {
+            Fluid::from(self.substance.clone()).update(
+                FluidInput::pressure(Pressure::new::<pascal>(p)),
+                FluidInput::entropy(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+                    entropy,
+                )),
+            )
+        };
+        Ok(NozzleResult {
+            choked,
+            throat: state(throat_pressure)?,
+            throat_velocity: Velocity::new::<meter_per_second>(throat_velocity),
+            exit: state(back_pressure.value)?,
+            exit_velocity: Velocity::new::<meter_per_second>(exit_velocity),
+        })
+    }
+}
+
+fn expanded(
+    backend: &mut AbstractState,
+    stagnation_enthalpy: f64,
+    entropy: f64,
+    pressure: f64,
+) -> Result<(f64, f64), CoolPropError> {
+    backend.update(FluidInputPair::PSMass, pressure, entropy)?;
+    let enthalpy = backend.keyed_output(FluidParam::HMass)?;
+    let sound_speed = backend.keyed_output(FluidParam::SoundSpeed)?;
+    let velocity = (2.0 * (stagnation_enthalpy - enthalpy).max(0.0)).sqrt();
+    Ok((velocity, velocity / sound_speed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::substance::{IncompPure, Pure};
+    use crate::uom::si::f64::ThermodynamicTemperature;
+    use crate::uom::si::pressure::kilopascal;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use approx::assert_relative_eq;
+
+    fn air() -> Fluid {
+        Fluid::from(Pure::Air)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(500.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn nozzle_expand_low_back_pressure_chokes() {
+        let sut = air();
+        let mut result = sut
+            .nozzle_expand(Pressure::new::<kilopascal>(100.0))
+            .unwrap();
+        assert!(result.choked);
+        assert_relative_eq!(
+            result.throat.pressure().unwrap().get::<kilopascal>() / 500.0,
+            0.528,
+            max_relative = 1e-2
+        );
+        assert_relative_eq!(
+            result.throat_velocity.value,
+            result.throat.output(FluidParam::SoundSpeed).unwrap(),
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            result.exit.pressure().unwrap().get::<kilopascal>(),
+            100.0,
+            max_relative = 1e-6
+        );
+        assert!(result.exit_velocity.value > result.exit.output(FluidParam::SoundSpeed).unwrap());
+    }
+
+    #[test]
+    fn nozzle_expand_high_back_pressure_does_not_choke() {
+        let sut = air();
+        let mut result = sut
+            .nozzle_expand(Pressure::new::<kilopascal>(450.0))
+            .unwrap();
+        assert!(!result.choked);
+        assert_eq!(result.throat_velocity, result.exit_velocity);
+        assert_relative_eq!(
+            result.throat.pressure().unwrap().get::<kilopascal>(),
+            450.0,
+            max_relative = 1e-6
+        );
+        assert!(result.exit_velocity.value < result.exit.output(FluidParam::SoundSpeed).unwrap());
+    }
+
+    #[test]
+    fn nozzle_expand_incomp_returns_err() {
+        let sut = Fluid::from(IncompPure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(500.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        let result = sut.nozzle_expand(Pressure::new::<kilopascal>(100.0));
+        assert!(matches!(result, Err(FluidStateError::UpdateFailed(_))));
+    }
+}
@@ -0,0 +1,207 @@
+use crate::error::FluidStateError;
+use crate::fluid::Fluid;
+use crate::io::{FluidInput, FluidInputPair};
+use crate::substance::Substance;
+use crate::{DefinedState, UndefinedState};
+
+/// Residual mismatch report of [`Fluid::from_overdetermined`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResidualReport {
+    /// Inputs used to define the thermodynamic state.
+    pub used: (FluidInput, FluidInput),
+    /// Unused inputs along with their residuals
+    /// _(relative difference between the calculated and specified values,
+    /// or absolute difference if the specified value is zero;
+    /// [`NaN`](f64::NAN) if the unused input can't be calculated)_.
+    pub residuals: Vec<(FluidInput, f64)>,
+}
+
+impl ResidualReport {
+    /// Maximum absolute residual _(zero if there are no unused inputs)_.
+    pub fn max_residual(&self) -> f64 {
+        self.residuals
+            .iter()
+            .map(|r| {
+                if r.1.is_nan() {
+                    f64::INFINITY
+                } else {
+                    r.1.abs()
+                }
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Fluid<UndefinedState> {
+    /// Creates a new instance with the thermodynamic state
+    /// defined by the over-specified inputs _(e.g., measured temperature,
+    /// pressure and density)_.
+    ///
+    /// Each valid pair of the specified inputs is tried, and the best-conditioned one
+    /// _(whose state reproduces the unused inputs with the smallest maximum residual)_
+    /// is used to define the state.
+    ///
+    /// # Args
+    ///
+    /// - `substance` -- substance.
+    /// - `inputs` -- input properties _(at least 2)_.
+    ///
+    /// # Errors
+    ///
+    /// If less than 2 inputs are specified, none of the pairs is valid
+    /// or none of the valid pairs defines the state, a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{MassDensity, Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::mass_density::kilogram_per_cubic_meter;
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let (water, report) = Fluid::from_overdetermined(
+    ///     Pure::Water,
+    ///     &[
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::density(MassDensity::new::<kilogram_per_cubic_meter>(998.2)),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(report.residuals.len(), 1);
+    /// assert_eq!(report.residuals[0].0.key, FluidParam::DMass);
+    /// assert!(report.max_residual() < 1e-5);
+    /// ```
+    pub fn from_overdetermined(
+        substance: impl Into<Substance>,
+        inputs: &[FluidInput],
+    ) -> Result<(Fluid<DefinedState>, ResidualReport), FluidStateError> {
+        if inputs.len() < 2 {
+            return Err(FluidStateError::NotEnoughInputs);
+        }
+        let substance = substance.into();
+        let mut best: Option<(Fluid<DefinedState>, ResidualReport)> = None;
+        let mut error = None;
+        for (i, &input1) in inputs.iter().enumerate() {
+            for (j, &input2) in inputs.iter().enumerate().skip(i + 1) {
+                if FluidInputPair::try_from((input1.key, input2.key)).is_err() {
+                    error.get_or_insert(FluidStateError::InvalidInputPair(input1.key, input2.key));
+                    continue;
+                }
+                let mut fluid = match Fluid::from(substance.clone()).update(input1, input2) {
+                    Ok(fluid) => fluid,
+                    Err(e) => {
+                        error = Some(e);
+                        continue;
+                    }
+                };
+                let residuals = inputs
+                    .iter()
+                    .enumerate()
+                    .filter(|&(k, _)| k != i && k != j)
+                    .map(|(_, &input)| (input, residual(&mut fluid, input)))
+                    .collect();
+                let report = ResidualReport {
+                    used: (input1, input2),
+                    residuals,
+                };
+                if best
+                    .as_ref()
+                    .is_none_or(|b| report.max_residual() < b.1.max_residual())
+                {
+                    best = Some((fluid, report));
+                }
+            }
+        }
+        best.ok_or_else(|| error.unwrap())
+    }
+}
+
+fn residual(fluid: &mut Fluid, input: FluidInput) -> f64 {
+    match fluid.output(input.key) {
+        Ok(value) if input.si_value == 0.0 => value,
+        Ok(value) => (value - input.si_value) / input.si_value,
+        Err(_) => f64::NAN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FluidParam;
+    use crate::substance::Pure;
+    use crate::uom::si::f64::{MassDensity, Pressure, Ratio, ThermodynamicTemperature};
+    use crate::uom::si::mass_density::kilogram_per_cubic_meter;
+    use crate::uom::si::pressure::atmosphere;
+    use crate::uom::si::ratio::ratio;
+    use crate::uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+    use approx::assert_relative_eq;
+
+    fn temperature() -> FluidInput {
+        FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0))
+    }
+
+    fn pressure() -> FluidInput {
+        FluidInput::pressure(Pressure::new::<atmosphere>(1.0))
+    }
+
+    #[test]
+    fn from_overdetermined_slightly_inconsistent_inputs_reports_small_residual() {
+        let density = FluidInput::density(MassDensity::new::<kilogram_per_cubic_meter>(
+            998.2071504679284 * (1.0 + 1e-5),
+        ));
+        let (mut fluid, report) =
+            Fluid::from_overdetermined(Pure::Water, &[temperature(), pressure(), density]).unwrap();
+        assert_eq!(report.used, (temperature(), pressure()));
+        assert_eq!(report.residuals.len(), 1);
+        assert_eq!(report.residuals[0].0, density);
+        assert_relative_eq!(report.residuals[0].1, -1e-5, max_relative = 1e-3);
+        assert_relative_eq!(
+            fluid.temperature().unwrap().get::<degree_celsius>(),
+            20.0,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn from_overdetermined_two_inputs_reports_no_residuals() {
+        let (_, report) =
+            Fluid::from_overdetermined(Pure::Water, &[temperature(), pressure()]).unwrap();
+        assert!(report.residuals.is_empty());
+        assert_eq!(report.max_residual(), 0.0);
+    }
+
+    #[test]
+    fn from_overdetermined_zero_specified_value_reports_absolute_residual() {
+        let quality = FluidInput::quality(Ratio::new::<ratio>(0.0));
+        let saturated = Fluid::from(Pure::Water)
+            .update(pressure(), quality)
+            .unwrap()
+            .output(FluidParam::T)
+            .unwrap();
+        let temperature =
+            FluidInput::temperature(ThermodynamicTemperature::new::<kelvin>(saturated));
+        let (_, report) =
+            Fluid::from_overdetermined(Pure::Water, &[pressure(), quality, temperature]).unwrap();
+        assert!(report.max_residual() < 1e-6);
+    }
+
+    #[test]
+    fn from_overdetermined_not_enough_inputs_returns_err() {
+        assert_eq!(
+            Fluid::from_overdetermined(Pure::Water, &[temperature()]).unwrap_err(),
+            FluidStateError::NotEnoughInputs
+        );
+    }
+
+    #[test]
+    fn from_overdetermined_invalid_pairs_returns_err() {
+        assert_eq!(
+            Fluid::from_overdetermined(Pure::Water, &[temperature(), temperature()]).unwrap_err(),
+            FluidStateError::InvalidInputPair(FluidParam::T, FluidParam::T)
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::error::FluidStateError;
+use crate::fluid::Fluid;
+use crate::io::{FluidInput, FluidParam};
+use crate::uom::si::available_energy::joule_per_kilogram;
+use crate::uom::si::f64::{AvailableEnergy, Pressure, SpecificHeatCapacity};
+use crate::uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use crate::DefinedState;
+
+impl Fluid<DefinedState> {
+    /// State after the isentropic process from the current state
+    /// to the specified pressure _(e.g., ideal compression or expansion)_.
+    ///
+    /// The current thermodynamic state is not affected.
+    ///
+    /// # Args
+    ///
+    /// - `pressure` -- final pressure.
+    ///
+    /// # Errors
+    ///
+    /// If the current entropy is not available
+    /// or the final state can't be calculated,
+    /// a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::kilopascal;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut air = Fluid::from(Pure::Air)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<kilopascal>(100.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let mut compressed = air
+    ///     .isentropic_to_pressure(Pressure::new::<kilopascal>(500.0))
+    ///     .unwrap();
+    /// assert!(compressed.temperature().unwrap() > air.temperature().unwrap());
+    /// ```
+    pub fn isentropic_to_pressure(&mut self, pressure: Pressure) -> Result<Fluid, FluidStateError> {
+        let entropy = self.process_invariant(FluidParam::SMass)?;
+        self.undefined_clone()
+            .map_err(FluidStateError::UpdateFailed)?
+            .update(
+                FluidInput::pressure(pressure),
+                FluidInput::entropy(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+                    entropy,
+                )),
+            )
+    }
+
+    /// State after the isenthalpic process _(throttling)_ from the current state
+    /// to the specified pressure _(e.g., expansion valve)_.
+    ///
+    /// The current thermodynamic state is not affected.
+    ///
+    /// # Args
+    ///
+    /// - `pressure` -- final pressure.
+    ///
+    /// # Errors
+    ///
+    /// If the current enthalpy is not available
+    /// or the final state can't be calculated,
+    /// a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, Phase};
+    /// use rfluids::substance::Refrigerant;
+    /// use rfluids::uom::si::f64::{Pressure, Ratio};
+    /// use rfluids::uom::si::pressure::kilopascal;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let mut r134a = Fluid::from(Refrigerant::R134a)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<kilopascal>(1000.0)),
+    ///         FluidInput::quality(Ratio::new::<percent>(0.0)),
+    ///     )
+    ///     .unwrap();
+    /// let mut throttled = r134a
+    ///     .isenthalpic_to_pressure(Pressure::new::<kilopascal>(200.0))
+    ///     .unwrap();
+    /// assert_eq!(throttled.phase().unwrap(), Phase::TwoPhase);
+    /// ```
+    pub fn isenthalpic_to_pressure(
+        &mut self,
+        pressure: Pressure,
+    ) -> Result<Fluid, FluidStateError> {
+        let enthalpy = self.process_invariant(FluidParam::HMass)?;
+        self.undefined_clone()
+            .map_err(FluidStateError::UpdateFailed)?
+            .update(
+                FluidInput::pressure(pressure),
+                FluidInput::enthalpy(AvailableEnergy::new::<joule_per_kilogram>(enthalpy)),
+            )
+    }
+
+    fn process_invariant(&mut self, key: FluidParam) -> Result<f64, FluidStateError> {
+        self.output(key).map_err(FluidStateError::UnavailableOutput)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FluidOutputError;
+    use crate::io::Phase;
+    use crate::substance::{Pure, Refrigerant};
+    use crate::uom::si::f64::{Ratio, ThermodynamicTemperature};
+    use crate::uom::si::pressure::kilopascal;
+    use crate::uom::si::ratio::ratio;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use approx::assert_relative_eq;
+
+    fn air() -> Fluid {
+        Fluid::from(Pure::Air)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(100.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn isentropic_to_pressure_keeps_entropy() {
+        let mut sut = air();
+        let mut result = sut
+            .isentropic_to_pressure(Pressure::new::<kilopascal>(500.0))
+            .unwrap();
+        assert_relative_eq!(
+            result.output(FluidParam::SMass).unwrap(),
+            sut.output(FluidParam::SMass).unwrap(),
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            result.pressure().unwrap().get::<kilopascal>(),
+            500.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(
+            result.temperature().unwrap().value / sut.temperature().unwrap().value,
+            5.0_f64.powf(0.4 / 1.4),
+            max_relative = 1e-2
+        );
+        assert_relative_eq!(
+            sut.pressure().unwrap().get::<kilopascal>(),
+            100.0,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn isentropic_to_pressure_invalid_pressure_returns_err() {
+        let mut sut = air();
+        assert!(matches!(
+            sut.isentropic_to_pressure(Pressure::new::<kilopascal>(-100.0)),
+            Err(FluidStateError::UpdateFailed(_))
+        ));
+    }
+
+    #[test]
+    fn isenthalpic_to_pressure_without_state_returns_err() {
+        let mut sut = air();
+        sut.update_request = None;
+        assert_eq!(
+            sut.isenthalpic_to_pressure(Pressure::new::<kilopascal>(50.0))
+                .unwrap_err(),
+            FluidStateError::UnavailableOutput(FluidOutputError::UnavailableOutput(
+                FluidParam::HMass
+            ))
+        );
+    }
+
+    #[test]
+    fn isenthalpic_to_pressure_of_saturated_liquid_returns_two_phase_state() {
+        let mut sut = Fluid::from(Refrigerant::R134a)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(1000.0)),
+                FluidInput::quality(Ratio::new::<ratio>(0.0)),
+            )
+            .unwrap();
+        let mut result = sut
+            .isenthalpic_to_pressure(Pressure::new::<kilopascal>(200.0))
+            .unwrap();
+        assert_eq!(result.phase().unwrap(), Phase::TwoPhase);
+        let quality = result.quality().unwrap().value;
+        assert!(quality > 0.0 && quality < 1.0);
+        assert_relative_eq!(
+            result.output(FluidParam::HMass).unwrap(),
+            sut.output(FluidParam::HMass).unwrap(),
+            max_relative = 1e-9
+        );
+        assert!(result.temperature().unwrap() < sut.temperature().unwrap());
+    }
+
+    #[test]
+    fn isenthalpic_to_pressure_of_ideal_gas_keeps_temperature() {
+        let mut sut = air();
+        let mut result = sut
+            .isenthalpic_to_pressure(Pressure::new::<kilopascal>(10.0))
+            .unwrap();
+        assert_relative_eq!(
+            result.temperature().unwrap().value,
+            sut.temperature().unwrap().value,
+            max_relative = 1e-3
+        );
+    }
+}
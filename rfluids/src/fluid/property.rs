@@ -0,0 +1,127 @@
+use crate::error::FluidStateError;
+use crate::fluid::Fluid;
+use crate::io::{FluidInput, FluidParam};
+use crate::substance::Substance;
+
+/// One-shot output parameter value _(in SI units)_
+/// for the specified substance and thermodynamic state
+/// _(similar to CoolProp `PropsSI`)_.
+///
+/// It's handy when the [`Fluid`] instance itself is not needed.
+/// For multiple outputs of the same state, it's better to use [`Fluid`] directly,
+/// since the thermodynamic state is recalculated on each call.
+///
+/// # Args
+///
+/// - `output` -- output parameter key.
+/// - `input1` -- first input property.
+/// - `input2` -- second input property.
+/// - `substance` -- substance.
+///
+/// # Errors
+///
+/// - For invalid inputs, a [`FluidStateError::InvalidInputPair`]
+///   or [`FluidStateError::UpdateFailed`] is returned.
+/// - If the specified output can't be calculated for the valid state,
+///   a [`FluidStateError::UnavailableOutput`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use rfluids::fluid::property;
+/// use rfluids::io::{FluidInput, FluidParam};
+/// use rfluids::substance::Pure;
+/// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+/// use rfluids::uom::si::pressure::atmosphere;
+/// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+///
+/// let result = property(
+///     FluidParam::DMass,
+///     FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+///     FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+///     Pure::Water,
+/// )
+/// .unwrap();
+/// assert_relative_eq!(result, 998.2071504679284, max_relative = 1e-9);
+/// ```
+///
+/// # See also
+///
+/// - [`Fluid::update_many`]
+pub fn property(
+    output: FluidParam,
+    input1: FluidInput,
+    input2: FluidInput,
+    substance: impl Into<Substance>,
+) -> Result<f64, FluidStateError> {
+    Fluid::from(substance.into())
+        .update_many([(input1, input2)], &[output])
+        .remove(0)
+        .map(|values| values[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FluidOutputError;
+    use crate::substance::{IncompPure, Pure};
+    use crate::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+    use crate::uom::si::pressure::atmosphere;
+    use crate::uom::si::ratio::percent;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+
+    fn inputs() -> (FluidInput, FluidInput) {
+        (
+            FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+        )
+    }
+
+    #[test]
+    fn property_returns_same_value_as_fluid() {
+        let (input1, input2) = inputs();
+        let mut expected = Fluid::from(Pure::Water).update(input1, input2).unwrap();
+        assert_eq!(
+            property(FluidParam::CpMass, input1, input2, Pure::Water),
+            Ok(expected.output(FluidParam::CpMass).unwrap())
+        );
+    }
+
+    #[test]
+    fn property_invalid_inputs_returns_err() {
+        let (input1, _) = inputs();
+        assert_eq!(
+            property(FluidParam::DMass, input1, input1, Pure::Water),
+            Err(FluidStateError::InvalidInputPair(
+                FluidParam::P,
+                FluidParam::P
+            ))
+        );
+    }
+
+    #[test]
+    fn property_unavailable_output_returns_err() {
+        let (input1, input2) = inputs();
+        assert!(matches!(
+            property(FluidParam::Z, input1, input2, IncompPure::Water),
+            Err(FluidStateError::UnavailableOutput(
+                FluidOutputError::CalculationFailed(FluidParam::Z, _)
+            ))
+        ));
+    }
+
+    #[test]
+    fn property_invalid_state_returns_err() {
+        let (input1, _) = inputs();
+        assert!(matches!(
+            property(
+                FluidParam::DMass,
+                input1,
+                FluidInput::quality(Ratio::new::<percent>(-100.0)),
+                Pure::Water
+            ),
+            Err(FluidStateError::UpdateFailed(_))
+        ));
+    }
+}
@@ -0,0 +1,1159 @@
+use crate::error::{CoolPropError, FluidOutputError, FluidStateError};
+use crate::fluid::common::new_backend;
+use crate::fluid::Fluid;
+use crate::io::{FluidInput, FluidInputPair, FluidParam, FluidTrivialParam};
+use crate::native::AbstractState;
+use crate::substance::Substance;
+use crate::uom::si::available_energy::joule_per_kilogram;
+use crate::uom::si::f64::{
+    AvailableEnergy, MassDensity, Pressure, Ratio, SpecificHeatCapacity, ThermodynamicTemperature,
+};
+use crate::uom::si::mass_density::kilogram_per_cubic_meter;
+use crate::uom::si::pressure::pascal;
+use crate::uom::si::ratio::ratio;
+use crate::uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use crate::uom::si::thermodynamic_temperature::kelvin;
+use crate::DefinedState;
+use std::collections::HashMap;
+
+/// Saturation properties at the specified pressure.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SaturationContext {
+    /// Saturation pressure.
+    pub pressure: Pressure,
+    /// Saturation temperature
+    /// _(bubble point temperature for zeotropic mixtures)_.
+    pub temperature: ThermodynamicTemperature,
+    /// Saturated liquid mass density.
+    pub liquid_density: MassDensity,
+    /// Saturated vapor mass density.
+    pub vapor_density: MassDensity,
+    /// Latent heat of vaporization
+    /// _(saturated vapor enthalpy minus saturated liquid enthalpy)_.
+    pub latent_heat: AvailableEnergy,
+}
+
+/// Snapshot of the main thermodynamic properties
+/// and optional additional outputs.
+///
+/// With the `approx` feature enabled, it implements
+/// [`AbsDiffEq`](https://docs.rs/approx/latest/approx/trait.AbsDiffEq.html) and
+/// [`RelativeEq`](https://docs.rs/approx/latest/approx/trait.RelativeEq.html)
+/// _(all properties are compared in SI units,
+/// and additional outputs must have the same keys)_, e.g., for use in tests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FluidState {
+    /// Pressure.
+    pub pressure: Pressure,
+    /// Temperature.
+    pub temperature: ThermodynamicTemperature,
+    /// Vapor quality.
+    pub quality: Ratio,
+    /// Mass density.
+    pub density: MassDensity,
+    /// Mass specific enthalpy.
+    pub enthalpy: AvailableEnergy,
+    /// Mass specific entropy.
+    pub entropy: SpecificHeatCapacity,
+    /// Additional output parameters values _(in SI units)_
+    /// _(see [`Fluid::snapshot`])_.
+    pub outputs: HashMap<FluidParam, f64>,
+}
+
+impl TryFrom<&AbstractState> for FluidState {
+    type Error = CoolPropError;
+
+    fn try_from(value: &AbstractState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pressure: Pressure::new::<pascal>(value.keyed_output(FluidParam::P)?),
+            temperature: ThermodynamicTemperature::new::<kelvin>(
+                value.keyed_output(FluidParam::T)?,
+            ),
+            quality: Ratio::new::<ratio>(value.keyed_output(FluidParam::Q)?),
+            density: MassDensity::new::<kilogram_per_cubic_meter>(
+                value.keyed_output(FluidParam::DMass)?,
+            ),
+            enthalpy: AvailableEnergy::new::<joule_per_kilogram>(
+                value.keyed_output(FluidParam::HMass)?,
+            ),
+            entropy: SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+                value.keyed_output(FluidParam::SMass)?,
+            ),
+            outputs: HashMap::new(),
+        })
+    }
+}
+
+#[cfg(feature = "approx")]
+mod approximation {
+    use super::*;
+    use approx::{AbsDiffEq, RelativeEq};
+
+    impl FluidState {
+        fn si_values(&self) -> [f64; 6] {
+            [
+                self.pressure.value,
+                self.temperature.value,
+                self.quality.value,
+                self.density.value,
+                self.enthalpy.value,
+                self.entropy.value,
+            ]
+        }
+
+        fn all_close(&self, other: &Self, close: impl Fn(&f64, &f64) -> bool) -> bool {
+            self.si_values()
+                .iter()
+                .zip(other.si_values().iter())
+                .all(|(a, b)| close(a, b))
+                && self.outputs.len() == other.outputs.len()
+                && self
+                    .outputs
+                    .iter()
+                    .all(|(key, a)| other.outputs.get(key).is_some_and(|b| close(a, b)))
+        }
+    }
+
+    impl AbsDiffEq for FluidState {
+        type Epsilon = f64;
+
+        fn default_epsilon() -> Self::Epsilon {
+            f64::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+            self.all_close(other, |a, b| a.abs_diff_eq(b, epsilon))
+        }
+    }
+
+    impl RelativeEq for FluidState {
+        fn default_max_relative() -> Self::Epsilon {
+            f64::default_max_relative()
+        }
+
+        fn relative_eq(
+            &self,
+            other: &Self,
+            epsilon: Self::Epsilon,
+            max_relative: Self::Epsilon,
+        ) -> bool {
+            self.all_close(other, |a, b| a.relative_eq(b, epsilon, max_relative))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::substance::Pure;
+        use approx::{assert_relative_eq, assert_relative_ne};
+
+        fn water_state() -> FluidState {
+            let backend = {
+                let mut backend = AbstractState::new("HEOS", "Water").unwrap();
+                backend
+                    .update(FluidInputPair::PT, 101325.0, 293.15)
+                    .unwrap();
+                backend
+            };
+            FluidState::try_from(&backend).unwrap()
+        }
+
+        #[test]
+        fn relative_eq_with_close_values_returns_true() {
+            let sut = water_state();
+            let other = FluidState {
+                density: sut.density * (1.0 + 1e-9),
+                ..sut.clone()
+            };
+            assert_relative_eq!(sut, other, max_relative = 1e-6);
+        }
+
+        #[test]
+        fn relative_eq_with_different_values_returns_false() {
+            let sut = water_state();
+            let other = FluidState {
+                enthalpy: sut.enthalpy * 1.01,
+                ..sut.clone()
+            };
+            assert_relative_ne!(sut, other, max_relative = 1e-6);
+        }
+
+        #[test]
+        fn relative_eq_compares_additional_outputs() {
+            let sut = FluidState {
+                outputs: HashMap::from([(FluidParam::CpMass, 4184.0)]),
+                ..water_state()
+            };
+            let close = FluidState {
+                outputs: HashMap::from([(FluidParam::CpMass, 4184.0 * (1.0 + 1e-9))]),
+                ..water_state()
+            };
+            let different = FluidState {
+                outputs: HashMap::from([(FluidParam::CpMass, 4184.0 * 1.01)]),
+                ..water_state()
+            };
+            let other_keys = FluidState {
+                outputs: HashMap::from([(FluidParam::CvMass, 4184.0)]),
+                ..water_state()
+            };
+            assert_relative_eq!(sut, close, max_relative = 1e-6);
+            assert_relative_ne!(sut, different, max_relative = 1e-6);
+            assert_relative_ne!(sut, other_keys, max_relative = 1e-6);
+            assert_relative_ne!(sut, water_state(), max_relative = 1e-6);
+        }
+
+        #[test]
+        fn abs_diff_eq_with_snapshot_of_same_state_returns_true() {
+            let mut fluid = Fluid::from(Pure::Water)
+                .update(
+                    FluidInput::pressure(Pressure::new::<pascal>(101325.0)),
+                    FluidInput::temperature(ThermodynamicTemperature::new::<kelvin>(293.15)),
+                )
+                .unwrap();
+            assert!(fluid
+                .snapshot(&[])
+                .unwrap()
+                .abs_diff_eq(&water_state(), 1e-9));
+        }
+    }
+}
+
+impl Fluid<DefinedState> {
+    /// Two-phase states at the specified pressure for each of the specified qualities.
+    ///
+    /// A single backend is reused for all updates,
+    /// which makes it much cheaper than creating a new [`Fluid`] for each quality.
+    ///
+    /// # Args
+    ///
+    /// - `substance` -- substance.
+    /// - `pressure` -- saturation pressure.
+    /// - `qualities` -- vapor qualities.
+    ///
+    /// # Errors
+    ///
+    /// Each result is evaluated independently.
+    /// For invalid inputs _(e.g., quality out of range, supercritical pressure
+    /// or incompressible substance)_, the corresponding result is a [`FluidStateError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, Ratio};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let qualities = [0.0, 50.0, 100.0].map(Ratio::new::<percent>);
+    /// let result = Fluid::quality_sweep(
+    ///     Pure::Water,
+    ///     Pressure::new::<atmosphere>(1.0),
+    ///     &qualities,
+    /// );
+    /// assert_eq!(result.len(), 3);
+    /// assert!(result.iter().all(|r| r.is_ok()));
+    /// ```
+    pub fn quality_sweep(
+        substance: impl Into<Substance>,
+        pressure: Pressure,
+        qualities: &[Ratio],
+    ) -> Vec<Result<FluidState, FluidStateError>> {
+        let mut backend = match new_backend(&substance.into()) {
+            Ok(backend) => backend,
+            Err(e) => {
+                return qualities
+                    .iter()
+                    .map(|_| Err(FluidStateError::UpdateFailed(e.clone())))
+                    .collect()
+            }
+        };
+        qualities
+            .iter()
+            .map(|quality| {
+                backend
+                    .update(FluidInputPair::PQ, pressure.value, quality.value)
+                    .and_then(|_| FluidState::try_from(&backend))
+                    .map_err(FluidStateError::UpdateFailed)
+            })
+            .collect()
+    }
+
+    /// Vapor pressure curve _(saturated liquid temperatures and pressures)_
+    /// within the specified temperature range.
+    ///
+    /// Temperatures are evenly spaced, and the upper bound is clamped
+    /// to the critical temperature. A single backend is reused for all updates.
+    ///
+    /// # Args
+    ///
+    /// - `substance` -- substance.
+    /// - `t_min` -- minimum temperature.
+    /// - `t_max` -- maximum temperature _(clamped to the critical temperature)_.
+    /// - `steps` -- number of intervals _(the curve contains `steps + 1` points)_.
+    ///
+    /// # Errors
+    ///
+    /// - If `t_min` or `t_max` is not finite,
+    ///   a [`FluidStateError::NonFiniteInput`] is returned.
+    /// - If `t_min` is above the critical temperature,
+    ///   a [`FluidStateError::SupercriticalTemperature`] is returned.
+    /// - If `t_min` is above `t_max`,
+    ///   a [`FluidStateError::InvalidInput`] is returned.
+    /// - If any of the points can't be calculated
+    ///   _(e.g., temperature below the triple point or incompressible substance)_,
+    ///   a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::ThermodynamicTemperature;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let result = Fluid::vapor_pressure_curve(
+    ///     Pure::Water,
+    ///     ThermodynamicTemperature::new::<degree_celsius>(20.0),
+    ///     ThermodynamicTemperature::new::<degree_celsius>(100.0),
+    ///     8,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(result.len(), 9);
+    /// ```
+    pub fn vapor_pressure_curve(
+        substance: impl Into<Substance>,
+        t_min: ThermodynamicTemperature,
+        t_max: ThermodynamicTemperature,
+        steps: usize,
+    ) -> Result<Vec<(ThermodynamicTemperature, Pressure)>, FluidStateError> {
+        if !t_min.value.is_finite() || !t_max.value.is_finite() {
+            return Err(FluidStateError::NonFiniteInput(FluidParam::T));
+        }
+        let mut backend = new_backend(&substance.into()).map_err(FluidStateError::UpdateFailed)?;
+        let t_critical = backend
+            .keyed_output(FluidTrivialParam::TCritical)
+            .map_err(FluidStateError::UpdateFailed)?;
+        if t_min.value > t_critical {
+            return Err(FluidStateError::SupercriticalTemperature {
+                specified: t_min,
+                critical: ThermodynamicTemperature::new::<kelvin>(t_critical),
+            });
+        }
+        if t_min > t_max {
+            return Err(FluidStateError::InvalidInput(FluidParam::T, t_min.value));
+        }
+        let (t_min, t_max) = (t_min.value, t_max.value.min(t_critical));
+        let step = if steps == 0 {
+            0.0
+        } else {
+            (t_max - t_min) / steps as f64
+        };
+        (0..=steps)
+            .map(|i| {
+                let temperature = if i == steps {
+                    t_max
+                } else {
+                    t_min + step * i as f64
+                };
+                backend
+                    .update(FluidInputPair::QT, 0.0, temperature)
+                    .and_then(|_| backend.keyed_output(FluidParam::P))
+                    .map(|pressure| {
+                        (
+                            ThermodynamicTemperature::new::<kelvin>(temperature),
+                            Pressure::new::<pascal>(pressure),
+                        )
+                    })
+                    .map_err(FluidStateError::UpdateFailed)
+            })
+            .collect()
+    }
+
+    /// Saturation properties at the current pressure
+    /// _(regardless of the current phase state)_.
+    ///
+    /// The current thermodynamic state and cached outputs are not affected.
+    ///
+    /// # Errors
+    ///
+    /// If saturation properties are not available at the current pressure
+    /// _(e.g., for supercritical pressures or incompressible substances)_,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// let result = water.saturation_context().unwrap();
+    /// assert_relative_eq!(
+    ///     result.temperature.get::<degree_celsius>(),
+    ///     99.97,
+    ///     max_relative = 1e-4
+    /// );
+    /// ```
+    pub fn saturation_context(&mut self) -> Result<SaturationContext, FluidOutputError> {
+        let pressure = self.pressure()?;
+        let mut backend = new_backend(&self.substance)
+            .map_err(|e| FluidOutputError::CalculationFailed(FluidParam::T, e))?;
+        let (temperature, liquid_density, liquid_enthalpy) =
+            saturated(&mut backend, pressure, 0.0)?;
+        let (_, vapor_density, vapor_enthalpy) = saturated(&mut backend, pressure, 1.0)?;
+        Ok(SaturationContext {
+            pressure,
+            temperature: ThermodynamicTemperature::new::<kelvin>(temperature),
+            liquid_density: MassDensity::new::<kilogram_per_cubic_meter>(liquid_density),
+            vapor_density: MassDensity::new::<kilogram_per_cubic_meter>(vapor_density),
+            latent_heat: AvailableEnergy::new::<joule_per_kilogram>(
+                vapor_enthalpy - liquid_enthalpy,
+            ),
+        })
+    }
+
+    /// Enthalpy of vaporization _(latent heat)_ at the current pressure
+    /// _(dew point enthalpy minus bubble point enthalpy,
+    /// regardless of the current phase state)_.
+    ///
+    /// The current thermodynamic state and cached outputs are not affected.
+    ///
+    /// # Errors
+    ///
+    /// For supercritical pressures _(where no latent heat exists)_
+    /// or incompressible substances, a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::FluidInput;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::available_energy::kilojoule_per_kilogram;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_relative_eq!(
+    ///     water
+    ///         .enthalpy_of_vaporization()
+    ///         .unwrap()
+    ///         .get::<kilojoule_per_kilogram>(),
+    ///     2256.4,
+    ///     max_relative = 1e-4
+    /// );
+    /// ```
+    pub fn enthalpy_of_vaporization(&mut self) -> Result<AvailableEnergy, FluidStateError> {
+        let pressure = self
+            .pressure()
+            .map_err(FluidStateError::UnavailableOutput)?;
+        let dew_point_enthalpy = self
+            .dew_point_at_pressure(pressure)?
+            .specific_enthalpy()
+            .map_err(FluidStateError::UnavailableOutput)?;
+        let bubble_point_enthalpy = self
+            .bubble_point_at_pressure(pressure)?
+            .specific_enthalpy()
+            .map_err(FluidStateError::UnavailableOutput)?;
+        Ok(dew_point_enthalpy - bubble_point_enthalpy)
+    }
+}
+
+impl<S> Fluid<S> {
+    /// Bubble point _(saturated liquid, quality 0)_ at the specified pressure.
+    ///
+    /// # Args
+    ///
+    /// - `pressure` -- saturation pressure.
+    ///
+    /// # Errors
+    ///
+    /// For incompressible substances _(which don't support quality inputs)_
+    /// or invalid pressure, a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::Pressure;
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let water = Fluid::from(Pure::Water);
+    /// let mut result = water
+    ///     .bubble_point_at_pressure(Pressure::new::<atmosphere>(1.0))
+    ///     .unwrap();
+    /// assert_relative_eq!(
+    ///     result.temperature().unwrap().get::<degree_celsius>(),
+    ///     99.97,
+    ///     max_relative = 1e-4
+    /// );
+    /// ```
+    pub fn bubble_point_at_pressure(
+        &self,
+        pressure: Pressure,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        self.saturated_state(FluidInput::pressure(pressure), 0.0)
+    }
+
+    /// Dew point _(saturated vapor, quality 1)_ at the specified pressure.
+    ///
+    /// # Args
+    ///
+    /// - `pressure` -- saturation pressure.
+    ///
+    /// # Errors
+    ///
+    /// For incompressible substances _(which don't support quality inputs)_
+    /// or invalid pressure, a [`FluidStateError`] is returned.
+    pub fn dew_point_at_pressure(
+        &self,
+        pressure: Pressure,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        self.saturated_state(FluidInput::pressure(pressure), 1.0)
+    }
+
+    /// Bubble point _(saturated liquid, quality 0)_ at the specified temperature.
+    ///
+    /// # Args
+    ///
+    /// - `temperature` -- saturation temperature.
+    ///
+    /// # Errors
+    ///
+    /// For incompressible substances _(which don't support quality inputs)_
+    /// or invalid temperature, a [`FluidStateError`] is returned.
+    pub fn bubble_point_at_temperature(
+        &self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        self.saturated_state(FluidInput::temperature(temperature), 0.0)
+    }
+
+    /// Dew point _(saturated vapor, quality 1)_ at the specified temperature.
+    ///
+    /// # Args
+    ///
+    /// - `temperature` -- saturation temperature.
+    ///
+    /// # Errors
+    ///
+    /// For incompressible substances _(which don't support quality inputs)_
+    /// or invalid temperature, a [`FluidStateError`] is returned.
+    pub fn dew_point_at_temperature(
+        &self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        self.saturated_state(FluidInput::temperature(temperature), 1.0)
+    }
+
+    /// Saturation pressure _(bubble point pressure for zeotropic mixtures)_
+    /// at the specified temperature.
+    ///
+    /// The current thermodynamic state and cached outputs are not affected.
+    ///
+    /// # Args
+    ///
+    /// - `temperature` -- saturation temperature.
+    ///
+    /// # Errors
+    ///
+    /// If the specified temperature is above the critical temperature,
+    /// a [`FluidStateError::SupercriticalTemperature`] is returned.
+    /// For incompressible substances _(which don't support quality inputs)_
+    /// or invalid temperature, a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::ThermodynamicTemperature;
+    /// use rfluids::uom::si::pressure::kilopascal;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let water = Fluid::from(Pure::Water);
+    /// let result = water
+    ///     .saturation_pressure(ThermodynamicTemperature::new::<degree_celsius>(100.0))
+    ///     .unwrap();
+    /// assert_relative_eq!(result.get::<kilopascal>(), 101.418, max_relative = 1e-4);
+    /// ```
+    pub fn saturation_pressure(
+        &self,
+        temperature: ThermodynamicTemperature,
+    ) -> Result<Pressure, FluidStateError> {
+        if matches!(
+            self.substance,
+            Substance::IncompPure(_) | Substance::BinaryMix(_)
+        ) {
+            return Err(FluidStateError::UnsupportedSubstance(
+                self.substance.name().into(),
+            ));
+        }
+        let mut backend = self
+            .backend
+            .try_clone()
+            .map_err(FluidStateError::UpdateFailed)?;
+        let critical = ThermodynamicTemperature::new::<kelvin>(
+            backend
+                .keyed_output(FluidTrivialParam::TCritical)
+                .map_err(FluidStateError::UpdateFailed)?,
+        );
+        if temperature > critical {
+            return Err(FluidStateError::SupercriticalTemperature {
+                specified: temperature,
+                critical,
+            });
+        }
+        backend
+            .update(FluidInputPair::QT, 0.0, temperature.value)
+            .and_then(|_| backend.keyed_output(FluidParam::P))
+            .map(Pressure::new::<pascal>)
+            .map_err(FluidStateError::UpdateFailed)
+    }
+
+    /// Saturation curve from the triple point to the critical point.
+    ///
+    /// Each point contains the saturation temperature,
+    /// the saturation pressure _(bubble point pressure for zeotropic mixtures)_,
+    /// the saturated liquid enthalpy and the saturated vapor enthalpy.
+    /// Temperatures are evenly spaced, and the last point is the critical point,
+    /// where both enthalpies are equal to the critical enthalpy.
+    ///
+    /// The current thermodynamic state and cached outputs are not affected.
+    ///
+    /// # Args
+    ///
+    /// - `points` -- number of points _(including both endpoints)_.
+    ///
+    /// # Errors
+    ///
+    /// For incompressible substances _(which don't support quality inputs)_
+    /// or if any of the points can't be calculated,
+    /// a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::pressure::kilopascal;
+    /// use rfluids::uom::si::thermodynamic_temperature::kelvin;
+    ///
+    /// let result = Fluid::from(Pure::Water).saturation_curve(10).unwrap();
+    /// assert_eq!(result.len(), 10);
+    /// let (temperature, pressure, bubble, dew) = result[9];
+    /// assert_relative_eq!(temperature.get::<kelvin>(), 647.096);
+    /// assert_relative_eq!(pressure.get::<kilopascal>(), 22064.0, max_relative = 1e-6);
+    /// assert_relative_eq!(bubble.value, dew.value, max_relative = 1e-6);
+    /// ```
+    pub fn saturation_curve(
+        &self,
+        points: usize,
+    ) -> Result<
+        Vec<(
+            ThermodynamicTemperature,
+            Pressure,
+            AvailableEnergy,
+            AvailableEnergy,
+        )>,
+        FluidStateError,
+    > {
+        if matches!(
+            self.substance,
+            Substance::IncompPure(_) | Substance::BinaryMix(_)
+        ) {
+            return Err(FluidStateError::UnsupportedSubstance(
+                self.substance.name().into(),
+            ));
+        }
+        let mut backend = self
+            .backend
+            .try_clone()
+            .map_err(FluidStateError::UpdateFailed)?;
+        let trivial = |key| {
+            backend
+                .keyed_output(key)
+                .map_err(FluidStateError::UpdateFailed)
+        };
+        let t_triple = trivial(FluidTrivialParam::TTriple)?;
+        let t_critical = trivial(FluidTrivialParam::TCritical)?;
+        let step = if points > 1 {
+            (t_critical - t_triple) / (points - 1) as f64
+        } else {
+            0.0
+        };
+        (0..points)
+            .map(|i| {
+                let temperature = if points > 1 && i == points - 1 {
+                    t_critical
+                } else {
+                    t_triple + step * i as f64
+                };
+                let (pressure, bubble, dew) = saturation_curve_point(&mut backend, temperature)
+                    .or_else(|e| {
+                        if temperature == t_critical {
+                            critical_point(&mut backend)
+                        } else {
+                            Err(e)
+                        }
+                    })
+                    .map_err(FluidStateError::UpdateFailed)?;
+                Ok((
+                    ThermodynamicTemperature::new::<kelvin>(temperature),
+                    Pressure::new::<pascal>(pressure),
+                    AvailableEnergy::new::<joule_per_kilogram>(bubble),
+                    AvailableEnergy::new::<joule_per_kilogram>(dew),
+                ))
+            })
+            .collect()
+    }
+
+    fn saturated_state(
+        &self,
+        input: FluidInput,
+        quality: f64,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        if matches!(
+            self.substance,
+            Substance::IncompPure(_) | Substance::BinaryMix(_)
+        ) {
+            return Err(FluidStateError::UnsupportedSubstance(
+                self.substance.name().into(),
+            ));
+        }
+        Fluid::from(self.substance.clone())
+            .update(input, FluidInput::quality(Ratio::new::<ratio>(quality)))
+    }
+}
+
+fn saturation_curve_point(
+    backend: &mut AbstractState,
+    temperature: f64,
+) -> Result<(f64, f64, f64), CoolPropError> {
+    backend.update(FluidInputPair::QT, 0.0, temperature)?;
+    let pressure = backend.keyed_output(FluidParam::P)?;
+    let bubble = backend.keyed_output(FluidParam::HMass)?;
+    backend.update(FluidInputPair::QT, 1.0, temperature)?;
+    Ok((pressure, bubble, backend.keyed_output(FluidParam::HMass)?))
+}
+
+fn critical_point(backend: &mut AbstractState) -> Result<(f64, f64, f64), CoolPropError> {
+    let density = backend.keyed_output(FluidTrivialParam::DMassCritical)?;
+    let temperature = backend.keyed_output(FluidTrivialParam::TCritical)?;
+    backend.update(FluidInputPair::DMassT, density, temperature)?;
+    let enthalpy = backend.keyed_output(FluidParam::HMass)?;
+    Ok((backend.keyed_output(FluidParam::P)?, enthalpy, enthalpy))
+}
+
+fn saturated(
+    backend: &mut AbstractState,
+    pressure: Pressure,
+    quality: f64,
+) -> Result<(f64, f64, f64), FluidOutputError> {
+    backend
+        .update(FluidInputPair::PQ, pressure.value, quality)
+        .map_err(|e| FluidOutputError::CalculationFailed(FluidParam::T, e))?;
+    let output = |key| {
+        backend
+            .keyed_output(key)
+            .map_err(|e| FluidOutputError::CalculationFailed(key, e))
+    };
+    Ok((
+        output(FluidParam::T)?,
+        output(FluidParam::DMass)?,
+        output(FluidParam::HMass)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FluidInput;
+    use crate::substance::{IncompPure, Pure, Refrigerant};
+    use crate::uom::si::pressure::{kilopascal, megapascal};
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn saturation_pressure_matches_bubble_point() {
+        let sut = Fluid::from(Refrigerant::R134a);
+        let temperature = ThermodynamicTemperature::new::<degree_celsius>(0.0);
+        let result = sut.saturation_pressure(temperature).unwrap();
+        let mut expected = sut.bubble_point_at_temperature(temperature).unwrap();
+        assert_relative_eq!(
+            result.value,
+            expected.pressure().unwrap().value,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn saturation_pressure_above_critical_temperature_returns_err() {
+        let sut = Fluid::from(Pure::Water);
+        let temperature = ThermodynamicTemperature::new::<degree_celsius>(400.0);
+        assert_eq!(
+            sut.saturation_pressure(temperature).unwrap_err(),
+            FluidStateError::SupercriticalTemperature {
+                specified: temperature,
+                critical: ThermodynamicTemperature::new::<kelvin>(647.096),
+            }
+        );
+    }
+
+    #[test]
+    fn saturation_pressure_incomp_returns_err() {
+        let sut = Fluid::from(IncompPure::Water);
+        assert_eq!(
+            sut.saturation_pressure(ThermodynamicTemperature::new::<degree_celsius>(20.0))
+                .unwrap_err(),
+            FluidStateError::UnsupportedSubstance("Water".into())
+        );
+    }
+
+    #[test]
+    fn saturation_curve_spans_triple_to_critical_point() {
+        let sut = Fluid::from(Refrigerant::R134a);
+        let result = sut.saturation_curve(20).unwrap();
+        assert_eq!(result.len(), 20);
+        assert_relative_eq!(result[0].0.value, 169.85);
+        assert_relative_eq!(result[19].0.value, 374.21);
+        assert!(result
+            .windows(2)
+            .all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+        assert!(result[..19].iter().all(|p| p.2 < p.3));
+        assert_relative_eq!(result[19].2.value, result[19].3.value, max_relative = 1e-6);
+        assert_relative_eq!(
+            result[19].1.value,
+            sut.saturation_pressure(result[19].0).unwrap().value,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn saturation_curve_single_point_returns_triple_point() {
+        let result = Fluid::from(Pure::Water).saturation_curve(1).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_relative_eq!(result[0].0.value, 273.16);
+        assert!(Fluid::from(Pure::Water)
+            .saturation_curve(0)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn saturation_curve_critical_point_fallback_returns_critical_state() {
+        let mut backend = new_backend(&Pure::Water.into()).unwrap();
+        let (pressure, bubble, dew) = critical_point(&mut backend).unwrap();
+        assert_relative_eq!(pressure, 22.064e6, max_relative = 1e-6);
+        assert_eq!(bubble, dew);
+        assert_relative_eq!(bubble, 2084256.255907945, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn saturation_curve_incomp_returns_err() {
+        let result = Fluid::from(IncompPure::Water).saturation_curve(10);
+        assert_eq!(
+            result.unwrap_err(),
+            FluidStateError::UnsupportedSubstance("Water".into())
+        );
+    }
+
+    #[test]
+    fn enthalpy_of_vaporization_matches_saturation_context() {
+        let mut sut = Fluid::from(Refrigerant::R134a)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(500.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(40.0)),
+            )
+            .unwrap();
+        let result = sut.enthalpy_of_vaporization().unwrap();
+        assert!(result.value > 0.0);
+        assert_relative_eq!(
+            result.value,
+            sut.saturation_context().unwrap().latent_heat.value,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn enthalpy_of_vaporization_supercritical_returns_err() {
+        let mut sut = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<megapascal>(25.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(400.0)),
+            )
+            .unwrap();
+        assert!(matches!(
+            sut.enthalpy_of_vaporization(),
+            Err(FluidStateError::UpdateFailed(_))
+        ));
+    }
+
+    #[test]
+    fn enthalpy_of_vaporization_incomp_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(500.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(40.0)),
+            )
+            .unwrap();
+        assert_eq!(
+            sut.enthalpy_of_vaporization().unwrap_err(),
+            FluidStateError::UnsupportedSubstance("Water".into())
+        );
+    }
+
+    #[test]
+    fn saturation_context_superheated_r134a_returns_saturation_temperature_at_its_pressure() {
+        let mut sut = Fluid::from(Refrigerant::R134a)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(500.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(40.0)),
+            )
+            .unwrap();
+        let mut expected = AbstractState::new("HEOS", "R134a").unwrap();
+        expected.update(FluidInputPair::PQ, 500e3, 0.0).unwrap();
+        let result = sut.saturation_context().unwrap();
+        assert_relative_eq!(result.pressure.value, 500e3, max_relative = 1e-6);
+        assert_relative_eq!(
+            result.temperature.value,
+            expected.keyed_output(FluidParam::T).unwrap(),
+            max_relative = 1e-9
+        );
+        assert!(result.temperature < sut.temperature().unwrap());
+        assert!(result.liquid_density > result.vapor_density);
+        assert!(result.latent_heat.value > 0.0);
+        assert_eq!(sut.outputs.len(), 2);
+    }
+
+    #[test]
+    fn quality_sweep_returns_states_with_monotonic_enthalpy() {
+        let qualities = [0.0, 0.25, 0.5, 0.75, 1.0].map(Ratio::new::<ratio>);
+        let result: Vec<FluidState> = Fluid::quality_sweep(
+            Pure::Water,
+            Pressure::new::<kilopascal>(101.325),
+            &qualities,
+        )
+        .into_iter()
+        .map(|r| r.unwrap())
+        .collect();
+        assert_eq!(result.len(), qualities.len());
+        for (state, quality) in result.iter().zip(qualities) {
+            assert_relative_eq!(state.quality.value, quality.value);
+            assert_relative_eq!(state.temperature.value, result[0].temperature.value);
+        }
+        assert!(result.windows(2).all(|w| w[0].enthalpy < w[1].enthalpy));
+        assert!(result.windows(2).all(|w| w[0].density > w[1].density));
+    }
+
+    #[test]
+    fn quality_sweep_invalid_quality_returns_err_only_for_it() {
+        let qualities = [0.0, 1.5, 1.0].map(Ratio::new::<ratio>);
+        let result = Fluid::quality_sweep(
+            Pure::Water,
+            Pressure::new::<kilopascal>(101.325),
+            &qualities,
+        );
+        assert!(result[0].is_ok());
+        assert!(matches!(result[1], Err(FluidStateError::UpdateFailed(_))));
+        assert!(result[2].is_ok());
+    }
+
+    #[test]
+    fn quality_sweep_incomp_returns_err_for_each_quality() {
+        let qualities = [0.0, 1.0].map(Ratio::new::<ratio>);
+        let result = Fluid::quality_sweep(
+            IncompPure::Water,
+            Pressure::new::<kilopascal>(101.325),
+            &qualities,
+        );
+        assert!(result.iter().all(|r| r.is_err()));
+    }
+
+    #[test]
+    fn vapor_pressure_curve_is_monotonic_and_spans_requested_range() {
+        let (t_min, t_max) = (
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            ThermodynamicTemperature::new::<degree_celsius>(200.0),
+        );
+        let result = Fluid::vapor_pressure_curve(Pure::Water, t_min, t_max, 18).unwrap();
+        assert_eq!(result.len(), 19);
+        assert_relative_eq!(result[0].0.value, t_min.value);
+        assert_relative_eq!(result[18].0.value, t_max.value);
+        assert!(result
+            .windows(2)
+            .all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+        assert_relative_eq!(result[8].1.get::<kilopascal>(), 101.42, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn vapor_pressure_curve_is_clamped_to_critical_temperature() {
+        let result = Fluid::vapor_pressure_curve(
+            Pure::Water,
+            ThermodynamicTemperature::new::<degree_celsius>(300.0),
+            ThermodynamicTemperature::new::<degree_celsius>(500.0),
+            4,
+        )
+        .unwrap();
+        assert_relative_eq!(result[4].0.value, 647.096);
+        assert_relative_eq!(
+            result[4].1.get::<kilopascal>(),
+            22064.0,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn vapor_pressure_curve_supercritical_min_temperature_returns_err() {
+        let t_min = ThermodynamicTemperature::new::<degree_celsius>(400.0);
+        let result = Fluid::vapor_pressure_curve(
+            Pure::Water,
+            t_min,
+            ThermodynamicTemperature::new::<degree_celsius>(500.0),
+            4,
+        );
+        assert_eq!(
+            result,
+            Err(FluidStateError::SupercriticalTemperature {
+                specified: t_min,
+                critical: ThermodynamicTemperature::new::<kelvin>(647.096),
+            })
+        );
+    }
+
+    #[test]
+    fn vapor_pressure_curve_reversed_temperature_range_returns_err() {
+        let t_min = ThermodynamicTemperature::new::<degree_celsius>(80.0);
+        let result = Fluid::vapor_pressure_curve(
+            Pure::Water,
+            t_min,
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            4,
+        );
+        assert_eq!(
+            result,
+            Err(FluidStateError::InvalidInput(FluidParam::T, t_min.value))
+        );
+    }
+
+    #[test]
+    fn vapor_pressure_curve_non_finite_temperature_returns_err() {
+        for (t_min, t_max) in [(f64::NAN, 373.15), (293.15, f64::INFINITY)] {
+            let result = Fluid::vapor_pressure_curve(
+                Pure::Water,
+                ThermodynamicTemperature::new::<kelvin>(t_min),
+                ThermodynamicTemperature::new::<kelvin>(t_max),
+                4,
+            );
+            assert_eq!(result, Err(FluidStateError::NonFiniteInput(FluidParam::T)));
+        }
+    }
+
+    #[test]
+    fn vapor_pressure_curve_incomp_returns_err() {
+        let result = Fluid::vapor_pressure_curve(
+            IncompPure::Water,
+            ThermodynamicTemperature::new::<degree_celsius>(20.0),
+            ThermodynamicTemperature::new::<degree_celsius>(80.0),
+            4,
+        );
+        assert!(matches!(result, Err(FluidStateError::UpdateFailed(_))));
+    }
+
+    #[test]
+    fn bubble_and_dew_points_of_pure_substance_have_same_temperature() {
+        let sut = Fluid::from(Pure::Water);
+        let pressure = Pressure::new::<kilopascal>(101.325);
+        let mut bubble_point = sut.bubble_point_at_pressure(pressure).unwrap();
+        let mut dew_point = sut.dew_point_at_pressure(pressure).unwrap();
+        assert_relative_eq!(
+            bubble_point.temperature().unwrap().value,
+            dew_point.temperature().unwrap().value
+        );
+        assert_relative_eq!(bubble_point.output(FluidParam::Q).unwrap(), 0.0);
+        assert_relative_eq!(dew_point.output(FluidParam::Q).unwrap(), 1.0);
+        assert!(dew_point.density().unwrap() < bubble_point.density().unwrap());
+    }
+
+    #[test]
+    fn bubble_and_dew_points_of_zeotropic_mixture_have_temperature_glide() {
+        let sut = Fluid::from(Refrigerant::R407C);
+        let pressure = Pressure::new::<kilopascal>(500.0);
+        let mut bubble_point = sut.bubble_point_at_pressure(pressure).unwrap();
+        let mut dew_point = sut.dew_point_at_pressure(pressure).unwrap();
+        assert!(bubble_point.temperature().unwrap() < dew_point.temperature().unwrap());
+    }
+
+    #[test]
+    fn bubble_and_dew_points_at_temperature_return_saturation_pressure() {
+        let sut = Fluid::from(Refrigerant::R134a);
+        let temperature = ThermodynamicTemperature::new::<degree_celsius>(0.0);
+        let mut bubble_point = sut.bubble_point_at_temperature(temperature).unwrap();
+        let mut dew_point = sut.dew_point_at_temperature(temperature).unwrap();
+        assert_relative_eq!(
+            bubble_point.pressure().unwrap().value,
+            dew_point.pressure().unwrap().value
+        );
+        assert_relative_eq!(bubble_point.temperature().unwrap().value, 273.15);
+    }
+
+    #[test]
+    fn bubble_and_dew_points_of_incomp_return_err() {
+        let sut = Fluid::from(IncompPure::Water);
+        let pressure = Pressure::new::<kilopascal>(101.325);
+        assert_eq!(
+            sut.bubble_point_at_pressure(pressure).unwrap_err(),
+            FluidStateError::UnsupportedSubstance("Water".into())
+        );
+        assert_eq!(
+            sut.dew_point_at_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0))
+                .unwrap_err(),
+            FluidStateError::UnsupportedSubstance("Water".into())
+        );
+    }
+
+    #[test]
+    fn saturation_context_supercritical_pressure_returns_err() {
+        let mut sut = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<megapascal>(30.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        assert!(sut.saturation_context().is_err());
+    }
+
+    #[test]
+    fn saturation_context_incomp_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<kilopascal>(100.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        assert!(sut.saturation_context().is_err());
+    }
+}
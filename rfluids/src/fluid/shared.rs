@@ -0,0 +1,168 @@
+use crate::error::FluidOutputError;
+use crate::fluid::Fluid;
+use crate::io::{FluidParam, FluidTrivialParam};
+use crate::DefinedState;
+use std::sync::{Mutex, MutexGuard};
+
+/// Thread-safe wrapper of [`Fluid`] with [`DefinedState`] type parameter,
+/// which provides access to its outputs via shared references.
+///
+/// Cached outputs are shared between all threads,
+/// so the thermodynamic state isn't recalculated
+/// and each output is calculated only once.
+/// Access to the wrapped [`Fluid`] is serialized by the internal lock.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::fluid::{Fluid, SharedFluid};
+/// use rfluids::io::{FluidInput, FluidParam};
+/// use rfluids::substance::Pure;
+/// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+/// use rfluids::uom::si::pressure::atmosphere;
+/// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let water = Fluid::from(Pure::Water)
+///     .update(
+///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+///     )
+///     .unwrap();
+/// let shared = Arc::new(SharedFluid::from(water));
+/// let handles = (0..4)
+///     .map(|_| {
+///         let shared = Arc::clone(&shared);
+///         thread::spawn(move || shared.output(FluidParam::DMass).unwrap())
+///     })
+///     .collect::<Vec<_>>();
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), shared.with(|f| f.density()).unwrap().value);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SharedFluid(Mutex<Fluid<DefinedState>>);
+
+impl SharedFluid {
+    /// Output parameter value _(in SI units)_.
+    ///
+    /// # Args
+    ///
+    /// - `key` -- output parameter key.
+    ///
+    /// # Errors
+    ///
+    /// If the specified output is not available
+    /// for the substance or its current thermodynamic state,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # See also
+    ///
+    /// - [`Fluid::output`]
+    pub fn output(&self, key: FluidParam) -> Result<f64, FluidOutputError> {
+        self.lock().output(key)
+    }
+
+    /// Trivial output parameter value _(in SI units)_.
+    ///
+    /// # Args
+    ///
+    /// - `key` -- trivial output parameter key.
+    ///
+    /// # Errors
+    ///
+    /// If the specified output is not available for the substance,
+    /// a [`FluidOutputError`] is returned.
+    ///
+    /// # See also
+    ///
+    /// - [`Fluid::trivial_output`]
+    pub fn trivial_output(&self, key: FluidTrivialParam) -> Result<f64, FluidOutputError> {
+        self.lock().trivial_output(key)
+    }
+
+    /// Calls the specified function with the wrapped [`Fluid`]
+    /// _(e.g., to use any of its typed outputs)_ and returns its result.
+    ///
+    /// The internal lock is held until the function returns.
+    ///
+    /// # Args
+    ///
+    /// - `f` -- function to call.
+    pub fn with<R>(&self, f: impl FnOnce(&mut Fluid<DefinedState>) -> R) -> R {
+        f(&mut self.lock())
+    }
+
+    /// Unwraps and returns the wrapped [`Fluid`].
+    pub fn into_inner(self) -> Fluid<DefinedState> {
+        self.0.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Fluid<DefinedState>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl From<Fluid<DefinedState>> for SharedFluid {
+    fn from(value: Fluid<DefinedState>) -> Self {
+        Self(Mutex::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::FluidInput;
+    use crate::substance::Pure;
+    use crate::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    use crate::uom::si::pressure::atmosphere;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use rayon::prelude::*;
+    use std::sync::Arc;
+
+    fn water() -> Fluid {
+        Fluid::from(Pure::Water)
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn shared_fluid_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedFluid>();
+    }
+
+    #[test]
+    fn output_from_multiple_threads_returns_same_cached_value() {
+        let sut = Arc::new(SharedFluid::from(water()));
+        let expected = water().output(FluidParam::CpMass).unwrap();
+        let result: Vec<f64> = (0..100)
+            .into_par_iter()
+            .map(|_| sut.output(FluidParam::CpMass).unwrap())
+            .collect();
+        assert!(result.iter().all(|&r| r == expected));
+        assert!(sut.with(|f| f.outputs.contains_key(&FluidParam::CpMass)));
+    }
+
+    #[test]
+    fn trivial_output_returns_same_value_as_fluid() {
+        let sut = SharedFluid::from(water());
+        assert_eq!(
+            sut.trivial_output(FluidTrivialParam::MolarMass),
+            water().trivial_output(FluidTrivialParam::MolarMass)
+        );
+    }
+
+    #[test]
+    fn into_inner_keeps_cached_outputs() {
+        let sut = SharedFluid::from(water());
+        sut.output(FluidParam::DMass).unwrap();
+        let result = sut.into_inner();
+        assert!(result.outputs.contains_key(&FluidParam::DMass));
+        assert_eq!(result, water());
+    }
+}
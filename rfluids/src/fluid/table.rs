@@ -0,0 +1,165 @@
+use crate::fluid::common::new_backend;
+use crate::io::{FluidInputPair, FluidParam};
+use crate::substance::Substance;
+use crate::uom::si::f64::{Pressure, ThermodynamicTemperature};
+use std::io;
+use std::io::Write;
+
+/// Writes a property table for the grid of pressures and temperatures
+/// in CSV format, reusing the same backend for all calculations.
+///
+/// The header contains `P`, `T` and the CoolProp names of the specified outputs.
+/// Each row corresponds to a single pair of pressure and temperature
+/// _(temperatures vary fastest)_, all values are **in SI units**.
+/// Cells which can't be calculated _(e.g., due to an invalid state)_ are left empty.
+///
+/// # Args
+///
+/// - `writer` -- destination of the CSV data.
+/// - `substance` -- substance for which the table is generated.
+/// - `pressures` -- pressures of the grid.
+/// - `temperatures` -- temperatures of the grid.
+/// - `outputs` -- output parameters keys.
+///
+/// # Errors
+///
+/// If writing fails, the underlying [`io::Error`] is returned.
+/// If the backend can't be created for the substance,
+/// an [`io::Error`] of kind [`InvalidInput`](io::ErrorKind::InvalidInput) is returned.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::fluid::write_property_table;
+/// use rfluids::io::FluidParam;
+/// use rfluids::substance::Pure;
+/// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+/// use rfluids::uom::si::pressure::atmosphere;
+/// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+///
+/// let mut csv = Vec::new();
+/// write_property_table(
+///     &mut csv,
+///     Pure::Water,
+///     &[Pressure::new::<atmosphere>(1.0), Pressure::new::<atmosphere>(2.0)],
+///     &[
+///         ThermodynamicTemperature::new::<degree_celsius>(20.0),
+///         ThermodynamicTemperature::new::<degree_celsius>(40.0),
+///     ],
+///     &[FluidParam::DMass, FluidParam::HMass],
+/// )
+/// .unwrap();
+/// let csv = String::from_utf8(csv).unwrap();
+/// assert!(csv.starts_with("P,T,Dmass,Hmass\n"));
+/// assert_eq!(csv.lines().count(), 5);
+/// ```
+pub fn write_property_table<W: Write>(
+    mut writer: W,
+    substance: impl Into<Substance>,
+    pressures: &[Pressure],
+    temperatures: &[ThermodynamicTemperature],
+    outputs: &[FluidParam],
+) -> io::Result<()> {
+    let mut backend = new_backend(&substance.into())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    write!(writer, "P,T")?;
+    for key in outputs {
+        write!(writer, ",{}", key.as_ref())?;
+    }
+    writeln!(writer)?;
+    for pressure in pressures {
+        for temperature in temperatures {
+            let updated = backend
+                .update(FluidInputPair::PT, pressure.value, temperature.value)
+                .is_ok();
+            write!(writer, "{},{}", pressure.value, temperature.value)?;
+            for &key in outputs {
+                match updated.then(|| backend.keyed_output(key).ok()).flatten() {
+                    Some(value) => write!(writer, ",{value}")?,
+                    None => write!(writer, ",")?,
+                }
+            }
+            writeln!(writer)?;
+        }
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::substance::{CustomMix, Pure};
+    use crate::uom::si::f64::Ratio;
+    use crate::uom::si::pressure::{atmosphere, pascal};
+    use crate::uom::si::ratio::percent;
+    use crate::uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+    use std::collections::HashMap;
+
+    fn write(
+        substance: impl Into<Substance>,
+        pressures: &[Pressure],
+        temperatures: &[ThermodynamicTemperature],
+        outputs: &[FluidParam],
+    ) -> String {
+        let mut csv = Vec::new();
+        write_property_table(&mut csv, substance, pressures, temperatures, outputs).unwrap();
+        String::from_utf8(csv).unwrap()
+    }
+
+    #[test]
+    fn write_property_table_writes_header_and_row_per_grid_point() {
+        let result = write(
+            Pure::Water,
+            &[
+                Pressure::new::<pascal>(101325.0),
+                Pressure::new::<pascal>(2e5),
+            ],
+            &[
+                ThermodynamicTemperature::new::<kelvin>(293.15),
+                ThermodynamicTemperature::new::<kelvin>(313.15),
+                ThermodynamicTemperature::new::<kelvin>(333.15),
+            ],
+            &[FluidParam::DMass],
+        );
+        let lines = result.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "P,T,Dmass");
+        assert!(lines[1].starts_with("101325,293.15,998."));
+        assert!(lines[6].starts_with("200000,333.15,"));
+        assert!(lines[1..].iter().all(|l| l.split(',').count() == 3));
+    }
+
+    #[test]
+    fn write_property_table_invalid_state_writes_empty_cells() {
+        let result = write(
+            Pure::Water,
+            &[Pressure::new::<atmosphere>(1.0)],
+            &[ThermodynamicTemperature::new::<kelvin>(-1.0)],
+            &[FluidParam::DMass, FluidParam::HMass],
+        );
+        assert_eq!(result.lines().nth(1).unwrap(), "101325,-1,,");
+    }
+
+    #[test]
+    fn write_property_table_without_grid_writes_only_header() {
+        let result = write(Pure::Water, &[], &[], &[FluidParam::DMass]);
+        assert_eq!(result, "P,T,Dmass\n");
+    }
+
+    #[test]
+    fn write_property_table_unsupported_substance_returns_err() {
+        let substance = CustomMix::mole_based(HashMap::from([
+            (Pure::Orthohydrogen.into(), Ratio::new::<percent>(50.0)),
+            (Pure::Parahydrogen.into(), Ratio::new::<percent>(50.0)),
+        ]))
+        .unwrap();
+        let result = write_property_table(
+            Vec::new(),
+            substance,
+            &[Pressure::new::<atmosphere>(1.0)],
+            &[ThermodynamicTemperature::new::<degree_celsius>(20.0)],
+            &[FluidParam::DMass],
+        );
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,1229 @@
+use crate::error::{BinaryMixError, CoolPropError, CustomMixError, FluidStateError};
+use crate::fluid::common::{output_error, FluidUpdateRequest};
+use crate::fluid::Fluid;
+use crate::io::{FluidInput, FluidInputPair, FluidParam, FluidTrivialParam, Phase};
+use crate::substance::{BinaryMix, BinaryMixKind, CustomMix, Substance};
+use crate::uom::si::f64::Ratio;
+use crate::uom::si::ratio::ratio;
+use crate::{DefinedState, Remember, UndefinedState};
+
+impl Fluid<UndefinedState> {
+    /// Updates the thermodynamic state and returns itself
+    /// with [`DefinedState`] type parameter.
+    ///
+    /// # Args
+    ///
+    /// - `input1` -- first input property.
+    /// - `input2` -- second input property.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, Phase};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(water.phase().unwrap(), Phase::Liquid);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FluidInput`]
+    pub fn update(
+        mut self,
+        input1: FluidInput,
+        input2: FluidInput,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        let request = FluidUpdateRequest::try_from((input1, input2))
+            .map_err(|_| FluidStateError::InvalidInputPair(input1.key, input2.key))?;
+        self.backend
+            .update(request.0, request.1, request.2)
+            .map_err(FluidStateError::UpdateFailed)?;
+        self.outputs.clear();
+        self.update_request = Some(request);
+        Ok(self.into_defined_state())
+    }
+
+    /// Updates the thermodynamic state with the specified phase state imposed
+    /// only for this update and returns itself with [`DefinedState`] type parameter.
+    ///
+    /// The previous phase setting _(see [`specify_phase`](Fluid::specify_phase))_
+    /// is restored afterward, so it doesn't affect further updates.
+    ///
+    /// # Args
+    ///
+    /// - `input1` -- first input property.
+    /// - `input2` -- second input property.
+    /// - `phase` -- phase state to be imposed for this update.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs or if the actual phase state
+    /// differs from the specified one, a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, Phase};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update_with_phase(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///         Phase::Liquid,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(water.phase().unwrap(), Phase::Liquid);
+    /// assert_eq!(water.imposed_phase(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Imposing the phase (optional)](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#imposing-the-phase-optional)
+    pub fn update_with_phase(
+        mut self,
+        input1: FluidInput,
+        input2: FluidInput,
+        phase: Phase,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        let previous_phase = self.imposed_phase;
+        self.specify_phase(phase);
+        let mut fluid = self.update(input1, input2)?;
+        fluid.imposed_phase = previous_phase;
+        fluid.restore_imposed_phase();
+        Ok(fluid)
+    }
+
+    /// Updates the thermodynamic state and returns itself
+    /// with [`DefinedState`] type parameter, retrying with imposed
+    /// [`Liquid`](Phase::Liquid) and then [`Gas`](Phase::Gas) phase state
+    /// if the initial update fails _(e.g., for near-critical states)_.
+    ///
+    /// Phase states imposed for the retries don't affect further updates
+    /// _(the previous phase setting is restored)_.
+    ///
+    /// # Args
+    ///
+    /// - `input1` -- first input property.
+    /// - `input2` -- second input property.
+    ///
+    /// # Errors
+    ///
+    /// For invalid input pair, a [`FluidStateError::InvalidInputPair`] is returned.
+    /// If all attempts fail, a [`FluidStateError::AllAttemptsFailed`] is returned
+    /// _(it contains the phase state and the error of each attempt)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, Phase};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// water.specify_phase(Phase::Gas);
+    /// let mut water = water
+    ///     .update_robust(
+    ///         FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///         FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(water.phase().unwrap(), Phase::Liquid);
+    /// assert_eq!(water.imposed_phase(), Some(Phase::Gas));
+    /// ```
+    pub fn update_robust(
+        mut self,
+        input1: FluidInput,
+        input2: FluidInput,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        let request = FluidUpdateRequest::try_from((input1, input2))
+            .map_err(|_| FluidStateError::InvalidInputPair(input1.key, input2.key))?;
+        let mut attempts = Vec::new();
+        for phase in [None, Some(Phase::Liquid), Some(Phase::Gas)] {
+            if phase.is_some() {
+                self.impose_backend_phase(phase);
+            }
+            let result = self.backend.update(request.0, request.1, request.2);
+            if let Err(error) = result {
+                if phase.is_some() {
+                    self.impose_backend_phase(self.imposed_phase);
+                }
+                attempts.push((
+                    phase.or(self.imposed_phase).unwrap_or(Phase::NotImposed),
+                    error,
+                ));
+                continue;
+            }
+            self.outputs.clear();
+            if phase.is_some() {
+                self.restore_imposed_phase();
+            }
+            self.update_request = Some(request);
+            return Ok(self.into_defined_state());
+        }
+        Err(FluidStateError::AllAttemptsFailed(attempts))
+    }
+
+    /// Updates the thermodynamic state using the input pair
+    /// and raw input values _(in SI units)_ and returns itself
+    /// with [`DefinedState`] type parameter.
+    ///
+    /// It's a lower-level alternative to [`update`](Fluid::update)
+    /// _(e.g., for deserialized data)_.
+    ///
+    /// The values must be in the same order as the keys of the input pair
+    /// _(see [`FluidInputPair`])_. Each value is checked for plausibility
+    /// against the key of its slot, so that swapped values are rejected:
+    /// all values must be finite, vapor quality must be in the range from 0 to 1,
+    /// density must be positive, and temperature and pressure must be positive
+    /// and must not exceed the [maximum temperature](Fluid::max_temperature)
+    /// and [maximum pressure](Fluid::max_pressure) of the substance _(if available)_.
+    ///
+    /// **NB.** Keys without natural bounds _(e.g., enthalpy or entropy)_
+    /// accept any finite value, so swapping them with each other
+    /// can't be detected.
+    ///
+    /// # Args
+    ///
+    /// - `pair` -- input pair key.
+    /// - `value1` -- value of the first input property _(in SI units)_.
+    /// - `value2` -- value of the second input property _(in SI units)_.
+    ///
+    /// # Errors
+    ///
+    /// - For non-finite values,
+    ///   a [`FluidStateError::NonFiniteInput`] is returned.
+    /// - For values implausible for the input pair keys
+    ///   _(e.g., swapped ones)_, a [`FluidStateError::InvalidInput`] is returned.
+    /// - For invalid inputs, a [`FluidStateError::UpdateFailed`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::error::FluidStateError;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInputPair, FluidParam, Phase};
+    /// use rfluids::substance::Pure;
+    ///
+    /// let mut water = Fluid::from(Pure::Water)
+    ///     .update_raw(FluidInputPair::PT, 101325.0, 293.15)
+    ///     .unwrap();
+    /// assert_eq!(water.phase().unwrap(), Phase::Liquid);
+    /// assert_eq!(
+    ///     Fluid::from(Pure::Water)
+    ///         .update_raw(FluidInputPair::PQ, 1.0, 101325.0)
+    ///         .unwrap_err(),
+    ///     FluidStateError::InvalidInput(FluidParam::Q, 101325.0)
+    /// );
+    /// assert_eq!(
+    ///     Fluid::from(Pure::Water)
+    ///         .update_raw(FluidInputPair::PT, 293.15, 101325.0)
+    ///         .unwrap_err(),
+    ///     FluidStateError::InvalidInput(FluidParam::T, 101325.0)
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FluidInputPair`]
+    pub fn update_raw(
+        mut self,
+        pair: FluidInputPair,
+        value1: f64,
+        value2: f64,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        let (key1, key2): (FluidParam, FluidParam) = pair.into();
+        for (key, value) in [(key1, value1), (key2, value2)] {
+            if !value.is_finite() {
+                return Err(FluidStateError::NonFiniteInput(key));
+            }
+            if !self.is_valid_raw_input(key, value) {
+                return Err(FluidStateError::InvalidInput(key, value));
+            }
+        }
+        let request = FluidUpdateRequest(pair, value1, value2);
+        self.backend
+            .update(request.0, request.1, request.2)
+            .map_err(FluidStateError::UpdateFailed)?;
+        self.outputs.clear();
+        self.update_request = Some(request);
+        Ok(self.into_defined_state())
+    }
+
+    /// Updates the thermodynamic state for each pair of inputs
+    /// and collects the specified outputs _(in SI units)_,
+    /// reusing the same backend for all calculations.
+    ///
+    /// It's much faster than creating a new instance for each pair of inputs,
+    /// so it's useful for generating property tables.
+    /// The instance itself stays in [`UndefinedState`].
+    ///
+    /// # Args
+    ///
+    /// - `inputs` -- pairs of input properties.
+    /// - `outputs` -- output parameters keys.
+    ///
+    /// # Returns
+    ///
+    /// For each pair of inputs _(in the same order)_,
+    /// output values in the same order as `outputs`,
+    /// or a [`FluidStateError`] if the inputs are invalid
+    /// _([`FluidStateError::InvalidInputPair`] or [`FluidStateError::UpdateFailed`])_
+    /// or any of the outputs can't be calculated
+    /// _([`FluidStateError::UnavailableOutput`])_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// let result = water.update_many(
+    ///     (1..=9).map(|i| {
+    ///         (
+    ///             FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///             FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(
+    ///                 10.0 * i as f64,
+    ///             )),
+    ///         )
+    ///     }),
+    ///     &[FluidParam::DMass, FluidParam::HMass],
+    /// );
+    /// assert_eq!(result.len(), 9);
+    /// assert!(result.iter().all(|r| r.as_ref().is_ok_and(|v| v.len() == 2)));
+    /// ```
+    pub fn update_many(
+        &mut self,
+        inputs: impl IntoIterator<Item = (FluidInput, FluidInput)>,
+        outputs: &[FluidParam],
+    ) -> Vec<Result<Vec<f64>, FluidStateError>> {
+        let result = inputs
+            .into_iter()
+            .map(|(input1, input2)| {
+                self.outputs.clear();
+                let request = FluidUpdateRequest::try_from((input1, input2))
+                    .map_err(|_| FluidStateError::InvalidInputPair(input1.key, input2.key))?;
+                self.backend
+                    .update(request.0, request.1, request.2)
+                    .map_err(FluidStateError::UpdateFailed)?;
+                outputs
+                    .iter()
+                    .map(|&key| {
+                        self.outputs
+                            .remember(&self.backend, key)
+                            .map_err(|e| FluidStateError::UnavailableOutput(output_error(key, e)))
+                    })
+                    .collect()
+            })
+            .collect();
+        self.outputs.clear();
+        result
+    }
+
+    /// Specifies the phase state for all further updates
+    /// _(until [`unspecify_phase`](Fluid::unspecify_phase) is called)_.
+    ///
+    /// Imposing the phase skips the phase determination step,
+    /// which speeds up calculations, but gives wrong or failed results
+    /// if the actual phase state differs from the specified one.
+    ///
+    /// **NB.** [`Phase::NotImposed`] is equivalent to
+    /// [`unspecify_phase`](Fluid::unspecify_phase).
+    ///
+    /// # Args
+    ///
+    /// - `phase` -- phase state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, Phase};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// water.specify_phase(Phase::Gas);
+    /// let result = water.update(
+    ///     FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///     FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    /// );
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Imposing the phase (optional)](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#imposing-the-phase-optional)
+    pub fn specify_phase(&mut self, phase: Phase) {
+        if phase == Phase::NotImposed {
+            self.unspecify_phase();
+            return;
+        }
+        self.backend
+            .specify_phase(phase)
+            .expect("All phase states must be supported by CoolProp!");
+        self.imposed_phase = Some(phase);
+    }
+
+    /// Unspecifies the phase state and goes back
+    /// to determining it based on the inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, Phase};
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut water = Fluid::from(Pure::Water);
+    /// water.specify_phase(Phase::Gas);
+    /// water.unspecify_phase();
+    /// let result = water.update(
+    ///     FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///     FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    /// );
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn unspecify_phase(&mut self) {
+        self.backend.unspecify_phase();
+        self.imposed_phase = None;
+    }
+
+    /// Creates and returns a new [`Fluid`] instance
+    /// with [`UndefinedState`] type parameter
+    /// for the incompressible binary mixture with the specified fraction.
+    ///
+    /// # Args
+    ///
+    /// - `kind` -- binary mixture kind.
+    /// - `fraction` -- fraction of the specified binary mixture kind.
+    ///
+    /// # Errors
+    ///
+    /// For invalid fraction _(see [`BinaryMix::try_new`])_,
+    /// a [`BinaryMixError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::BinaryMixKind;
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// assert!(Fluid::binary(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).is_ok());
+    /// assert!(Fluid::binary(BinaryMixKind::MPG, Ratio::new::<percent>(100.0)).is_err());
+    /// ```
+    pub fn binary(kind: BinaryMixKind, fraction: Ratio) -> Result<Self, BinaryMixError> {
+        Ok(BinaryMix::try_new(kind, fraction)?.into())
+    }
+
+    /// Sets the fraction of the incompressible binary mixture
+    /// _(without creating a new instance)_.
+    ///
+    /// Cached trivial outputs are cleared, since they depend on the fraction.
+    ///
+    /// # Args
+    ///
+    /// - `fraction` -- new fraction of the binary mixture kind.
+    ///
+    /// # Errors
+    ///
+    /// For invalid fraction _(see [`BinaryMix::try_new`])_
+    /// or if the substance is not a [`BinaryMix`],
+    /// a [`BinaryMixError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::BinaryMixKind;
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let mut propylene_glycol = Fluid::binary(BinaryMixKind::MPG, Ratio::new::<percent>(40.0))
+    ///     .unwrap();
+    /// assert!(propylene_glycol
+    ///     .set_fraction(Ratio::new::<percent>(50.0))
+    ///     .is_ok());
+    /// assert!(propylene_glycol
+    ///     .set_fraction(Ratio::new::<percent>(100.0))
+    ///     .is_err());
+    /// ```
+    pub fn set_fraction(&mut self, fraction: Ratio) -> Result<(), BinaryMixError> {
+        let Substance::BinaryMix(binary_mix) = &self.substance else {
+            return Err(BinaryMixError::NotBinaryMix(self.substance.name().into()));
+        };
+        let binary_mix = binary_mix.with(fraction)?;
+        self.backend
+            .set_fractions(&[binary_mix.fraction.value])
+            .expect("Fraction of the binary mixture must be valid after validation!");
+        self.substance = binary_mix.into();
+        self.trivial_outputs.clear();
+        Ok(())
+    }
+
+    /// Sets the mole fractions of the custom mixture components
+    /// _(without creating a new instance)_.
+    ///
+    /// Fractions must be in the same order as the components in the
+    /// [substance name](Substance::name) _(alphabetical)_.
+    /// Cached trivial outputs are cleared, since they depend on the composition.
+    ///
+    /// # Args
+    ///
+    /// - `fractions` -- new mole fractions _(dimensionless, from 0 to 1)_.
+    ///
+    /// # Errors
+    ///
+    /// If the substance is not a [`CustomMix`],
+    /// the number of fractions doesn't match the number of components
+    /// or the fractions are invalid _(see [`CustomMix::mole_based`])_,
+    /// a [`CustomMixError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::{CustomMix, Refrigerant};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let mix = CustomMix::mole_based(HashMap::from([
+    ///     (Refrigerant::R32.into(), Ratio::new::<percent>(70.0)),
+    ///     (Refrigerant::R125.into(), Ratio::new::<percent>(30.0)),
+    /// ]))
+    /// .unwrap();
+    /// let mut fluid = Fluid::from(mix);
+    /// // Components order: R125, R32
+    /// assert!(fluid.set_mole_fractions(&[0.5, 0.5]).is_ok());
+    /// assert!(fluid.set_mole_fractions(&[0.5, 0.6]).is_err());
+    /// ```
+    pub fn set_mole_fractions(&mut self, fractions: &[f64]) -> Result<(), CustomMixError> {
+        let Substance::CustomMix(mix) = &self.substance else {
+            return Err(CustomMixError::NotCustomMix(self.substance.name().into()));
+        };
+        let mut components: Vec<_> = mix.components().keys().cloned().collect();
+        if components.len() != fractions.len() {
+            return Err(CustomMixError::FractionsCountMismatch {
+                expected: components.len(),
+                specified: fractions.len(),
+            });
+        }
+        components.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        let mix = CustomMix::mole_based(
+            components
+                .into_iter()
+                .zip(fractions.iter().map(|f| Ratio::new::<ratio>(*f)))
+                .collect(),
+        )?;
+        mix.mole_fractions()
+            .and_then(|fractions| self.backend.set_fractions(&fractions))
+            .expect("Mole fractions of the custom mixture must be valid after validation!");
+        self.substance = mix.into();
+        self.trivial_outputs.clear();
+        Ok(())
+    }
+
+    /// Sets the binary interaction parameter for the pair of mixture components
+    /// _(e.g., to calibrate the mixture model)_.
+    ///
+    /// Components are indexed in the same order as in the
+    /// [substance name](Substance::name) _(alphabetical for
+    /// [`CustomMix`])_.
+    /// Cached trivial outputs are cleared, since they depend on the mixture model.
+    ///
+    /// # Args
+    ///
+    /// - `i` -- index of the first component _(zero-based)_.
+    /// - `j` -- index of the second component _(zero-based)_.
+    /// - `parameter` -- name of the binary interaction parameter
+    ///   _(see [`AbstractState::set_binary_interaction`](crate::native::AbstractState::set_binary_interaction))_.
+    /// - `value` -- value of the binary interaction parameter.
+    ///
+    /// # Errors
+    ///
+    /// For substances other than mixtures, invalid indices or unknown parameter,
+    /// a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::substance::{CustomMix, Refrigerant};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let mix = CustomMix::mole_based(HashMap::from([
+    ///     (Refrigerant::R32.into(), Ratio::new::<percent>(70.0)),
+    ///     (Refrigerant::R125.into(), Ratio::new::<percent>(30.0)),
+    /// ]))
+    /// .unwrap();
+    /// let mut fluid = Fluid::from(mix);
+    /// assert!(fluid.set_binary_interaction(0, 1, "betaT", 1.05).is_ok());
+    /// ```
+    pub fn set_binary_interaction(
+        &mut self,
+        i: usize,
+        j: usize,
+        parameter: impl AsRef<str>,
+        value: f64,
+    ) -> Result<(), CoolPropError> {
+        self.backend
+            .set_binary_interaction(i, j, parameter, value)?;
+        self.trivial_outputs.clear();
+        Ok(())
+    }
+
+    /// Creates a new instance with the saturated thermodynamic state
+    /// specified by the composition, a single input property and the phase state.
+    ///
+    /// The phase state defines the saturation boundary:
+    /// [`Gas`](Phase::Gas) -- dew point _(quality 1)_,
+    /// [`Liquid`](Phase::Liquid) -- bubble point _(quality 0)_.
+    /// It's also imposed on the backend _(see [`Fluid::specify_phase`])_
+    /// and remains imposed for further updates.
+    ///
+    /// # Args
+    ///
+    /// - `composition` -- substance _(e.g., [`CustomMix`])_.
+    /// - `input` -- input property _(e.g., pressure or temperature)_.
+    /// - `phase` -- phase state _([`Gas`](Phase::Gas) or [`Liquid`](Phase::Liquid))_.
+    ///
+    /// # Errors
+    ///
+    /// For unsupported phase states or invalid inputs, a [`FluidStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::fluid::Fluid;
+    /// use rfluids::io::{FluidInput, FluidParam, Phase};
+    /// use rfluids::substance::{CustomMix, Refrigerant};
+    /// use rfluids::uom::si::f64::{Pressure, Ratio};
+    /// use rfluids::uom::si::pressure::kilopascal;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let mix = CustomMix::mole_based(HashMap::from([
+    ///     (Refrigerant::R32.into(), Ratio::new::<percent>(70.0)),
+    ///     (Refrigerant::R125.into(), Ratio::new::<percent>(30.0)),
+    /// ]))
+    /// .unwrap();
+    /// let mut dew_point = Fluid::from_composition_and(
+    ///     mix,
+    ///     FluidInput::pressure(Pressure::new::<kilopascal>(500.0)),
+    ///     Phase::Gas,
+    /// )
+    /// .unwrap();
+    /// assert_relative_eq!(dew_point.output(FluidParam::Q).unwrap(), 1.0);
+    /// ```
+    pub fn from_composition_and(
+        composition: impl Into<Substance>,
+        input: FluidInput,
+        phase: Phase,
+    ) -> Result<Fluid<DefinedState>, FluidStateError> {
+        let quality = match phase {
+            Phase::Gas => 1.0,
+            Phase::Liquid => 0.0,
+            _ => {
+                return Err(FluidStateError::InvalidInputPair(
+                    input.key,
+                    FluidParam::Phase,
+                ))
+            }
+        };
+        let mut fluid = Fluid::from(composition.into());
+        fluid.specify_phase(phase);
+        fluid.update(input, FluidInput::quality(Ratio::new::<ratio>(quality)))
+    }
+
+    fn is_valid_raw_input(&mut self, key: FluidParam, value: f64) -> bool {
+        let max = match key {
+            FluidParam::Q => return (0.0..=1.0).contains(&value),
+            FluidParam::T => self.trivial_output(FluidTrivialParam::TMax).ok(),
+            FluidParam::P => self.trivial_output(FluidTrivialParam::PMax).ok(),
+            FluidParam::DMass | FluidParam::DMolar => None,
+            _ => return true,
+        };
+        value > 0.0 && max.is_none_or(|max| value <= max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FluidOutputError;
+    use crate::substance::{CustomMixComponent, IncompPure, Pure, Refrigerant};
+    use crate::uom::si::f64::{MolarMass, Pressure, ThermodynamicTemperature};
+    use crate::uom::si::molar_mass::kilogram_per_mole;
+    use crate::uom::si::pressure::{atmosphere, kilopascal, pascal};
+    use crate::uom::si::ratio::percent;
+    use crate::uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+    use approx::assert_relative_eq;
+    use rstest::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn update_valid_inputs_returns_ok() {
+        let sut = Fluid::from(Pure::Water);
+        let result = sut
+            .update(
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            )
+            .unwrap();
+        assert_eq!(
+            result.update_request,
+            Some(FluidUpdateRequest(FluidInputPair::PT, 101325.0, 293.15))
+        );
+    }
+
+    #[test]
+    fn update_same_inputs_returns_err() {
+        let sut = Fluid::from(Pure::Water);
+        let input = FluidInput::pressure(Pressure::new::<atmosphere>(1.0));
+        let result = sut.update(input, input);
+        assert_eq!(
+            result.unwrap_err(),
+            FluidStateError::InvalidInputPair(FluidParam::P, FluidParam::P)
+        );
+    }
+
+    #[test]
+    fn update_invalid_inputs_returns_err() {
+        let sut = Fluid::from(Pure::Water);
+        let result = sut.update(
+            FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            FluidInput::quality(Ratio::new::<percent>(-100.0)),
+        );
+        assert!(matches!(
+            result.unwrap_err(),
+            FluidStateError::UpdateFailed(_)
+        ));
+    }
+
+    fn pt_inputs() -> (FluidInput, FluidInput) {
+        (
+            FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+        )
+    }
+
+    #[test]
+    fn update_many_returns_same_outputs_as_separate_updates() {
+        let inputs: Vec<(FluidInput, FluidInput)> = [10.0, 50.0, 90.0]
+            .iter()
+            .map(|&t| {
+                (
+                    FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                    FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(t)),
+                )
+            })
+            .collect();
+        let outputs = [FluidParam::DMass, FluidParam::HMass];
+        let mut sut = Fluid::from(Pure::Water);
+        let result = sut.update_many(inputs.clone(), &outputs);
+        assert_eq!(result.len(), inputs.len());
+        for ((input1, input2), values) in inputs.into_iter().zip(result) {
+            let mut expected = Fluid::from(Pure::Water).update(input1, input2).unwrap();
+            assert_eq!(
+                values.unwrap(),
+                vec![
+                    expected.output(FluidParam::DMass).unwrap(),
+                    expected.output(FluidParam::HMass).unwrap()
+                ]
+            );
+        }
+        assert!(sut.outputs.is_empty());
+        assert!(sut.update_request.is_none());
+    }
+
+    #[test]
+    fn update_many_invalid_inputs_returns_err_only_for_them() {
+        let pressure = FluidInput::pressure(Pressure::new::<atmosphere>(1.0));
+        let mut sut = Fluid::from(Pure::Water);
+        let result = sut.update_many(
+            [
+                (pressure, pressure),
+                (pressure, FluidInput::quality(Ratio::new::<percent>(-100.0))),
+                (
+                    pressure,
+                    FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+                ),
+            ],
+            &[FluidParam::DMass],
+        );
+        assert_eq!(
+            result[0],
+            Err(FluidStateError::InvalidInputPair(
+                FluidParam::P,
+                FluidParam::P
+            ))
+        );
+        assert!(matches!(result[1], Err(FluidStateError::UpdateFailed(_))));
+        assert!(result[2].is_ok());
+    }
+
+    #[test]
+    fn update_many_unavailable_output_returns_err() {
+        let mut sut = Fluid::from(IncompPure::Water);
+        let result = sut.update_many(
+            [(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )],
+            &[FluidParam::DMass, FluidParam::Z],
+        );
+        assert!(matches!(
+            result[0],
+            Err(FluidStateError::UnavailableOutput(
+                FluidOutputError::UnavailableOutput(FluidParam::Z)
+                    | FluidOutputError::CalculationFailed(FluidParam::Z, _)
+            ))
+        ));
+    }
+
+    #[test]
+    fn specify_phase_persists_across_updates() {
+        let (pressure, temperature) = pt_inputs();
+        let mut sut = Fluid::from(Pure::Water);
+        sut.specify_phase(Phase::Liquid);
+        let fluid = sut.update(pressure, temperature).unwrap();
+        assert_eq!(fluid.imposed_phase, Some(Phase::Liquid));
+        let mut sut = fluid.reset();
+        assert!(sut.update_request.is_none());
+        assert_eq!(sut.imposed_phase, Some(Phase::Liquid));
+        sut.specify_phase(Phase::Gas);
+        assert!(matches!(
+            sut.update(pressure, temperature),
+            Err(FluidStateError::UpdateFailed(_))
+        ));
+    }
+
+    #[test]
+    fn update_with_phase_does_not_leak_imposed_phase() {
+        let (pressure, temperature) = pt_inputs();
+        let mut fluid = Fluid::from(Pure::Water)
+            .update_with_phase(pressure, temperature, Phase::Liquid)
+            .unwrap();
+        assert_eq!(fluid.phase().unwrap(), Phase::Liquid);
+        assert!(fluid.imposed_phase.is_none());
+        let mut result = fluid
+            .reset()
+            .update(
+                pressure,
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(200.0)),
+            )
+            .unwrap();
+        assert_eq!(result.phase().unwrap(), Phase::Gas);
+    }
+
+    #[test]
+    fn update_with_phase_restores_previously_imposed_phase() {
+        let (pressure, temperature) = pt_inputs();
+        let mut sut = Fluid::from(Pure::Water);
+        sut.specify_phase(Phase::Gas);
+        let mut fluid = sut
+            .update_with_phase(pressure, temperature, Phase::Liquid)
+            .unwrap();
+        assert_eq!(fluid.phase().unwrap(), Phase::Liquid);
+        assert_eq!(fluid.imposed_phase, Some(Phase::Gas));
+        assert!(matches!(
+            fluid.reset().update(pressure, temperature),
+            Err(FluidStateError::UpdateFailed(_))
+        ));
+    }
+
+    #[test]
+    fn update_robust_valid_inputs_returns_ok() {
+        let (pressure, temperature) = pt_inputs();
+        let mut result = Fluid::from(Pure::Water)
+            .update_robust(pressure, temperature)
+            .unwrap();
+        let mut expected = Fluid::from(Pure::Water)
+            .update(pressure, temperature)
+            .unwrap();
+        assert_eq!(result.density().unwrap(), expected.density().unwrap());
+        assert!(result.imposed_phase.is_none());
+    }
+
+    #[test]
+    fn update_robust_failed_initial_update_retries_with_imposed_phase() {
+        let (pressure, temperature) = pt_inputs();
+        let mut sut = Fluid::from(Pure::Water);
+        sut.specify_phase(Phase::Gas);
+        let mut result = sut.update_robust(pressure, temperature).unwrap();
+        assert_eq!(result.phase().unwrap(), Phase::Liquid);
+        assert_eq!(result.imposed_phase, Some(Phase::Gas));
+        assert!(matches!(
+            result.reset().update(pressure, temperature),
+            Err(FluidStateError::UpdateFailed(_))
+        ));
+    }
+
+    #[test]
+    fn update_robust_all_attempts_failed_returns_err_with_attempts() {
+        let result = Fluid::from(Pure::Water).update_robust(
+            FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            FluidInput::temperature(ThermodynamicTemperature::new::<kelvin>(-1.0)),
+        );
+        let Err(error @ FluidStateError::AllAttemptsFailed(attempts)) = &result else {
+            panic!("Expected all attempts to fail!");
+        };
+        assert!(error
+            .to_string()
+            .starts_with("Failed to update the fluid state in all attempts! [Not imposed]"));
+        assert_eq!(
+            attempts.iter().map(|a| a.0).collect::<Vec<_>>(),
+            [Phase::NotImposed, Phase::Liquid, Phase::Gas]
+        );
+    }
+
+    #[test]
+    fn update_robust_invalid_input_pair_returns_err() {
+        let (pressure, _) = pt_inputs();
+        assert_eq!(
+            Fluid::from(Pure::Water)
+                .update_robust(pressure, pressure)
+                .unwrap_err(),
+            FluidStateError::InvalidInputPair(FluidParam::P, FluidParam::P)
+        );
+    }
+
+    #[test]
+    fn update_with_phase_wrong_phase_returns_err() {
+        let (pressure, temperature) = pt_inputs();
+        assert!(matches!(
+            Fluid::from(Pure::Water).update_with_phase(pressure, temperature, Phase::Gas),
+            Err(FluidStateError::UpdateFailed(_))
+        ));
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(Phase::NotImposed))]
+    fn unspecify_phase_restores_phase_determination(#[case] phase: Option<Phase>) {
+        let (pressure, temperature) = pt_inputs();
+        let mut sut = Fluid::from(Pure::Water);
+        sut.specify_phase(Phase::Gas);
+        match phase {
+            Some(phase) => sut.specify_phase(phase),
+            None => sut.unspecify_phase(),
+        }
+        assert!(sut.imposed_phase.is_none());
+        let mut result = sut.update(pressure, temperature).unwrap();
+        assert_eq!(result.phase().unwrap(), Phase::Liquid);
+    }
+
+    #[test]
+    fn update_raw_is_same_as_update() {
+        let mut sut = Fluid::from(Pure::Water)
+            .update_raw(FluidInputPair::PT, 101325.0, 293.15)
+            .unwrap();
+        let mut other = Fluid::from(Pure::Water)
+            .update(
+                FluidInput::temperature(ThermodynamicTemperature::new::<kelvin>(293.15)),
+                FluidInput::pressure(Pressure::new::<pascal>(101325.0)),
+            )
+            .unwrap();
+        assert_eq!(sut.update_request, other.update_request);
+        assert_eq!(sut.density().unwrap(), other.density().unwrap());
+    }
+
+    #[rstest]
+    #[case(FluidInputPair::PQ, 1.0, 101325.0, FluidParam::Q, 101325.0)]
+    #[case(FluidInputPair::QT, 293.15, 0.5, FluidParam::Q, 293.15)]
+    #[case(FluidInputPair::PT, 101325.0, -293.15, FluidParam::T, -293.15)]
+    #[case(FluidInputPair::PT, -101325.0, 293.15, FluidParam::P, -101325.0)]
+    #[case(FluidInputPair::PT, 293.15, 101325.0, FluidParam::T, 101325.0)]
+    #[case(FluidInputPair::PT, 1e12, 293.15, FluidParam::P, 1e12)]
+    fn update_raw_invalid_values_returns_err(
+        #[case] pair: FluidInputPair,
+        #[case] value1: f64,
+        #[case] value2: f64,
+        #[case] expected_key: FluidParam,
+        #[case] expected_value: f64,
+    ) {
+        assert_eq!(
+            Fluid::from(Pure::Water)
+                .update_raw(pair, value1, value2)
+                .unwrap_err(),
+            FluidStateError::InvalidInput(expected_key, expected_value)
+        );
+    }
+
+    #[rstest]
+    #[case(FluidInputPair::HMassP, 100e3, f64::INFINITY, FluidParam::P)]
+    #[case(FluidInputPair::HMassP, f64::NAN, 101325.0, FluidParam::HMass)]
+    #[case(FluidInputPair::PQ, f64::NEG_INFINITY, f64::NAN, FluidParam::P)]
+    fn update_raw_non_finite_values_returns_err(
+        #[case] pair: FluidInputPair,
+        #[case] value1: f64,
+        #[case] value2: f64,
+        #[case] expected: FluidParam,
+    ) {
+        assert_eq!(
+            Fluid::from(Pure::Water)
+                .update_raw(pair, value1, value2)
+                .unwrap_err(),
+            FluidStateError::NonFiniteInput(expected)
+        );
+    }
+
+    #[test]
+    fn update_raw_invalid_state_returns_err() {
+        let result = Fluid::from(Pure::Water).update_raw(FluidInputPair::PT, 101325.0, 200.0);
+        assert!(matches!(result, Err(FluidStateError::UpdateFailed(_))));
+    }
+
+    #[test]
+    fn binary_valid_fraction_returns_ok() {
+        let fraction = Ratio::new::<percent>(40.0);
+        let sut = Fluid::binary(BinaryMixKind::MPG, fraction).unwrap();
+        assert_eq!(
+            sut.substance,
+            BinaryMix::try_new(BinaryMixKind::MPG, fraction)
+                .unwrap()
+                .into()
+        );
+        let mut result = sut
+            .update(
+                FluidInput::pressure(Pressure::new::<atmosphere>(1.0)),
+                FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            )
+            .unwrap();
+        assert!(result.density().unwrap().value > 1000.0);
+    }
+
+    #[test]
+    fn binary_invalid_fraction_returns_err() {
+        let fraction = Ratio::new::<percent>(100.0);
+        assert_eq!(
+            Fluid::binary(BinaryMixKind::MPG, fraction).unwrap_err(),
+            BinaryMix::try_new(BinaryMixKind::MPG, fraction).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn set_fraction_valid_fraction_changes_properties() {
+        let pressure = FluidInput::pressure(Pressure::new::<atmosphere>(1.0));
+        let temperature =
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+        let mut sut = Fluid::binary(BinaryMixKind::MEG, Ratio::new::<percent>(20.0)).unwrap();
+        let mut before = Fluid::binary(BinaryMixKind::MEG, Ratio::new::<percent>(20.0))
+            .unwrap()
+            .update(pressure, temperature)
+            .unwrap();
+        let fraction = Ratio::new::<percent>(50.0);
+        sut.set_fraction(fraction).unwrap();
+        assert_eq!(
+            sut.substance,
+            BinaryMix::try_new(BinaryMixKind::MEG, fraction)
+                .unwrap()
+                .into()
+        );
+        let mut after = sut.update(pressure, temperature).unwrap();
+        assert!(after.density().unwrap() > before.density().unwrap());
+        let mut reference = Fluid::binary(BinaryMixKind::MEG, fraction)
+            .unwrap()
+            .update(pressure, temperature)
+            .unwrap();
+        assert_eq!(after.density().unwrap(), reference.density().unwrap());
+    }
+
+    #[test]
+    fn set_fraction_invalid_fraction_returns_err_and_keeps_substance() {
+        let mut sut = Fluid::binary(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap();
+        let substance = sut.substance.clone();
+        let fraction = Ratio::new::<percent>(100.0);
+        assert_eq!(
+            sut.set_fraction(fraction).unwrap_err(),
+            BinaryMix::try_new(BinaryMixKind::MPG, fraction).unwrap_err()
+        );
+        assert_eq!(sut.substance, substance);
+    }
+
+    #[test]
+    fn set_fraction_not_binary_mix_returns_err() {
+        let mut sut = Fluid::from(Pure::Water);
+        assert_eq!(
+            sut.set_fraction(Ratio::new::<percent>(40.0)).unwrap_err(),
+            BinaryMixError::NotBinaryMix("Water".into())
+        );
+    }
+
+    fn r32_r125() -> CustomMix {
+        CustomMix::mole_based(HashMap::from([
+            (Refrigerant::R32.into(), Ratio::new::<percent>(70.0)),
+            (Refrigerant::R125.into(), Ratio::new::<percent>(30.0)),
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn set_mole_fractions_valid_fractions_changes_composition() {
+        let pressure = FluidInput::pressure(Pressure::new::<atmosphere>(1.0));
+        let temperature =
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+        let mut sut = Fluid::from(r32_r125());
+        sut.set_mole_fractions(&[0.5, 0.5]).unwrap();
+        let expected = CustomMix::mole_based(HashMap::from([
+            (Refrigerant::R32.into(), Ratio::new::<percent>(50.0)),
+            (Refrigerant::R125.into(), Ratio::new::<percent>(50.0)),
+        ]))
+        .unwrap();
+        assert_eq!(sut.substance, expected.clone().into());
+        let mut result = sut.update(pressure, temperature).unwrap();
+        let mut reference = Fluid::from(expected).update(pressure, temperature).unwrap();
+        assert_relative_eq!(
+            result.density().unwrap().value,
+            reference.density().unwrap().value
+        );
+    }
+
+    #[test]
+    fn set_mole_fractions_keeps_overridden_molar_masses() {
+        let component = CustomMixComponent::with_molar_mass(
+            Refrigerant::R32,
+            MolarMass::new::<kilogram_per_mole>(0.052),
+        );
+        let mut sut = Fluid::from(
+            CustomMix::mole_based(HashMap::from([
+                (component, Ratio::new::<percent>(70.0)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(30.0)),
+            ]))
+            .unwrap(),
+        );
+        sut.set_mole_fractions(&[0.5, 0.5]).unwrap();
+        assert_eq!(
+            sut.fractions().unwrap()[&component],
+            Ratio::new::<percent>(50.0)
+        );
+    }
+
+    #[rstest]
+    #[case(&[0.5], CustomMixError::FractionsCountMismatch { expected: 2, specified: 1 })]
+    #[case(&[0.5, 0.6], CustomMixError::InvalidFractionsSum)]
+    #[case(&[1.0, 0.0], CustomMixError::InvalidFraction)]
+    fn set_mole_fractions_invalid_fractions_returns_err(
+        #[case] fractions: &[f64],
+        #[case] expected: CustomMixError,
+    ) {
+        let mut sut = Fluid::from(r32_r125());
+        assert_eq!(sut.set_mole_fractions(fractions).unwrap_err(), expected);
+        assert_eq!(sut.substance, r32_r125().into());
+    }
+
+    #[test]
+    fn set_mole_fractions_not_custom_mix_returns_err() {
+        let mut sut = Fluid::from(Refrigerant::R410A);
+        assert_eq!(
+            sut.set_mole_fractions(&[0.5, 0.5]).unwrap_err(),
+            CustomMixError::NotCustomMix("R410A".into())
+        );
+    }
+
+    #[test]
+    fn set_binary_interaction_custom_mix_changes_properties() {
+        let pressure = FluidInput::pressure(Pressure::new::<atmosphere>(1.0));
+        let temperature =
+            FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+        let mut reference = Fluid::from(r32_r125())
+            .update(pressure, temperature)
+            .unwrap();
+        let mut sut = Fluid::from(r32_r125());
+        sut.set_binary_interaction(0, 1, "betaT", 1.1).unwrap();
+        let mut result = sut.update(pressure, temperature).unwrap();
+        assert_ne!(result.density().unwrap(), reference.density().unwrap());
+        assert_relative_eq!(
+            result.density().unwrap().value,
+            reference.density().unwrap().value,
+            max_relative = 1e-2
+        );
+    }
+
+    #[test]
+    fn set_binary_interaction_pure_returns_err() {
+        let mut sut = Fluid::from(Pure::Water);
+        assert!(sut.set_binary_interaction(0, 1, "betaT", 1.1).is_err());
+    }
+
+    #[test]
+    fn from_composition_and_gas_phase_returns_dew_point() {
+        let pressure = Pressure::new::<kilopascal>(500.0);
+        let mut result =
+            Fluid::from_composition_and(r32_r125(), FluidInput::pressure(pressure), Phase::Gas)
+                .unwrap();
+        let mut expected = Fluid::from(r32_r125())
+            .dew_point_at_pressure(pressure)
+            .unwrap();
+        assert_relative_eq!(result.output(FluidParam::Q).unwrap(), 1.0);
+        assert_relative_eq!(
+            result.temperature().unwrap().value,
+            expected.temperature().unwrap().value
+        );
+        assert_eq!(result.imposed_phase(), Some(Phase::Gas));
+    }
+
+    #[test]
+    fn from_composition_and_liquid_phase_returns_bubble_point() {
+        let input = FluidInput::pressure(Pressure::new::<kilopascal>(500.0));
+        let mut bubble_point =
+            Fluid::from_composition_and(r32_r125(), input, Phase::Liquid).unwrap();
+        let mut dew_point = Fluid::from_composition_and(r32_r125(), input, Phase::Gas).unwrap();
+        assert_relative_eq!(bubble_point.output(FluidParam::Q).unwrap(), 0.0);
+        assert_eq!(bubble_point.imposed_phase(), Some(Phase::Liquid));
+        assert!(bubble_point.temperature().unwrap() < dew_point.temperature().unwrap());
+    }
+
+    #[rstest]
+    #[case(Phase::TwoPhase)]
+    #[case(Phase::Supercritical)]
+    #[case(Phase::NotImposed)]
+    fn from_composition_and_unsupported_phase_returns_err(#[case] phase: Phase) {
+        let result = Fluid::from_composition_and(
+            r32_r125(),
+            FluidInput::pressure(Pressure::new::<kilopascal>(500.0)),
+            phase,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            FluidStateError::InvalidInputPair(FluidParam::P, FluidParam::Phase)
+        );
+    }
+}
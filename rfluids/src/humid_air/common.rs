@@ -0,0 +1,103 @@
+use crate::error::CoolPropError;
+use crate::io::{HumidAirInput, HumidAirParam};
+use crate::native::CoolProp;
+use crate::Remember;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct HumidAirUpdateRequest(pub HumidAirInput, pub HumidAirInput, pub HumidAirInput);
+
+impl TryFrom<(HumidAirInput, HumidAirInput, HumidAirInput)> for HumidAirUpdateRequest {
+    type Error = ();
+
+    fn try_from(value: (HumidAirInput, HumidAirInput, HumidAirInput)) -> Result<Self, ()> {
+        let keys = [value.0.key, value.1.key, value.2.key];
+        if keys[0] == keys[1] || keys[0] == keys[2] || keys[1] == keys[2] {
+            return Err(());
+        }
+        if !keys.contains(&HumidAirParam::P) {
+            return Err(());
+        }
+        Ok(Self(value.0, value.1, value.2))
+    }
+}
+
+impl Remember<&HumidAirUpdateRequest, HumidAirParam> for HashMap<HumidAirParam, f64> {
+    type Error = CoolPropError;
+
+    fn remember(
+        &mut self,
+        src: &HumidAirUpdateRequest,
+        key: HumidAirParam,
+    ) -> Result<f64, CoolPropError> {
+        Ok(match self.entry(key) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => *entry.insert(CoolProp::ha_props_si(
+                key,
+                src.0.key,
+                src.0.si_value,
+                src.1.key,
+                src.1.si_value,
+                src.2.key,
+                src.2.si_value,
+            )?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+    use crate::uom::si::pressure::kilopascal;
+    use crate::uom::si::ratio::percent;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use approx::assert_relative_eq;
+
+    fn inputs() -> (HumidAirInput, HumidAirInput, HumidAirInput) {
+        (
+            HumidAirInput::pressure(Pressure::new::<kilopascal>(100.0)),
+            HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(30.0)),
+            HumidAirInput::relative_humidity(Ratio::new::<percent>(50.0)),
+        )
+    }
+
+    #[test]
+    fn try_from_three_valid_inputs_returns_ok() {
+        let (pressure, temperature, relative_humidity) = inputs();
+        assert_eq!(
+            HumidAirUpdateRequest::try_from((temperature, pressure, relative_humidity)),
+            Ok(HumidAirUpdateRequest(
+                temperature,
+                pressure,
+                relative_humidity
+            ))
+        );
+    }
+
+    #[test]
+    fn try_from_duplicated_inputs_returns_err() {
+        let (pressure, temperature, _) = inputs();
+        assert!(HumidAirUpdateRequest::try_from((pressure, temperature, temperature)).is_err());
+    }
+
+    #[test]
+    fn try_from_inputs_without_pressure_returns_err() {
+        let (_, temperature, relative_humidity) = inputs();
+        let humidity_ratio = HumidAirInput::humidity_ratio(Ratio::new::<percent>(1.0));
+        assert!(
+            HumidAirUpdateRequest::try_from((temperature, relative_humidity, humidity_ratio))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn remember_returns_cached_value() {
+        let request = HumidAirUpdateRequest::try_from(inputs()).unwrap();
+        let mut sut = HashMap::new();
+        let result = sut.remember(&request, HumidAirParam::TWetBulb).unwrap();
+        assert_relative_eq!(result, 295.1200365362656, max_relative = 1e-9);
+        assert_eq!(sut[&HumidAirParam::TWetBulb], result);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::error::HumidAirOutputError;
+use crate::humid_air::HumidAir;
+use crate::io::HumidAirParam;
+use crate::uom::si::available_energy::joule_per_kilogram;
+use crate::uom::si::f64::{
+    AvailableEnergy, MassDensity, Pressure, Ratio, ThermodynamicTemperature,
+};
+use crate::uom::si::mass_density::kilogram_per_cubic_meter;
+use crate::uom::si::pressure::pascal;
+use crate::uom::si::ratio::ratio;
+use crate::uom::si::thermodynamic_temperature::kelvin;
+use crate::{DefinedState, Remember, UndefinedState};
+
+impl HumidAir<DefinedState> {
+    /// Output parameter value _(in SI units)_.
+    ///
+    /// # Args
+    ///
+    /// - `key` -- output parameter key.
+    ///
+    /// # Errors
+    ///
+    /// If the specified output can't be calculated
+    /// for the current thermodynamic state, a [`HumidAirOutputError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::humid_air::HumidAir;
+    /// use rfluids::io::{HumidAirInput, HumidAirParam};
+    /// use rfluids::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::kilopascal;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let mut humid_air = HumidAir::new()
+    ///     .update(
+    ///         HumidAirInput::pressure(Pressure::new::<kilopascal>(100.0)),
+    ///         HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(30.0)),
+    ///         HumidAirInput::relative_humidity(Ratio::new::<percent>(50.0)),
+    ///     )
+    ///     .unwrap();
+    /// assert_relative_eq!(
+    ///     humid_air.output(HumidAirParam::TWetBulb).unwrap(),
+    ///     295.1200365362656,
+    ///     max_relative = 1e-9
+    /// );
+    /// ```
+    pub fn output(&mut self, key: HumidAirParam) -> Result<f64, HumidAirOutputError> {
+        let request = self
+            .update_request
+            .as_ref()
+            .expect("HumidAir with `DefinedState` type parameter must have a thermodynamic state!");
+        self.outputs
+            .remember(request, key)
+            .map_err(|e| HumidAirOutputError::CalculationFailed(key, e))
+    }
+
+    /// Pressure _(key: [`P`](HumidAirParam::P))_.
+    pub fn pressure(&mut self) -> Result<Pressure, HumidAirOutputError> {
+        Ok(Pressure::new::<pascal>(self.output(HumidAirParam::P)?))
+    }
+
+    /// Dry-bulb temperature _(key: [`T`](HumidAirParam::T))_.
+    pub fn temperature(&mut self) -> Result<ThermodynamicTemperature, HumidAirOutputError> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            self.output(HumidAirParam::T)?,
+        ))
+    }
+
+    /// Wet-bulb temperature _(key: [`TWetBulb`](HumidAirParam::TWetBulb))_.
+    pub fn wet_bulb_temperature(
+        &mut self,
+    ) -> Result<ThermodynamicTemperature, HumidAirOutputError> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            self.output(HumidAirParam::TWetBulb)?,
+        ))
+    }
+
+    /// Dew-point temperature _(key: [`TDew`](HumidAirParam::TDew))_.
+    pub fn dew_point_temperature(
+        &mut self,
+    ) -> Result<ThermodynamicTemperature, HumidAirOutputError> {
+        Ok(ThermodynamicTemperature::new::<kelvin>(
+            self.output(HumidAirParam::TDew)?,
+        ))
+    }
+
+    /// Relative humidity _(key: [`R`](HumidAirParam::R))_.
+    pub fn relative_humidity(&mut self) -> Result<Ratio, HumidAirOutputError> {
+        Ok(Ratio::new::<ratio>(self.output(HumidAirParam::R)?))
+    }
+
+    /// Humidity ratio _(key: [`W`](HumidAirParam::W), kg water/kg dry air)_.
+    pub fn humidity_ratio(&mut self) -> Result<Ratio, HumidAirOutputError> {
+        Ok(Ratio::new::<ratio>(self.output(HumidAirParam::W)?))
+    }
+
+    /// Specific enthalpy per unit of humid air _(key: [`Hha`](HumidAirParam::Hha))_.
+    pub fn enthalpy(&mut self) -> Result<AvailableEnergy, HumidAirOutputError> {
+        Ok(AvailableEnergy::new::<joule_per_kilogram>(
+            self.output(HumidAirParam::Hha)?,
+        ))
+    }
+
+    /// Mass density _(reciprocal of [`Vha`](HumidAirParam::Vha))_.
+    pub fn density(&mut self) -> Result<MassDensity, HumidAirOutputError> {
+        Ok(MassDensity::new::<kilogram_per_cubic_meter>(
+            1.0 / self.output(HumidAirParam::Vha)?,
+        ))
+    }
+
+    /// Resets the thermodynamic state and returns itself
+    /// with [`UndefinedState`] type parameter.
+    pub fn reset(mut self) -> HumidAir<UndefinedState> {
+        self.outputs.clear();
+        self.update_request = None;
+        self.into_state()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::HumidAirInput;
+    use crate::uom::si::pressure::kilopascal;
+    use crate::uom::si::ratio::percent;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+    use approx::assert_relative_eq;
+
+    fn humid_air() -> HumidAir {
+        HumidAir::new()
+            .update(
+                HumidAirInput::pressure(Pressure::new::<kilopascal>(100.0)),
+                HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(30.0)),
+                HumidAirInput::relative_humidity(Ratio::new::<percent>(50.0)),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn output_returns_cached_value() {
+        let mut sut = humid_air();
+        let result = sut.output(HumidAirParam::TWetBulb).unwrap();
+        assert_relative_eq!(result, 295.1200365362656, max_relative = 1e-9);
+        assert_eq!(sut.outputs[&HumidAirParam::TWetBulb], result);
+    }
+
+    #[test]
+    fn typed_outputs_return_expected_values() {
+        let mut sut = humid_air();
+        assert_relative_eq!(sut.pressure().unwrap().get::<kilopascal>(), 100.0);
+        assert_relative_eq!(
+            sut.temperature().unwrap().get::<degree_celsius>(),
+            30.0,
+            max_relative = 1e-9
+        );
+        assert_relative_eq!(sut.relative_humidity().unwrap().get::<percent>(), 50.0);
+        assert_relative_eq!(
+            sut.wet_bulb_temperature().unwrap().value,
+            295.1200365362656,
+            max_relative = 1e-9
+        );
+        assert!(sut.dew_point_temperature().unwrap() < sut.wet_bulb_temperature().unwrap());
+        assert!(sut.humidity_ratio().unwrap().value > 0.0);
+        assert!(sut.enthalpy().unwrap().value > 0.0);
+        assert!(sut.density().unwrap().value > 1.0);
+    }
+
+    #[test]
+    fn humidity_ratio_is_consistent_with_relative_humidity() {
+        let mut sut = humid_air();
+        let humidity_ratio = sut.humidity_ratio().unwrap();
+        let mut other = HumidAir::new()
+            .update(
+                HumidAirInput::pressure(Pressure::new::<kilopascal>(100.0)),
+                HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(30.0)),
+                HumidAirInput::humidity_ratio(humidity_ratio),
+            )
+            .unwrap();
+        assert_relative_eq!(
+            other.relative_humidity().unwrap().get::<percent>(),
+            50.0,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn reset_clears_state_and_outputs() {
+        let mut sut = humid_air();
+        sut.output(HumidAirParam::TDew).unwrap();
+        let result = sut.reset();
+        assert!(result.outputs.is_empty());
+        assert!(result.update_request.is_none());
+    }
+}
@@ -0,0 +1,100 @@
+//! Thermophysical properties of humid air.
+
+mod common;
+mod defined;
+mod undefined;
+
+use crate::humid_air::common::HumidAirUpdateRequest;
+use crate::io::HumidAirParam;
+use crate::{DefinedState, UndefinedState};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// Provider of thermophysical properties of humid air.
+///
+/// It implements the [typestate pattern](https://en.wikipedia.org/wiki/Typestate_analysis)
+/// and has one generic type parameter `S` _(state type, [`DefinedState`] or [`UndefinedState`])_.
+///
+/// Depending on `S`, the `HumidAir` instance has different functionality.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use rfluids::humid_air::HumidAir;
+/// use rfluids::io::HumidAirInput;
+/// use rfluids::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+/// use rfluids::uom::si::pressure::kilopascal;
+/// use rfluids::uom::si::ratio::percent;
+/// use rfluids::uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+///
+/// let mut humid_air = HumidAir::new()
+///     .update(
+///         HumidAirInput::pressure(Pressure::new::<kilopascal>(100.0)),
+///         HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(30.0)),
+///         HumidAirInput::relative_humidity(Ratio::new::<percent>(50.0)),
+///     )
+///     .unwrap();
+/// assert_relative_eq!(
+///     humid_air.wet_bulb_temperature().unwrap().get::<kelvin>(),
+///     295.1200365362656,
+///     max_relative = 1e-9
+/// );
+/// ```
+///
+/// # See also
+///
+/// - [Humid air properties](https://coolprop.github.io/CoolProp/fluid_properties/HumidAir.html)
+#[derive(Debug)]
+pub struct HumidAir<S = DefinedState> {
+    update_request: Option<HumidAirUpdateRequest>,
+    outputs: HashMap<HumidAirParam, f64>,
+    state: PhantomData<S>,
+}
+
+impl HumidAir<UndefinedState> {
+    /// Creates and returns a new [`HumidAir`] instance
+    /// with [`UndefinedState`] type parameter.
+    pub fn new() -> Self {
+        Self {
+            update_request: None,
+            outputs: HashMap::new(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl Default for HumidAir<UndefinedState> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> HumidAir<S> {
+    fn into_state<T>(self) -> HumidAir<T> {
+        HumidAir {
+            update_request: self.update_request,
+            outputs: self.outputs,
+            state: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_returns_instance_without_state() {
+        let sut = HumidAir::new();
+        assert!(sut.update_request.is_none());
+        assert!(sut.outputs.is_empty());
+    }
+
+    #[test]
+    fn default_returns_same_as_new() {
+        let sut = HumidAir::default();
+        assert!(sut.update_request.is_none());
+        assert!(sut.outputs.is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::error::HumidAirStateError;
+use crate::humid_air::common::HumidAirUpdateRequest;
+use crate::humid_air::HumidAir;
+use crate::io::{HumidAirInput, HumidAirParam};
+use crate::{DefinedState, Remember, UndefinedState};
+
+impl HumidAir<UndefinedState> {
+    /// Updates the thermodynamic state and returns itself
+    /// with [`DefinedState`] type parameter.
+    ///
+    /// Humid air state is defined by three inputs,
+    /// and one of them must be [`pressure`](HumidAirInput::pressure).
+    ///
+    /// # Args
+    ///
+    /// - `input1` -- first input property.
+    /// - `input2` -- second input property.
+    /// - `input3` -- third input property.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`HumidAirStateError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::humid_air::HumidAir;
+    /// use rfluids::io::HumidAirInput;
+    /// use rfluids::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+    /// use rfluids::uom::si::pressure::atmosphere;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// let humid_air = HumidAir::new().update(
+    ///     HumidAirInput::pressure(Pressure::new::<atmosphere>(1.0)),
+    ///     HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+    ///     HumidAirInput::relative_humidity(Ratio::new::<percent>(50.0)),
+    /// );
+    /// assert!(humid_air.is_ok());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`HumidAirInput`]
+    pub fn update(
+        mut self,
+        input1: HumidAirInput,
+        input2: HumidAirInput,
+        input3: HumidAirInput,
+    ) -> Result<HumidAir<DefinedState>, HumidAirStateError> {
+        let request = HumidAirUpdateRequest::try_from((input1, input2, input3))
+            .map_err(|_| HumidAirStateError::InvalidInputs(input1.key, input2.key, input3.key))?;
+        self.outputs.clear();
+        self.outputs
+            .remember(&request, HumidAirParam::W)
+            .map_err(HumidAirStateError::UpdateFailed)?;
+        self.update_request = Some(request);
+        Ok(self.into_state())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+    use crate::uom::si::pressure::atmosphere;
+    use crate::uom::si::ratio::percent;
+    use crate::uom::si::thermodynamic_temperature::degree_celsius;
+
+    #[test]
+    fn update_valid_inputs_returns_ok() {
+        let result = HumidAir::new().update(
+            HumidAirInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            HumidAirInput::relative_humidity(Ratio::new::<percent>(50.0)),
+        );
+        assert!(result.is_ok());
+        assert!(result.unwrap().update_request.is_some());
+    }
+
+    #[test]
+    fn update_same_inputs_returns_err() {
+        let temperature =
+            HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+        let result = HumidAir::new().update(
+            HumidAirInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            temperature,
+            temperature,
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            HumidAirStateError::InvalidInputs(HumidAirParam::P, HumidAirParam::T, HumidAirParam::T)
+        );
+    }
+
+    #[test]
+    fn update_invalid_inputs_returns_err() {
+        let result = HumidAir::new().update(
+            HumidAirInput::pressure(Pressure::new::<atmosphere>(1.0)),
+            HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0)),
+            HumidAirInput::relative_humidity(Ratio::new::<percent>(200.0)),
+        );
+        assert!(matches!(result, Err(HumidAirStateError::UpdateFailed(_))));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::io::FluidParam;
+use crate::error::FluidInputPairError;
+use crate::io::{FluidInput, FluidParam};
 
 /// CoolProp input pairs.
 ///
@@ -26,7 +27,30 @@ use crate::io::FluidParam;
 ///     Ok(FluidInputPair::PT)
 /// );
 /// ```
+///
+/// Conversion from two [`FluidInput`]s:
+///
+/// ```
+/// use rfluids::error::FluidInputPairError;
+/// use rfluids::io::{FluidInput, FluidInputPair, FluidParam};
+/// use rfluids::uom::si::f64::{Pressure, ThermodynamicTemperature};
+/// use rfluids::uom::si::pressure::atmosphere;
+/// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+///
+/// let pressure = FluidInput::pressure(Pressure::new::<atmosphere>(1.0));
+/// let temperature =
+///     FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+/// assert_eq!(
+///     FluidInputPair::try_from((temperature, pressure)),
+///     Ok(FluidInputPair::PT)
+/// );
+/// assert_eq!(
+///     FluidInputPair::try_from((pressure, pressure)),
+///     Err(FluidInputPairError::DuplicateKey(FluidParam::P))
+/// );
+/// ```
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(test, derive(strum_macros::EnumIter))]
 pub enum FluidInputPair {
     /// Vapor quality _(dimensionless, from 0 to 1)_, temperature _(K)_.
     QT = 1,
@@ -134,6 +158,77 @@ pub enum FluidInputPair {
     DMolarUMolar = 35,
 }
 
+impl FluidInputPair {
+    /// All input pairs _(in the same order as in CoolProp)_.
+    ///
+    /// **NB.** Not every input pair is supported by every backend
+    /// _(e.g., `HEOS` doesn't support [`TUMass`](FluidInputPair::TUMass)
+    /// or [`HMassT`](FluidInputPair::HMassT) yet)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::io::{FluidInputPair, FluidParam};
+    ///
+    /// assert_eq!(FluidInputPair::all().len(), 35);
+    /// assert!(FluidInputPair::all()
+    ///     .iter()
+    ///     .any(|pair| pair.params() == (FluidParam::P, FluidParam::T)));
+    /// ```
+    pub fn all() -> &'static [FluidInputPair] {
+        &[
+            FluidInputPair::QT,
+            FluidInputPair::PQ,
+            FluidInputPair::QSMolar,
+            FluidInputPair::QSMass,
+            FluidInputPair::HMolarQ,
+            FluidInputPair::HMassQ,
+            FluidInputPair::DMolarQ,
+            FluidInputPair::DMassQ,
+            FluidInputPair::PT,
+            FluidInputPair::DMassT,
+            FluidInputPair::DMolarT,
+            FluidInputPair::HMolarT,
+            FluidInputPair::HMassT,
+            FluidInputPair::SMolarT,
+            FluidInputPair::SMassT,
+            FluidInputPair::TUMolar,
+            FluidInputPair::TUMass,
+            FluidInputPair::DMassP,
+            FluidInputPair::DMolarP,
+            FluidInputPair::HMassP,
+            FluidInputPair::HMolarP,
+            FluidInputPair::PSMass,
+            FluidInputPair::PSMolar,
+            FluidInputPair::PUMass,
+            FluidInputPair::PUMolar,
+            FluidInputPair::HMassSMass,
+            FluidInputPair::HMolarSMolar,
+            FluidInputPair::SMassUMass,
+            FluidInputPair::SMolarUMolar,
+            FluidInputPair::DMassHMass,
+            FluidInputPair::DMolarHMolar,
+            FluidInputPair::DMassSMass,
+            FluidInputPair::DMolarSMolar,
+            FluidInputPair::DMassUMass,
+            FluidInputPair::DMolarUMolar,
+        ]
+    }
+
+    /// Keys of the input parameters _(in the same order as in the input pair)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::io::{FluidInputPair, FluidParam};
+    ///
+    /// assert_eq!(FluidInputPair::PT.params(), (FluidParam::P, FluidParam::T));
+    /// ```
+    pub fn params(&self) -> (FluidParam, FluidParam) {
+        (*self).into()
+    }
+}
+
 impl From<FluidInputPair> for u8 {
     fn from(value: FluidInputPair) -> Self {
         value as u8
@@ -297,12 +392,89 @@ impl TryFrom<(FluidParam, FluidParam)> for FluidInputPair {
     }
 }
 
+impl TryFrom<(FluidInput, FluidInput)> for FluidInputPair {
+    type Error = FluidInputPairError;
+
+    fn try_from(value: (FluidInput, FluidInput)) -> Result<Self, Self::Error> {
+        let (key1, key2) = (value.0.key, value.1.key);
+        if key1 == key2 {
+            return Err(FluidInputPairError::DuplicateKey(key1));
+        }
+        FluidInputPair::try_from((key1, key2))
+            .map_err(|_| FluidInputPairError::Unsupported(key1, key2))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::FluidInputPair::*;
     use super::FluidParam::*;
     use super::*;
+    use crate::native::AbstractState;
+    use crate::uom::si::f64::{
+        MassDensity, MolarConcentration, Pressure, ThermodynamicTemperature,
+    };
+    use crate::uom::si::mass_density::kilogram_per_cubic_meter;
+    use crate::uom::si::molar_concentration::mole_per_cubic_meter;
+    use crate::uom::si::pressure::pascal;
+    use crate::uom::si::thermodynamic_temperature::kelvin;
     use rstest::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn all_returns_each_pair_once() {
+        let result = FluidInputPair::all();
+        assert_eq!(result.len(), FluidInputPair::iter().count());
+        assert!(FluidInputPair::iter().all(|pair| result.contains(&pair)));
+    }
+
+    #[test]
+    fn all_pairs_except_unsupported_by_heos_update_water_successfully() {
+        let unsupported = [
+            QSMass,
+            HMolarQ,
+            HMassQ,
+            HMolarT,
+            HMassT,
+            TUMolar,
+            TUMass,
+            SMassUMass,
+            SMolarUMolar,
+        ];
+        let state = |pair: FluidInputPair, value1: f64, value2: f64| {
+            let mut backend = AbstractState::new("HEOS", "Water").unwrap();
+            backend.update(pair, value1, value2).unwrap();
+            backend
+        };
+        let saturated = state(PQ, 101325.0, 1.0);
+        let single_phase = state(PT, 101325.0, 473.15);
+        for &pair in FluidInputPair::all() {
+            let (key1, key2) = pair.params();
+            let reference = if key1 == Q || key2 == Q {
+                &saturated
+            } else {
+                &single_phase
+            };
+            let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+            let result = sut.update(
+                pair,
+                reference.keyed_output(key1).unwrap(),
+                reference.keyed_output(key2).unwrap(),
+            );
+            assert_eq!(result.is_ok(), !unsupported.contains(&pair), "{pair:?}");
+        }
+    }
+
+    #[rstest]
+    #[case(PT, (P, T))]
+    #[case(QT, (Q, T))]
+    #[case(DMolarUMolar, (DMolar, UMolar))]
+    fn params_returns_expected_keys(
+        #[case] pair: FluidInputPair,
+        #[case] expected: (FluidParam, FluidParam),
+    ) {
+        assert_eq!(pair.params(), expected);
+    }
 
     #[rstest]
     #[case(QT, 1)]
@@ -475,4 +647,32 @@ mod tests {
     fn try_from_two_invalid_params_returns_err(#[case] invalid_params: (FluidParam, FluidParam)) {
         assert!(FluidInputPair::try_from(invalid_params).is_err());
     }
+
+    #[test]
+    fn try_from_two_valid_inputs_with_invariant_order_returns_ok() {
+        let input1 = FluidInput::temperature(ThermodynamicTemperature::new::<kelvin>(293.15));
+        let input2 = FluidInput::pressure(Pressure::new::<pascal>(101325.0));
+        assert_eq!(FluidInputPair::try_from((input1, input2)), Ok(PT));
+        assert_eq!(FluidInputPair::try_from((input2, input1)), Ok(PT));
+    }
+
+    #[test]
+    fn try_from_two_inputs_with_same_key_returns_err() {
+        let input = FluidInput::pressure(Pressure::new::<pascal>(101325.0));
+        assert_eq!(
+            FluidInputPair::try_from((input, input)),
+            Err(FluidInputPairError::DuplicateKey(P))
+        );
+    }
+
+    #[test]
+    fn try_from_two_unsupported_inputs_returns_err() {
+        let input1 = FluidInput::density(MassDensity::new::<kilogram_per_cubic_meter>(1.0));
+        let input2 =
+            FluidInput::molar_density(MolarConcentration::new::<mole_per_cubic_meter>(1.0));
+        assert_eq!(
+            FluidInputPair::try_from((input1, input2)),
+            Err(FluidInputPairError::Unsupported(DMass, DMolar))
+        );
+    }
 }
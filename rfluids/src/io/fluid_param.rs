@@ -1,5 +1,5 @@
 use crate::io::try_from;
-use strum_macros::{AsRefStr, EnumString, FromRepr};
+use strum_macros::{AsRefStr, EnumIter, EnumString, FromRepr};
 
 /// CoolProp input/output parameters.
 ///
@@ -16,6 +16,27 @@ use strum_macros::{AsRefStr, EnumString, FromRepr};
 /// assert_eq!(FluidParam::try_from("L"), Ok(FluidParam::Conductivity));
 /// ```
 ///
+/// Parsing of the user-supplied list of outputs
+/// _(CoolProp aliases are accepted, case-insensitive)_:
+///
+/// ```
+/// use std::str::FromStr;
+/// use rfluids::io::FluidParam;
+///
+/// let result = "T, P, D, Hmass"
+///     .split(',')
+///     .map(|s| FluidParam::from_str(s.trim()))
+///     .collect::<Result<Vec<_>, _>>();
+/// assert_eq!(
+///     result,
+///     Ok(vec![FluidParam::T, FluidParam::P, FluidParam::DMass, FluidParam::HMass])
+/// );
+/// assert_eq!(
+///     FluidParam::from_str("Hello, World!"),
+///     Err(strum::ParseError::VariantNotFound)
+/// );
+/// ```
+///
 /// Conversion between [`u8`]:
 ///
 /// ```
@@ -53,6 +74,7 @@ use strum_macros::{AsRefStr, EnumString, FromRepr};
 /// - [CoolProp input/output parameters _(only those for which the value in the "Trivial" column is "False")_](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#parameter-table)
 //noinspection SpellCheckingInspection
 #[derive(AsRefStr, EnumString, FromRepr, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(test, derive(EnumIter))]
 #[strum(ascii_case_insensitive)]
 #[repr(u8)]
 pub enum FluidParam {
@@ -271,6 +293,63 @@ pub enum FluidParam {
     Phase = 78,
 }
 
+/// Category of the CoolProp parameter.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::io::{FluidParam, FluidParamKind, FluidTrivialParam};
+///
+/// assert_eq!(FluidParam::HMass.kind(), FluidParamKind::State);
+/// assert_eq!(FluidParam::CpMass.kind(), FluidParamKind::Thermodynamic);
+/// assert_eq!(FluidParam::DynamicViscosity.kind(), FluidParamKind::Transport);
+/// assert_eq!(FluidTrivialParam::TCritical.kind(), FluidParamKind::Trivial);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum FluidParamKind {
+    /// State variable, which can be used both as an input and as an output
+    /// _(e.g., [`T`](FluidParam::T), [`P`](FluidParam::P) or [`HMass`](FluidParam::HMass))_.
+    State,
+
+    /// State-dependent thermodynamic property, which can be used only as an output
+    /// _(e.g., [`CpMass`](FluidParam::CpMass) or [`Z`](FluidParam::Z))_.
+    Thermodynamic,
+
+    /// State-dependent transport property, which can be used only as an output
+    /// _(e.g., [`DynamicViscosity`](FluidParam::DynamicViscosity))_.
+    Transport,
+
+    /// State-independent constant, which can be used only as an output
+    /// _(any [`FluidTrivialParam`])_.
+    Trivial,
+}
+
+impl FluidParam {
+    /// Category of the parameter _(see [`FluidParamKind`])_.
+    pub fn kind(&self) -> FluidParamKind {
+        match self {
+            FluidParam::T
+            | FluidParam::P
+            | FluidParam::Q
+            | FluidParam::Tau
+            | FluidParam::Delta
+            | FluidParam::DMolar
+            | FluidParam::HMolar
+            | FluidParam::SMolar
+            | FluidParam::UMolar
+            | FluidParam::DMass
+            | FluidParam::HMass
+            | FluidParam::SMass
+            | FluidParam::UMass => FluidParamKind::State,
+            FluidParam::DynamicViscosity
+            | FluidParam::Conductivity
+            | FluidParam::SurfaceTension
+            | FluidParam::Prandtl => FluidParamKind::Transport,
+            _ => FluidParamKind::Thermodynamic,
+        }
+    }
+}
+
 impl From<FluidParam> for u8 {
     fn from(value: FluidParam) -> Self {
         value as u8
@@ -329,7 +408,7 @@ impl TryFrom<f64> for FluidParam {
 ///
 /// - [CoolProp input/output parameters _(only those for which the value in the "Trivial" column is "True")_](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#parameter-table)
 //noinspection SpellCheckingInspection
-#[derive(AsRefStr, EnumString, FromRepr, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(AsRefStr, EnumString, EnumIter, FromRepr, Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[strum(ascii_case_insensitive)]
 #[repr(u8)]
 pub enum FluidTrivialParam {
@@ -453,6 +532,18 @@ pub enum FluidTrivialParam {
     ODP = 77,
 }
 
+impl FluidTrivialParam {
+    /// Category of the parameter _(always [`Trivial`](FluidParamKind::Trivial))_.
+    pub fn kind(&self) -> FluidParamKind {
+        FluidParamKind::Trivial
+    }
+
+    /// Returns `true` if the parameter is state-independent _(always `true`)_.
+    pub fn is_trivial(&self) -> bool {
+        true
+    }
+}
+
 impl From<FluidTrivialParam> for u8 {
     fn from(value: FluidTrivialParam) -> Self {
         value as u8
@@ -480,9 +571,11 @@ mod tests {
     use super::FluidParam::*;
     use super::FluidTrivialParam::*;
     use super::*;
+    use crate::io::FluidInputPair;
     use rstest::*;
     use std::fmt::Debug;
     use std::str::FromStr;
+    use strum::IntoEnumIterator;
 
     //noinspection SpellCheckingInspection
     #[rstest]
@@ -673,6 +766,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_str_list_of_aliases_returns_expected_params() {
+        let result = ["t", "P", "D", "Hmass", "smass", "A", "V", "L", "Phase"]
+            .map(FluidParam::from_str)
+            .map(Result::unwrap);
+        assert_eq!(
+            result,
+            [
+                T,
+                P,
+                DMass,
+                HMass,
+                SMass,
+                SoundSpeed,
+                DynamicViscosity,
+                Conductivity,
+                Phase
+            ]
+        );
+        assert_eq!(
+            FluidParam::from_str("rho"),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
+
     #[rstest]
     #[case("")]
     #[case("Hello, World!")]
@@ -874,4 +992,46 @@ mod tests {
         assert!(FluidParam::try_from(invalid_value).is_err());
         assert!(FluidTrivialParam::try_from(invalid_value).is_err());
     }
+
+    #[rstest]
+    #[case(T, FluidParamKind::State)]
+    #[case(Q, FluidParamKind::State)]
+    #[case(Tau, FluidParamKind::State)]
+    #[case(HMolar, FluidParamKind::State)]
+    #[case(UMass, FluidParamKind::State)]
+    #[case(CpMass, FluidParamKind::Thermodynamic)]
+    #[case(GMolar, FluidParamKind::Thermodynamic)]
+    #[case(SoundSpeed, FluidParamKind::Thermodynamic)]
+    #[case(Z, FluidParamKind::Thermodynamic)]
+    #[case(FluidParam::Phase, FluidParamKind::Thermodynamic)]
+    #[case(DynamicViscosity, FluidParamKind::Transport)]
+    #[case(Conductivity, FluidParamKind::Transport)]
+    #[case(SurfaceTension, FluidParamKind::Transport)]
+    #[case(Prandtl, FluidParamKind::Transport)]
+    fn kind_returns_expected_value(#[case] param: FluidParam, #[case] expected: FluidParamKind) {
+        assert_eq!(param.kind(), expected);
+    }
+
+    #[test]
+    fn kind_of_state_params_matches_valid_input_pairs() {
+        for param in FluidParam::iter() {
+            let used_as_input = FluidInputPair::iter().any(|pair| {
+                let (key1, key2): (FluidParam, FluidParam) = pair.into();
+                key1 == param || key2 == param
+            });
+            assert_eq!(
+                param.kind() == FluidParamKind::State,
+                used_as_input || matches!(param, Tau | Delta),
+                "{param:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn kind_of_trivial_params_is_trivial() {
+        for param in FluidTrivialParam::iter() {
+            assert_eq!(param.kind(), FluidParamKind::Trivial);
+            assert!(param.is_trivial());
+        }
+    }
 }
@@ -1,8 +1,27 @@
-use crate::io::FluidParam;
+use crate::io::{FluidParam, HumidAirParam};
+use crate::uom::si::available_energy::joule_per_kilogram;
 use crate::uom::si::f64::{
     AvailableEnergy, MassDensity, MolarConcentration, MolarEnergy, MolarHeatCapacity, Pressure,
-    Ratio, SpecificHeatCapacity, ThermodynamicTemperature,
+    Ratio, SpecificHeatCapacity, SpecificVolume, ThermodynamicTemperature,
 };
+use crate::uom::si::mass_density::kilogram_per_cubic_meter;
+use crate::uom::si::molar_concentration::mole_per_cubic_meter;
+use crate::uom::si::molar_energy::joule_per_mole;
+use crate::uom::si::molar_heat_capacity::joule_per_kelvin_mole;
+use crate::uom::si::pressure::pascal;
+use crate::uom::si::ratio::ratio;
+use crate::uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+use crate::uom::si::thermodynamic_temperature::kelvin;
+use std::str::FromStr;
+
+/// Input identified by the key.
+pub trait KeyedInput<K> {
+    /// Specified key.
+    fn key(&self) -> K;
+
+    /// Specified value _(in SI units)_.
+    fn si_value(&self) -> f64;
+}
 
 /// Keyed input.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -14,6 +33,16 @@ pub struct Input<T> {
     pub si_value: f64,
 }
 
+impl<K: Copy> KeyedInput<K> for Input<K> {
+    fn key(&self) -> K {
+        self.key
+    }
+
+    fn si_value(&self) -> f64 {
+        self.si_value
+    }
+}
+
 /// Fluid keyed input.
 ///
 /// # Examples
@@ -31,6 +60,11 @@ pub struct Input<T> {
 ///     FluidInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
 /// let density =
 ///     FluidInput::density(MassDensity::new::<gram_per_cubic_centimeter>(1.0));
+/// assert_eq!(
+///     pressure.as_pressure().unwrap().get::<atmosphere>(),
+///     1.0
+/// );
+/// assert!(pressure.as_temperature().is_none());
 /// ```
 pub type FluidInput = Input<FluidParam>;
 
@@ -122,6 +156,211 @@ impl FluidInput {
             si_value: value.value,
         }
     }
+
+    /// Specified value as mass density
+    /// _(`None` if the key is not [`DMass`](FluidParam::DMass))_.
+    pub fn as_density(&self) -> Option<MassDensity> {
+        self.value_of(FluidParam::DMass)
+            .map(MassDensity::new::<kilogram_per_cubic_meter>)
+    }
+
+    /// Specified value as mass specific enthalpy
+    /// _(`None` if the key is not [`HMass`](FluidParam::HMass))_.
+    pub fn as_enthalpy(&self) -> Option<AvailableEnergy> {
+        self.value_of(FluidParam::HMass)
+            .map(AvailableEnergy::new::<joule_per_kilogram>)
+    }
+
+    /// Specified value as mass specific entropy
+    /// _(`None` if the key is not [`SMass`](FluidParam::SMass))_.
+    pub fn as_entropy(&self) -> Option<SpecificHeatCapacity> {
+        self.value_of(FluidParam::SMass)
+            .map(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>)
+    }
+
+    /// Specified value as mass specific internal energy
+    /// _(`None` if the key is not [`UMass`](FluidParam::UMass))_.
+    pub fn as_internal_energy(&self) -> Option<AvailableEnergy> {
+        self.value_of(FluidParam::UMass)
+            .map(AvailableEnergy::new::<joule_per_kilogram>)
+    }
+
+    /// Specified value as molar density
+    /// _(`None` if the key is not [`DMolar`](FluidParam::DMolar))_.
+    pub fn as_molar_density(&self) -> Option<MolarConcentration> {
+        self.value_of(FluidParam::DMolar)
+            .map(MolarConcentration::new::<mole_per_cubic_meter>)
+    }
+
+    /// Specified value as molar specific enthalpy
+    /// _(`None` if the key is not [`HMolar`](FluidParam::HMolar))_.
+    pub fn as_molar_enthalpy(&self) -> Option<MolarEnergy> {
+        self.value_of(FluidParam::HMolar)
+            .map(MolarEnergy::new::<joule_per_mole>)
+    }
+
+    /// Specified value as molar specific entropy
+    /// _(`None` if the key is not [`SMolar`](FluidParam::SMolar))_.
+    pub fn as_molar_entropy(&self) -> Option<MolarHeatCapacity> {
+        self.value_of(FluidParam::SMolar)
+            .map(MolarHeatCapacity::new::<joule_per_kelvin_mole>)
+    }
+
+    /// Specified value as molar specific internal energy
+    /// _(`None` if the key is not [`UMolar`](FluidParam::UMolar))_.
+    pub fn as_molar_internal_energy(&self) -> Option<MolarEnergy> {
+        self.value_of(FluidParam::UMolar)
+            .map(MolarEnergy::new::<joule_per_mole>)
+    }
+
+    /// Specified value as pressure
+    /// _(`None` if the key is not [`P`](FluidParam::P))_.
+    pub fn as_pressure(&self) -> Option<Pressure> {
+        self.value_of(FluidParam::P).map(Pressure::new::<pascal>)
+    }
+
+    /// Specified value as vapor quality
+    /// _(`None` if the key is not [`Q`](FluidParam::Q))_.
+    pub fn as_quality(&self) -> Option<Ratio> {
+        self.value_of(FluidParam::Q).map(Ratio::new::<ratio>)
+    }
+
+    /// Specified value as temperature
+    /// _(`None` if the key is not [`T`](FluidParam::T))_.
+    pub fn as_temperature(&self) -> Option<ThermodynamicTemperature> {
+        self.value_of(FluidParam::T)
+            .map(ThermodynamicTemperature::new::<kelvin>)
+    }
+
+    fn value_of(&self, key: FluidParam) -> Option<f64> {
+        (self.key == key).then_some(self.si_value)
+    }
+}
+
+impl TryFrom<(&str, f64)> for FluidInput {
+    type Error = strum::ParseError;
+
+    /// Creates a fluid input from the CoolProp parameter name
+    /// _(aliases are accepted, case-insensitive)_ and its value.
+    ///
+    /// **NB.** The value is used as is, i.e., it must already be **in SI units**.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::io::{FluidInput, FluidParam};
+    ///
+    /// let input = FluidInput::try_from(("T", 300.0)).unwrap();
+    /// assert_eq!(input.key, FluidParam::T);
+    /// assert_eq!(input.si_value, 300.0);
+    /// assert_eq!(
+    ///     FluidInput::try_from(("Hello, World!", 300.0)),
+    ///     Err(strum::ParseError::VariantNotFound)
+    /// );
+    /// ```
+    fn try_from(value: (&str, f64)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            key: FluidParam::from_str(value.0)?,
+            si_value: value.1,
+        })
+    }
+}
+
+/// Humid air keyed input.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::io::HumidAirInput;
+/// use rfluids::uom::si::f64::{Pressure, Ratio, ThermodynamicTemperature};
+/// use rfluids::uom::si::pressure::atmosphere;
+/// use rfluids::uom::si::ratio::percent;
+/// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+///
+/// let pressure =
+///     HumidAirInput::pressure(Pressure::new::<atmosphere>(1.0));
+/// let temperature =
+///     HumidAirInput::temperature(ThermodynamicTemperature::new::<degree_celsius>(20.0));
+/// let relative_humidity =
+///     HumidAirInput::relative_humidity(Ratio::new::<percent>(50.0));
+/// ```
+pub type HumidAirInput = Input<HumidAirParam>;
+
+impl HumidAirInput {
+    /// Dew-point temperature _(key: [`TDew`](HumidAirParam::TDew), SI units: K)_.
+    pub fn dew_point_temperature(value: ThermodynamicTemperature) -> Self {
+        Self {
+            key: HumidAirParam::TDew,
+            si_value: value.value,
+        }
+    }
+
+    /// Specific enthalpy per unit of dry air
+    /// _(key: [`Hda`](HumidAirParam::Hda), SI units: J/kg dry air)_.
+    pub fn enthalpy(value: AvailableEnergy) -> Self {
+        Self {
+            key: HumidAirParam::Hda,
+            si_value: value.value,
+        }
+    }
+
+    /// Specific entropy per unit of dry air
+    /// _(key: [`Sda`](HumidAirParam::Sda), SI units: J/kg dry air/K)_.
+    pub fn entropy(value: SpecificHeatCapacity) -> Self {
+        Self {
+            key: HumidAirParam::Sda,
+            si_value: value.value,
+        }
+    }
+
+    /// Humidity ratio _(key: [`W`](HumidAirParam::W), SI units: kg water/kg dry air)_.
+    pub fn humidity_ratio(value: Ratio) -> Self {
+        Self {
+            key: HumidAirParam::W,
+            si_value: value.value,
+        }
+    }
+
+    /// Pressure _(key: [`P`](HumidAirParam::P), SI units: Pa)_.
+    pub fn pressure(value: Pressure) -> Self {
+        Self {
+            key: HumidAirParam::P,
+            si_value: value.value,
+        }
+    }
+
+    /// Relative humidity _(key: [`R`](HumidAirParam::R), SI units: dimensionless, from 0 to 1)_.
+    pub fn relative_humidity(value: Ratio) -> Self {
+        Self {
+            key: HumidAirParam::R,
+            si_value: value.value,
+        }
+    }
+
+    /// Specific volume per unit of dry air
+    /// _(key: [`Vda`](HumidAirParam::Vda), SI units: m³/kg dry air)_.
+    pub fn specific_volume(value: SpecificVolume) -> Self {
+        Self {
+            key: HumidAirParam::Vda,
+            si_value: value.value,
+        }
+    }
+
+    /// Dry-bulb temperature _(key: [`T`](HumidAirParam::T), SI units: K)_.
+    pub fn temperature(value: ThermodynamicTemperature) -> Self {
+        Self {
+            key: HumidAirParam::T,
+            si_value: value.value,
+        }
+    }
+
+    /// Wet-bulb temperature _(key: [`TWetBulb`](HumidAirParam::TWetBulb), SI units: K)_.
+    pub fn wet_bulb_temperature(value: ThermodynamicTemperature) -> Self {
+        Self {
+            key: HumidAirParam::TWetBulb,
+            si_value: value.value,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -130,15 +369,7 @@ mod tests {
 
     mod fluid_input {
         use super::*;
-        use crate::uom::si::available_energy::joule_per_kilogram;
-        use crate::uom::si::mass_density::kilogram_per_cubic_meter;
-        use crate::uom::si::molar_concentration::mole_per_cubic_meter;
-        use crate::uom::si::molar_energy::joule_per_mole;
-        use crate::uom::si::molar_heat_capacity::joule_per_kelvin_mole;
-        use crate::uom::si::pressure::pascal;
-        use crate::uom::si::ratio::ratio;
-        use crate::uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
-        use crate::uom::si::thermodynamic_temperature::kelvin;
+        use rstest::*;
 
         #[test]
         fn density_returns_expected_key_and_si_value() {
@@ -219,5 +450,174 @@ mod tests {
             assert_eq!(sut.key, FluidParam::T);
             assert_eq!(sut.si_value, 1.0);
         }
+
+        #[rstest]
+        #[case("T", FluidParam::T)]
+        #[case("p", FluidParam::P)]
+        #[case("Dmass", FluidParam::DMass)]
+        #[case("Q", FluidParam::Q)]
+        fn try_from_valid_name_returns_ok(#[case] name: &str, #[case] expected_key: FluidParam) {
+            let sut = FluidInput::try_from((name, 42.0)).unwrap();
+            assert_eq!(sut.key(), expected_key);
+            assert_eq!(sut.si_value(), 42.0);
+        }
+
+        #[test]
+        fn try_from_invalid_name_returns_err() {
+            assert_eq!(
+                FluidInput::try_from(("Hello, World!", 42.0)),
+                Err(strum::ParseError::VariantNotFound)
+            );
+        }
+
+        #[test]
+        fn as_quantity_with_matching_key_returns_some() {
+            let value = 42.0;
+            assert_eq!(
+                FluidInput::density(MassDensity::new::<kilogram_per_cubic_meter>(value))
+                    .as_density(),
+                Some(MassDensity::new::<kilogram_per_cubic_meter>(value))
+            );
+            assert_eq!(
+                FluidInput::enthalpy(AvailableEnergy::new::<joule_per_kilogram>(value))
+                    .as_enthalpy(),
+                Some(AvailableEnergy::new::<joule_per_kilogram>(value))
+            );
+            assert_eq!(
+                FluidInput::entropy(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+                    value
+                ))
+                .as_entropy(),
+                Some(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(
+                    value
+                ))
+            );
+            assert_eq!(
+                FluidInput::internal_energy(AvailableEnergy::new::<joule_per_kilogram>(value))
+                    .as_internal_energy(),
+                Some(AvailableEnergy::new::<joule_per_kilogram>(value))
+            );
+            assert_eq!(
+                FluidInput::molar_density(MolarConcentration::new::<mole_per_cubic_meter>(value))
+                    .as_molar_density(),
+                Some(MolarConcentration::new::<mole_per_cubic_meter>(value))
+            );
+            assert_eq!(
+                FluidInput::molar_enthalpy(MolarEnergy::new::<joule_per_mole>(value))
+                    .as_molar_enthalpy(),
+                Some(MolarEnergy::new::<joule_per_mole>(value))
+            );
+            assert_eq!(
+                FluidInput::molar_entropy(MolarHeatCapacity::new::<joule_per_kelvin_mole>(value))
+                    .as_molar_entropy(),
+                Some(MolarHeatCapacity::new::<joule_per_kelvin_mole>(value))
+            );
+            assert_eq!(
+                FluidInput::molar_internal_energy(MolarEnergy::new::<joule_per_mole>(value))
+                    .as_molar_internal_energy(),
+                Some(MolarEnergy::new::<joule_per_mole>(value))
+            );
+            assert_eq!(
+                FluidInput::pressure(Pressure::new::<pascal>(value)).as_pressure(),
+                Some(Pressure::new::<pascal>(value))
+            );
+            assert_eq!(
+                FluidInput::quality(Ratio::new::<ratio>(0.5)).as_quality(),
+                Some(Ratio::new::<ratio>(0.5))
+            );
+            assert_eq!(
+                FluidInput::temperature(ThermodynamicTemperature::new::<kelvin>(value))
+                    .as_temperature(),
+                Some(ThermodynamicTemperature::new::<kelvin>(value))
+            );
+        }
+
+        #[test]
+        fn as_quantity_with_other_key_returns_none() {
+            let sut = FluidInput::temperature(ThermodynamicTemperature::new::<kelvin>(1.0));
+            assert!(sut.as_density().is_none());
+            assert!(sut.as_enthalpy().is_none());
+            assert!(sut.as_entropy().is_none());
+            assert!(sut.as_internal_energy().is_none());
+            assert!(sut.as_molar_density().is_none());
+            assert!(sut.as_molar_enthalpy().is_none());
+            assert!(sut.as_molar_entropy().is_none());
+            assert!(sut.as_molar_internal_energy().is_none());
+            assert!(sut.as_pressure().is_none());
+            assert!(sut.as_quality().is_none());
+        }
+
+        #[test]
+        fn keyed_input_returns_same_key_and_si_value() {
+            let sut = FluidInput::pressure(Pressure::new::<pascal>(1.0));
+            assert_eq!(KeyedInput::key(&sut), sut.key);
+            assert_eq!(KeyedInput::si_value(&sut), sut.si_value);
+        }
+    }
+
+    mod humid_air_input {
+        use super::*;
+        use crate::uom::si::available_energy::joule_per_kilogram;
+        use crate::uom::si::pressure::pascal;
+        use crate::uom::si::ratio::{percent, ratio};
+        use crate::uom::si::specific_heat_capacity::joule_per_kilogram_kelvin;
+        use crate::uom::si::specific_volume::cubic_meter_per_kilogram;
+        use crate::uom::si::thermodynamic_temperature::kelvin;
+        use rstest::*;
+
+        #[rstest]
+        #[case(
+            HumidAirInput::dew_point_temperature(ThermodynamicTemperature::new::<kelvin>(1.0)),
+            HumidAirParam::TDew
+        )]
+        #[case(
+            HumidAirInput::enthalpy(AvailableEnergy::new::<joule_per_kilogram>(1.0)),
+            HumidAirParam::Hda
+        )]
+        #[case(
+            HumidAirInput::entropy(SpecificHeatCapacity::new::<joule_per_kilogram_kelvin>(1.0)),
+            HumidAirParam::Sda
+        )]
+        #[case(
+            HumidAirInput::humidity_ratio(Ratio::new::<ratio>(1.0)),
+            HumidAirParam::W
+        )]
+        #[case(HumidAirInput::pressure(Pressure::new::<pascal>(1.0)), HumidAirParam::P)]
+        #[case(
+            HumidAirInput::relative_humidity(Ratio::new::<ratio>(1.0)),
+            HumidAirParam::R
+        )]
+        #[case(
+            HumidAirInput::specific_volume(SpecificVolume::new::<cubic_meter_per_kilogram>(1.0)),
+            HumidAirParam::Vda
+        )]
+        #[case(
+            HumidAirInput::temperature(ThermodynamicTemperature::new::<kelvin>(1.0)),
+            HumidAirParam::T
+        )]
+        #[case(
+            HumidAirInput::wet_bulb_temperature(ThermodynamicTemperature::new::<kelvin>(1.0)),
+            HumidAirParam::TWetBulb
+        )]
+        fn new_returns_expected_key_and_si_value(
+            #[case] sut: HumidAirInput,
+            #[case] expected_key: HumidAirParam,
+        ) {
+            assert_eq!(sut.key, expected_key);
+            assert_eq!(sut.si_value, 1.0);
+        }
+
+        #[test]
+        fn relative_humidity_stores_dimensionless_fraction() {
+            let sut = HumidAirInput::relative_humidity(Ratio::new::<percent>(50.0));
+            assert_eq!(sut.si_value, 0.5);
+        }
+
+        #[test]
+        fn keyed_input_returns_same_key_and_si_value() {
+            let sut = HumidAirInput::pressure(Pressure::new::<pascal>(1.0));
+            assert_eq!(KeyedInput::key(&sut), sut.key);
+            assert_eq!(KeyedInput::si_value(&sut), sut.si_value);
+        }
     }
 }
@@ -4,13 +4,17 @@ pub use fluid_input_pair::*;
 pub use fluid_param::*;
 pub use humid_air_param::*;
 pub use input::*;
+pub use output::*;
 pub use phase::*;
+pub use reference_state::*;
 
 mod fluid_input_pair;
 mod fluid_param;
 mod humid_air_param;
 mod input;
+mod output;
 mod phase;
+mod reference_state;
 
 pub(crate) fn try_from<T: TryFrom<u8, Error = strum::ParseError>>(
     value: f64,
@@ -0,0 +1,108 @@
+use crate::io::{FluidParam, FluidTrivialParam, HumidAirParam};
+
+/// Output identified by the key.
+///
+/// It allows generic code _(e.g., logging or serialization)_
+/// to treat fluid and humid air outputs uniformly.
+pub trait KeyedOutput<K> {
+    /// Output key.
+    fn key(&self) -> K;
+
+    /// Output value _(in SI units)_.
+    fn si_value(&self) -> f64;
+}
+
+/// Keyed output.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::io::{FluidOutput, FluidParam, HumidAirOutput, HumidAirParam, KeyedOutput};
+///
+/// fn row<K: AsRef<str>>(output: &impl KeyedOutput<K>) -> String {
+///     format!("{}: {}", output.key().as_ref(), output.si_value())
+/// }
+///
+/// let fluid_output = FluidOutput::new(FluidParam::T, 293.15);
+/// let humid_air_output = HumidAirOutput::new(HumidAirParam::R, 0.5);
+/// assert_eq!(row(&fluid_output), "T: 293.15");
+/// assert_eq!(row(&humid_air_output), "R: 0.5");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Output<T> {
+    /// Output key.
+    pub key: T,
+    /// Output value _(in SI units)_.
+    pub si_value: f64,
+}
+
+impl<T> Output<T> {
+    /// Creates and returns a new [`Output`] instance.
+    ///
+    /// # Args
+    ///
+    /// - `key` -- output key.
+    /// - `si_value` -- output value _(in SI units)_.
+    pub fn new(key: T, si_value: f64) -> Self {
+        Self { key, si_value }
+    }
+}
+
+impl<K: Copy> KeyedOutput<K> for Output<K> {
+    fn key(&self) -> K {
+        self.key
+    }
+
+    fn si_value(&self) -> f64 {
+        self.si_value
+    }
+}
+
+/// Fluid keyed output.
+pub type FluidOutput = Output<FluidParam>;
+
+/// Fluid trivial keyed output.
+pub type FluidTrivialOutput = Output<FluidTrivialParam>;
+
+/// Humid air keyed output.
+pub type HumidAirOutput = Output<HumidAirParam>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[test]
+    fn new_returns_expected_key_and_si_value() {
+        let sut = FluidOutput::new(FluidParam::T, 293.15);
+        assert_eq!(sut.key, FluidParam::T);
+        assert_eq!(sut.si_value, 293.15);
+    }
+
+    #[rstest]
+    #[case(FluidOutput::new(FluidParam::P, 101325.0), "P", 101325.0)]
+    #[case(FluidOutput::new(FluidParam::DMass, 998.0), "Dmass", 998.0)]
+    fn keyed_output_for_fluid_output_returns_expected_values(
+        #[case] sut: FluidOutput,
+        #[case] expected_key: &str,
+        #[case] expected_si_value: f64,
+    ) {
+        assert_eq!(sut.key().as_ref(), expected_key);
+        assert_eq!(sut.si_value(), expected_si_value);
+    }
+
+    #[test]
+    fn keyed_output_for_fluid_trivial_output_returns_expected_values() {
+        let sut = FluidTrivialOutput::new(FluidTrivialParam::MolarMass, 0.018);
+        assert_eq!(sut.key(), FluidTrivialParam::MolarMass);
+        assert_eq!(sut.si_value(), 0.018);
+    }
+
+    #[test]
+    fn keyed_output_for_humid_air_output_returns_expected_values() {
+        let sut = HumidAirOutput::new(HumidAirParam::R, 0.5);
+        assert_eq!(sut.key(), HumidAirParam::R);
+        assert_eq!(sut.si_value(), 0.5);
+    }
+}
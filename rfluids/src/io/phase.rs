@@ -1,4 +1,7 @@
 use crate::io::try_from;
+use std::fmt::{Display, Formatter};
+#[cfg(test)]
+use strum_macros::EnumIter;
 use strum_macros::{AsRefStr, EnumString, FromRepr};
 
 /// Phase states of fluids and mixtures.
@@ -33,12 +36,23 @@ use strum_macros::{AsRefStr, EnumString, FromRepr};
 /// assert_eq!(Phase::try_from(5.0), Ok(Phase::Gas));
 /// ```
 ///
+/// Human-readable representation _(unlike [`as_ref`](AsRef::as_ref),
+/// which returns the CoolProp identifier)_:
+///
+/// ```
+/// use rfluids::io::Phase;
+///
+/// assert_eq!(Phase::TwoPhase.to_string(), "Two-phase");
+/// assert_eq!(Phase::TwoPhase.as_ref(), "phase_twophase");
+/// ```
+///
 /// # See also
 ///
 /// - [Imposing the phase (optional)](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#imposing-the-phase-optional)
 //noinspection SpellCheckingInspection
 #[derive(AsRefStr, EnumString, FromRepr, Debug, Copy, Clone, Eq, PartialEq)]
 #[strum(ascii_case_insensitive)]
+#[cfg_attr(test, derive(EnumIter))]
 #[repr(u8)]
 pub enum Phase {
     /// Liquid _([`P`](crate::io::FluidParam::P) <
@@ -120,6 +134,22 @@ pub enum Phase {
     NotImposed = 8,
 }
 
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Phase::Liquid => "Liquid",
+            Phase::Supercritical => "Supercritical",
+            Phase::SupercriticalGas => "Supercritical gas",
+            Phase::SupercriticalLiquid => "Supercritical liquid",
+            Phase::CriticalPoint => "Critical point",
+            Phase::Gas => "Gas",
+            Phase::TwoPhase => "Two-phase",
+            Phase::Unknown => "Unknown",
+            Phase::NotImposed => "Not imposed",
+        })
+    }
+}
+
 impl From<Phase> for u8 {
     fn from(value: Phase) -> Self {
         value as u8
@@ -142,6 +172,61 @@ impl TryFrom<f64> for Phase {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serialization {
+    use super::*;
+
+    fn serde_name(phase: &Phase) -> &'static str {
+        match phase {
+            Phase::Liquid => "liquid",
+            Phase::Supercritical => "supercritical",
+            Phase::SupercriticalGas => "supercritical_gas",
+            Phase::SupercriticalLiquid => "supercritical_liquid",
+            Phase::CriticalPoint => "critical_point",
+            Phase::Gas => "gas",
+            Phase::TwoPhase => "two_phase",
+            Phase::Unknown => "unknown",
+            Phase::NotImposed => "not_imposed",
+        }
+    }
+
+    serde_via_str!(Phase, "phase", serde_name);
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use strum::IntoEnumIterator;
+
+        #[test]
+        fn phase_round_trip_returns_same_phase() {
+            for phase in Phase::iter() {
+                let json = serde_json::to_string(&phase).unwrap();
+                assert_eq!(serde_json::from_str::<Phase>(&json).unwrap(), phase);
+            }
+        }
+
+        #[test]
+        fn phase_is_serialized_as_friendly_name() {
+            assert_eq!(serde_json::to_string(&Phase::Liquid).unwrap(), "\"liquid\"");
+            assert_eq!(
+                serde_json::to_string(&Phase::TwoPhase).unwrap(),
+                "\"two_phase\""
+            );
+        }
+
+        #[test]
+        fn phase_is_deserialized_from_any_alias() {
+            for alias in ["phase_twophase", "two_phase", "TwoPhase"] {
+                assert_eq!(
+                    serde_json::from_str::<Phase>(&format!("\"{alias}\"")).unwrap(),
+                    Phase::TwoPhase
+                );
+            }
+            assert!(serde_json::from_str::<Phase>("\"Hello, World!\"").is_err());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Phase::*;
@@ -164,6 +249,21 @@ mod tests {
         assert_eq!(phase.as_ref(), expected);
     }
 
+    #[rstest]
+    #[case(Liquid, "Liquid")]
+    #[case(Supercritical, "Supercritical")]
+    #[case(SupercriticalGas, "Supercritical gas")]
+    #[case(SupercriticalLiquid, "Supercritical liquid")]
+    #[case(CriticalPoint, "Critical point")]
+    #[case(Gas, "Gas")]
+    #[case(TwoPhase, "Two-phase")]
+    #[case(Unknown, "Unknown")]
+    #[case(NotImposed, "Not imposed")]
+    fn to_string_returns_expected_human_readable_str(#[case] phase: Phase, #[case] expected: &str) {
+        assert_eq!(phase.to_string(), expected);
+        assert_ne!(phase.to_string(), phase.as_ref());
+    }
+
     //noinspection SpellCheckingInspection
     #[rstest]
     #[case(vec!["phase_liquid", "liquid"], Liquid)]
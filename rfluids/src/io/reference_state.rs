@@ -0,0 +1,81 @@
+use strum_macros::{AsRefStr, EnumString};
+
+/// Reference states, which define the zero points of enthalpy and entropy.
+///
+/// # Examples
+///
+/// Conversion between [`&str`](str):
+///
+/// ```
+/// use std::str::FromStr;
+/// use rfluids::io::ReferenceState;
+///
+/// assert_eq!(ReferenceState::IIR.as_ref(), "IIR");
+/// assert_eq!(ReferenceState::from_str("IIR"), Ok(ReferenceState::IIR));
+/// assert_eq!(ReferenceState::try_from("iir"), Ok(ReferenceState::IIR));
+/// ```
+///
+/// # See also
+///
+/// - [Reference states](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#reference-states)
+//noinspection SpellCheckingInspection
+#[derive(AsRefStr, EnumString, Debug, Copy, Clone, Eq, PartialEq)]
+#[strum(ascii_case_insensitive)]
+pub enum ReferenceState {
+    /// Default reference state of the equation of state.
+    #[strum(to_string = "DEF", serialize = "Default")]
+    Default,
+
+    /// _h = 200 kJ/kg_, _s = 1 kJ/kg/K_ for saturated liquid at _0 °C_.
+    #[strum(to_string = "IIR")]
+    IIR,
+
+    /// _h = 0_, _s = 0_ for saturated liquid at _-40 °C_.
+    #[strum(to_string = "ASHRAE")]
+    ASHRAE,
+
+    /// _h = 0_, _s = 0_ for saturated liquid at _1 atm_.
+    #[strum(to_string = "NBP")]
+    NBP,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReferenceState::*;
+    use super::*;
+    use rstest::*;
+    use std::str::FromStr;
+
+    //noinspection SpellCheckingInspection
+    #[rstest]
+    #[case(Default, "DEF")]
+    #[case(IIR, "IIR")]
+    #[case(ASHRAE, "ASHRAE")]
+    #[case(NBP, "NBP")]
+    fn as_ref_returns_expected_str(#[case] reference: ReferenceState, #[case] expected: &str) {
+        assert_eq!(reference.as_ref(), expected);
+    }
+
+    //noinspection SpellCheckingInspection
+    #[rstest]
+    #[case(vec!["DEF", "def", "Default"], Default)]
+    #[case(vec!["IIR", "iir"], IIR)]
+    #[case(vec!["ASHRAE", "ashrae"], ASHRAE)]
+    #[case(vec!["NBP", "nbp"], NBP)]
+    fn from_valid_str_returns_ok(
+        #[case] valid_values: Vec<&str>,
+        #[case] expected: ReferenceState,
+    ) {
+        for s in valid_values {
+            assert_eq!(ReferenceState::from_str(s), Ok(expected));
+            assert_eq!(ReferenceState::try_from(s), Ok(expected));
+        }
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("Hello, World!")]
+    fn from_invalid_str_returns_err(#[case] invalid_value: &str) {
+        assert!(ReferenceState::from_str(invalid_value).is_err());
+    }
+}
@@ -29,8 +29,31 @@
 
 pub extern crate uom;
 
+#[cfg(feature = "serde")]
+macro_rules! serde_via_str {
+    ($type:ty) => {
+        serde_via_str!($type, "substance name", AsRef::<str>::as_ref);
+    };
+    ($type:ty, $what:literal, $to_str:expr) => {
+        impl serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str($to_str(self))
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let name = String::deserialize(deserializer)?;
+                <$type as std::str::FromStr>::from_str(&name)
+                    .map_err(|_| serde::de::Error::custom(format!("Unknown {} `{}`!", $what, name)))
+            }
+        }
+    };
+}
+
 pub mod error;
 pub mod fluid;
+pub mod humid_air;
 pub mod io;
 pub mod native;
 pub mod substance;
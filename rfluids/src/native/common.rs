@@ -1,3 +1,4 @@
+use core::cell::Cell;
 use core::ffi::{c_char, c_int, c_long};
 use std::ffi::CString;
 use std::sync::{LazyLock, Mutex};
@@ -12,14 +13,14 @@ pub(crate) static COOLPROP: LazyLock<Mutex<coolprop_sys::bindings::CoolProp>> =
 
 #[derive(Debug)]
 pub(crate) struct ErrorBuffer {
-    pub code: *mut c_long,
+    pub code: Cell<c_long>,
     pub message: MessageBuffer,
 }
 
 impl ErrorBuffer {
     pub fn blank() -> Self {
         Self {
-            code: &mut 0,
+            code: Cell::new(0),
             message: MessageBuffer::blank(),
         }
     }
@@ -28,7 +29,7 @@ impl ErrorBuffer {
 impl Default for ErrorBuffer {
     fn default() -> Self {
         Self {
-            code: &mut 0,
+            code: Cell::new(0),
             message: MessageBuffer::default(),
         }
     }
@@ -47,7 +48,7 @@ pub(crate) struct MessageBuffer {
 }
 
 impl MessageBuffer {
-    fn with_capacity(capacity: c_int) -> Self {
+    pub fn with_capacity(capacity: c_int) -> Self {
         Self {
             capacity,
             buffer: CString::new(" ".repeat(capacity as usize))
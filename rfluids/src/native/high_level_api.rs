@@ -1,5 +1,5 @@
 use crate::error::CoolPropError;
-use crate::native::common::{const_ptr_c_char, MessageBuffer, COOLPROP};
+use crate::native::common::{const_ptr_c_char, ErrorBuffer, MessageBuffer, COOLPROP};
 use core::ffi::c_char;
 use std::sync::MutexGuard;
 
@@ -21,7 +21,8 @@ impl CoolProp {
     ///   _(raw [`&str`](str) or [`FluidParam`](crate::io::FluidParam))_.
     /// - `input2_value` -- value of the second input property _(in SI units)_.
     /// - `fluid_name` -- name of the fluid _(raw [`&str`](str),
-    ///   [`Substance`](crate::substance::Substance) or its subset)_.
+    ///   [`Substance::name`](crate::substance::Substance::name)
+    ///   or any [`Substance`](crate::substance::Substance) subset)_.
     ///
     /// # Errors
     ///
@@ -177,7 +178,8 @@ impl CoolProp {
     /// - `output_key` -- key of the _trivial_ output
     ///   _(raw [`&str`](str) or [`FluidTrivialParam`](crate::io::FluidTrivialParam))_.
     /// - `fluid_name` -- name of the fluid _(raw [`&str`](str),
-    ///   [`Substance`](crate::substance::Substance) or its subset)_.
+    ///   [`Substance::name`](crate::substance::Substance::name)
+    ///   or any [`Substance`](crate::substance::Substance) subset)_.
     ///
     /// # Errors
     ///
@@ -225,41 +227,240 @@ impl CoolProp {
         Self::result(value, lock)
     }
 
+    /// Registers new fluids from the JSON equation of state definitions.
+    ///
+    /// Once registered, fluids are available _(by the names specified in their definitions)_
+    /// for all further calculations via the native API
+    /// _([`CoolProp`] and [`AbstractState`](crate::native::AbstractState))_.
+    ///
+    /// **NB.** Registered fluids can't be used via [`Fluid`](crate::fluid::Fluid),
+    /// since it's created from the [`Substance`](crate::substance::Substance),
+    /// which covers only the substances known at compile time.
+    ///
+    /// # Args
+    ///
+    /// - `backend_name` -- name of the backend _(e.g., `"HEOS"`)_.
+    /// - `json` -- JSON array of fluid definitions
+    ///   _(in the same format as CoolProp fluid files)_.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs _(e.g., malformed JSON or already registered fluid)_,
+    /// a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::native::CoolProp;
+    ///
+    /// let result = CoolProp::add_fluids_as_json("HEOS", "Hello, World!");
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Fluid files](https://github.com/CoolProp/CoolProp/tree/master/dev/fluids)
+    pub fn add_fluids_as_json(
+        backend_name: impl AsRef<str>,
+        json: impl AsRef<str>,
+    ) -> Result<(), CoolPropError> {
+        let error = ErrorBuffer::default();
+        unsafe {
+            COOLPROP.lock().unwrap().add_fluids_as_JSON(
+                const_ptr_c_char!(backend_name.as_ref().trim()),
+                const_ptr_c_char!(json.as_ref().trim()),
+                error.code.as_ptr(),
+                error.message.buffer,
+                error.message.capacity,
+            );
+        }
+        let message: String = error.into();
+        if message.trim().is_empty() {
+            Ok(())
+        } else {
+            Err(CoolPropError::CalculationFailed(message))
+        }
+    }
+
+    /// Sets the reference state _(zero points of enthalpy and entropy)_
+    /// for the specified fluid.
+    ///
+    /// The reference state is global for the fluid
+    /// and affects only instances created after this call.
+    ///
+    /// # Args
+    ///
+    /// - `fluid_name` -- name of the fluid _(raw [`&str`](str),
+    ///   [`Substance::name`](crate::substance::Substance::name)
+    ///   or any [`Substance`](crate::substance::Substance) subset)_.
+    /// - `reference_state` -- reference state
+    ///   _(raw [`&str`](str) or [`ReferenceState`](crate::io::ReferenceState))_.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::io::ReferenceState;
+    /// use rfluids::native::CoolProp;
+    ///
+    /// CoolProp::set_reference_state("R134a", ReferenceState::ASHRAE).unwrap();
+    /// let result = CoolProp::props_si("H", "T", 233.15, "Q", 0.0, "R134a").unwrap();
+    /// assert_relative_eq!(result, 0.0, epsilon = 1e-6);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Reference states](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#reference-states)
+    /// - [`ReferenceState`](crate::io::ReferenceState)
+    pub fn set_reference_state(
+        fluid_name: impl AsRef<str>,
+        reference_state: impl AsRef<str>,
+    ) -> Result<(), CoolPropError> {
+        let lock = COOLPROP.lock().unwrap();
+        let success = unsafe {
+            lock.set_reference_stateS(
+                const_ptr_c_char!(fluid_name.as_ref().trim()),
+                const_ptr_c_char!(reference_state.as_ref().trim()),
+            )
+        };
+        if success == 1 {
+            return Ok(());
+        }
+        let message = Self::get_error_message(lock);
+        Err(CoolPropError::CalculationFailed(
+            message.unwrap_or("Unknown error".into()),
+        ))
+    }
+
     fn result(
         value: f64,
         lock: MutexGuard<coolprop_sys::bindings::CoolProp>,
     ) -> Result<f64, CoolPropError> {
         if !value.is_finite() {
             let message = Self::get_error_message(lock);
-            return Err(CoolPropError(message.unwrap_or("Unknown error".into())));
+            return Err(CoolPropError::CalculationFailed(
+                message.unwrap_or("Unknown error".into()),
+            ));
         }
         Ok(value)
     }
 
     fn get_error_message(lock: MutexGuard<coolprop_sys::bindings::CoolProp>) -> Option<String> {
-        let message = MessageBuffer::default();
-        let _unused = unsafe {
-            lock.get_global_param_string(
-                const_ptr_c_char!("errstring"),
-                message.buffer,
-                message.capacity,
-            )
-        };
-        let result: String = message.into();
-        if result.trim().is_empty() {
-            None
-        } else {
-            Some(result)
+        global_param_string(&lock, "errstring")
+    }
+}
+
+/// Version of the linked CoolProp library _(e.g., `"6.6.0"`)_.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::native::coolprop_version;
+///
+/// assert!(!coolprop_version().is_empty());
+/// ```
+///
+/// # See also
+///
+/// - [`coolprop_gitrevision`]
+pub fn coolprop_version() -> String {
+    global_param_string(&COOLPROP.lock().unwrap(), "version").unwrap_or_default()
+}
+
+/// Git revision _(commit hash)_ of the linked CoolProp library.
+///
+/// # Examples
+///
+/// ```
+/// use rfluids::native::coolprop_gitrevision;
+///
+/// assert!(!coolprop_gitrevision().is_empty());
+/// ```
+///
+/// # See also
+///
+/// - [`coolprop_version`]
+pub fn coolprop_gitrevision() -> String {
+    global_param_string(&COOLPROP.lock().unwrap(), "gitrevision").unwrap_or_default()
+}
+
+fn global_param_string(
+    lock: &MutexGuard<coolprop_sys::bindings::CoolProp>,
+    name: &str,
+) -> Option<String> {
+    let message = MessageBuffer::default();
+    let _unused = unsafe {
+        lock.get_global_param_string(const_ptr_c_char!(name), message.buffer, message.capacity)
+    };
+    let result: String = message.into();
+    let result = result.trim();
+    if result.is_empty() {
+        None
+    } else {
+        Some(result.into())
+    }
+}
+
+/// Guard for tests that change the _process-global_ reference state.
+///
+/// Serializes such tests and resets the reference state
+/// of the fluid to the default one when dropped _(even if the test panics)_.
+#[cfg(test)]
+pub(crate) struct ReferenceStateGuard {
+    fluid_name: &'static str,
+    _lock: MutexGuard<'static, ()>,
+}
+
+#[cfg(test)]
+impl ReferenceStateGuard {
+    pub(crate) fn new(fluid_name: &'static str) -> Self {
+        static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+        Self {
+            fluid_name,
+            _lock: LOCK
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
         }
     }
 }
 
+#[cfg(test)]
+impl Drop for ReferenceStateGuard {
+    fn drop(&mut self) {
+        let _ = CoolProp::set_reference_state(self.fluid_name, "DEF");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use approx::assert_relative_eq;
     use rayon::prelude::*;
 
+    #[test]
+    fn coolprop_version_returns_semantic_version() {
+        let result = coolprop_version();
+        let parts = result.split('.').collect::<Vec<_>>();
+        assert_eq!(parts.len(), 3, "{result}");
+        assert!(
+            parts
+                .iter()
+                .all(|p| p.starts_with(|c: char| c.is_ascii_digit())),
+            "{result}"
+        );
+    }
+
+    #[test]
+    fn coolprop_gitrevision_returns_commit_hash() {
+        let result = coolprop_gitrevision();
+        assert_eq!(result.len(), 40);
+        assert!(result.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
     #[test]
     fn props_si_water_density_in_standard_conditions_returns_ok() {
         let result = CoolProp::props_si("D", "P", 101325.0, "T", 293.15, "Water");
@@ -328,6 +529,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add_fluids_as_json_valid_input_registers_new_fluid() {
+        let json = fluid_json("Argon")
+            .replace("\"NAME\": \"Argon\"", "\"NAME\": \"CustomArgon\"")
+            .replace("\"CAS\": \"7440-37-1\"", "\"CAS\": \"N/A\"");
+        let start = json.find("\"ALIASES\": [").unwrap();
+        let end = start + json[start..].find(']').unwrap();
+        let json = format!("{}\"ALIASES\": []{}", &json[..start], &json[end + 1..]);
+        let result = CoolProp::add_fluids_as_json("HEOS", json);
+        assert!(result.is_ok());
+        assert_relative_eq!(
+            CoolProp::props_si("D", "P", 101325.0, "T", 300.0, "CustomArgon").unwrap(),
+            CoolProp::props_si("D", "P", 101325.0, "T", 300.0, "Argon").unwrap()
+        );
+        let mut backend = crate::native::AbstractState::new("HEOS", "CustomArgon").unwrap();
+        backend
+            .update(crate::io::FluidInputPair::PT, 101325.0, 300.0)
+            .unwrap();
+        assert_relative_eq!(
+            backend.keyed_output(crate::io::FluidParam::DMass).unwrap(),
+            CoolProp::props_si("D", "P", 101325.0, "T", 300.0, "Argon").unwrap()
+        );
+    }
+
+    #[test]
+    fn add_fluids_as_json_already_registered_fluid_returns_err() {
+        let result = CoolProp::add_fluids_as_json("HEOS", fluid_json("Argon"));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("because it is already in library"));
+    }
+
+    #[test]
+    fn add_fluids_as_json_invalid_input_returns_err() {
+        let result = CoolProp::add_fluids_as_json("HEOS", "Hello, World!");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error: Unable to load JSON string"
+        );
+    }
+
+    fn fluid_json(fluid_name: &str) -> String {
+        let message = MessageBuffer::with_capacity(100_000);
+        unsafe {
+            COOLPROP.lock().unwrap().get_fluid_param_string(
+                const_ptr_c_char!(fluid_name),
+                const_ptr_c_char!("JSON"),
+                message.buffer,
+                message.capacity,
+            );
+        }
+        message.into()
+    }
+
+    #[test]
+    fn set_reference_state_valid_inputs_affects_further_calculations() {
+        let _guard = ReferenceStateGuard::new("R245fa");
+        CoolProp::set_reference_state("R245fa", "NBP").unwrap();
+        let result = CoolProp::props_si("H", "P", 101325.0, "Q", 0.0, "R245fa");
+        assert_relative_eq!(result.unwrap(), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn set_reference_state_invalid_inputs_returns_err() {
+        let result = CoolProp::set_reference_state("Hello, World!", "IIR");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "key [Hello, World!] was not found in string_to_index_map in JSONFluidLibrary"
+        );
+    }
+
     #[test]
     fn validate_result_valid_number_returns_ok() {
         let result = CoolProp::result(42.0, COOLPROP.lock().unwrap());
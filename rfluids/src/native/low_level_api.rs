@@ -1,11 +1,20 @@
 use crate::error::CoolPropError;
 use crate::native::common::{const_ptr_c_char, ErrorBuffer, COOLPROP};
-use core::ffi::{c_char, c_long};
+#[cfg(feature = "profiling")]
+use crate::native::profiling::{FfiProfiler, FfiStats};
+use core::ffi::{c_char, c_int, c_long};
 
 /// CoolProp thread safe low-level API.
 #[derive(Debug)]
 pub struct AbstractState {
     ptr: c_long,
+    backend_name: String,
+    fluid_names: String,
+    fractions: Option<Vec<f64>>,
+    binary_interactions: Vec<(usize, usize, String, f64)>,
+    phase: Option<String>,
+    #[cfg(feature = "profiling")]
+    profiler: FfiProfiler,
 }
 
 impl AbstractState {
@@ -17,7 +26,8 @@ impl AbstractState {
     ///   or [`BackendName::backend_name`](crate::substance::BackendName::backend_name))_.
     /// - `fluid_names` -- names of the fluids separated by the `&` symbol
     ///   or just a single fluid name _(raw [`&str`](str),
-    ///   [`Substance`](crate::substance::Substance) or its subset)_.
+    ///   [`Substance::name`](crate::substance::Substance::name)
+    ///   or any [`Substance`](crate::substance::Substance) subset)_.
     ///
     /// # Errors
     ///
@@ -63,17 +73,69 @@ impl AbstractState {
         backend_name: impl AsRef<str>,
         fluid_names: impl AsRef<str>,
     ) -> Result<AbstractState, CoolPropError> {
+        let (backend_name, fluid_names) =
+            (backend_name.as_ref().trim(), fluid_names.as_ref().trim());
         let error = ErrorBuffer::default();
         let ptr = unsafe {
             COOLPROP.lock().unwrap().AbstractState_factory(
-                const_ptr_c_char!(backend_name.as_ref().trim()),
-                const_ptr_c_char!(fluid_names.as_ref().trim()),
-                error.code,
+                const_ptr_c_char!(backend_name),
+                const_ptr_c_char!(fluid_names),
+                error.code.as_ptr(),
                 error.message.buffer,
                 error.message.capacity,
             )
         };
-        Self::result(Self { ptr }, error)
+        Self::result(
+            Self {
+                ptr,
+                backend_name: backend_name.into(),
+                fluid_names: fluid_names.into(),
+                fractions: None,
+                binary_interactions: Vec::new(),
+                phase: None,
+                #[cfg(feature = "profiling")]
+                profiler: FfiProfiler::default(),
+            },
+            error,
+        )
+    }
+
+    /// Creates and returns a new [`AbstractState`] instance
+    /// with the same backend and fluid names,
+    /// with the same fractions, binary interaction parameters
+    /// and specified phase state _(if any)_.
+    ///
+    /// **NB.** The thermodynamic state is not copied,
+    /// so the new instance should be updated separately.
+    ///
+    /// # Errors
+    ///
+    /// If the new instance can't be created, a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::io::{FluidInputPair, Phase};
+    /// use rfluids::native::AbstractState;
+    ///
+    /// let mut water = AbstractState::new("HEOS", "Water").unwrap();
+    /// water.specify_phase(Phase::Gas).unwrap();
+    /// let mut clone = water.try_clone().unwrap();
+    /// let result = clone.update(FluidInputPair::PT, 101325.0, 293.15);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn try_clone(&self) -> Result<AbstractState, CoolPropError> {
+        let mut clone = Self::new(&self.backend_name, &self.fluid_names)?;
+        if let Some(fractions) = &self.fractions {
+            clone.set_fractions(fractions)?;
+        }
+        for (i, j, parameter, value) in &self.binary_interactions {
+            clone.set_binary_interaction(*i, *j, parameter, *value)?;
+        }
+        if let Some(phase) = &self.phase {
+            clone.specify_phase(phase)?;
+        }
+        Ok(clone)
     }
 
     /// Set the fractions _(mole, mass or volume)_[^note].
@@ -114,17 +176,79 @@ impl AbstractState {
     /// ```
     pub fn set_fractions(&mut self, fractions: &[f64]) -> Result<(), CoolPropError> {
         let error = ErrorBuffer::default();
-        unsafe {
+        self.ffi(|| unsafe {
             COOLPROP.lock().unwrap().AbstractState_set_fractions(
                 self.ptr,
                 fractions.as_ptr(),
                 fractions.len() as c_long,
-                error.code,
+                error.code.as_ptr(),
                 error.message.buffer,
                 error.message.capacity,
             );
-        }
-        Self::result((), error)
+        });
+        Self::result((), error)?;
+        self.fractions = Some(fractions.to_vec());
+        Ok(())
+    }
+
+    /// Set the binary interaction parameter for the pair of mixture components.
+    ///
+    /// # Args
+    ///
+    /// - `i` -- index of the first component _(zero-based)_.
+    /// - `j` -- index of the second component _(zero-based)_.
+    /// - `parameter` -- name of the binary interaction parameter
+    ///   _(e.g., `"betaT"`, `"gammaT"`, `"betaV"`, `"gammaV"` or `"Fij"`
+    ///   for the `"HEOS"` backend, `"kij"` for cubic backends)_.
+    /// - `value` -- value of the binary interaction parameter.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs _(e.g., not a mixture, invalid indices
+    /// or unknown parameter)_, a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::native::AbstractState;
+    ///
+    /// let mut mixture = AbstractState::new("HEOS", "R32&R125").unwrap();
+    /// mixture.set_fractions(&[0.7, 0.3]).unwrap();
+    /// let result = mixture.set_binary_interaction(0, 1, "betaT", 1.0);
+    /// assert!(result.is_ok());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Binary interaction parameters](https://coolprop.github.io/CoolProp/fluid_properties/Mixtures.html#binary-pairs)
+    pub fn set_binary_interaction(
+        &mut self,
+        i: usize,
+        j: usize,
+        parameter: impl AsRef<str>,
+        value: f64,
+    ) -> Result<(), CoolPropError> {
+        let parameter = parameter.as_ref().trim();
+        let error = ErrorBuffer::default();
+        self.ffi(|| unsafe {
+            COOLPROP
+                .lock()
+                .unwrap()
+                .AbstractState_set_binary_interaction_double(
+                    self.ptr,
+                    i as c_long,
+                    j as c_long,
+                    const_ptr_c_char!(parameter),
+                    value,
+                    error.code.as_ptr(),
+                    error.message.buffer,
+                    error.message.capacity,
+                );
+        });
+        Self::result((), error)?;
+        self.binary_interactions
+            .push((i, j, parameter.into(), value));
+        Ok(())
     }
 
     /// Update the state of the fluid.
@@ -161,17 +285,17 @@ impl AbstractState {
         input2: f64,
     ) -> Result<(), CoolPropError> {
         let error = ErrorBuffer::default();
-        unsafe {
+        self.ffi(|| unsafe {
             COOLPROP.lock().unwrap().AbstractState_update(
                 self.ptr,
                 input_pair_key.into() as c_long,
                 input1,
                 input2,
-                error.code,
+                error.code.as_ptr(),
                 error.message.buffer,
                 error.message.capacity,
             );
-        }
+        });
         Self::result((), error)
     }
 
@@ -185,8 +309,10 @@ impl AbstractState {
     ///
     /// # Errors
     ///
-    /// For non-trivial outputs with undefined state or invalid inputs,
-    /// a [`CoolPropError`] is returned.
+    /// For invalid inputs, a [`CoolPropError::CalculationFailed`]
+    /// with the CoolProp error message is returned.
+    /// If CoolProp returns a non-finite result _(e.g., for non-trivial outputs
+    /// with undefined state)_, a [`CoolPropError::UnavailableOutput`] is returned.
     ///
     /// # Examples
     ///
@@ -246,18 +372,321 @@ impl AbstractState {
     pub fn keyed_output(&self, key: impl Into<u8>) -> Result<f64, CoolPropError> {
         let error = ErrorBuffer::default();
         let key = key.into();
-        let value = unsafe {
+        let value = self.ffi(|| unsafe {
             COOLPROP.lock().unwrap().AbstractState_keyed_output(
                 self.ptr,
                 key as c_long,
-                error.code,
+                error.code.as_ptr(),
                 error.message.buffer,
                 error.message.capacity,
             )
-        };
+        });
         Self::keyed_output_result(key, value, error)
     }
 
+    /// Get the phase state index of the current thermodynamic state
+    /// _(it can be converted to [`Phase`](crate::io::Phase))_.
+    ///
+    /// # Errors
+    ///
+    /// If the backend doesn't support phase calculation
+    /// _(e.g., `INCOMP`)_, a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::io::{FluidInputPair, Phase};
+    /// use rfluids::native::AbstractState;
+    ///
+    /// let mut water = AbstractState::new("HEOS", "Water").unwrap();
+    /// assert_eq!(Phase::try_from(water.phase().unwrap()), Ok(Phase::Unknown));
+    /// water.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+    /// assert_eq!(Phase::try_from(water.phase().unwrap()), Ok(Phase::Liquid));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Phase`](crate::io::Phase)
+    pub fn phase(&self) -> Result<u8, CoolPropError> {
+        let error = ErrorBuffer::default();
+        let value = self.ffi(|| unsafe {
+            COOLPROP.lock().unwrap().AbstractState_phase(
+                self.ptr,
+                error.code.as_ptr(),
+                error.message.buffer,
+                error.message.capacity,
+            )
+        });
+        Self::phase_result(value, error)
+    }
+
+    /// Get an output parameter value along with the phase state index
+    /// of the current thermodynamic state.
+    ///
+    /// Both values are read in a single call _(under one lock of the CoolProp library)_,
+    /// so they always correspond to the same thermodynamic state.
+    ///
+    /// # Args
+    ///
+    /// - `key` -- output parameter key
+    ///   _(raw [`u8`], [`FluidParam`](crate::io::FluidParam)
+    ///   or [`FluidTrivialParam`](crate::io::FluidTrivialParam))_.
+    ///
+    /// # Errors
+    ///
+    /// If the output is not available or the backend doesn't support phase calculation,
+    /// a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::io::{FluidInputPair, FluidParam, Phase};
+    /// use rfluids::native::AbstractState;
+    ///
+    /// let mut water = AbstractState::new("HEOS", "Water").unwrap();
+    /// water.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+    /// let (density, phase) = water.keyed_output_and_phase(FluidParam::DMass).unwrap();
+    /// assert_relative_eq!(density, 998.2071504679284, max_relative = 1e-9);
+    /// assert_eq!(Phase::try_from(phase), Ok(Phase::Liquid));
+    /// ```
+    pub fn keyed_output_and_phase(&self, key: impl Into<u8>) -> Result<(f64, u8), CoolPropError> {
+        let output_error = ErrorBuffer::default();
+        let phase_error = ErrorBuffer::default();
+        let key = key.into();
+        let (value, phase) = self.ffi(|| unsafe {
+            let coolprop = COOLPROP.lock().unwrap();
+            (
+                coolprop.AbstractState_keyed_output(
+                    self.ptr,
+                    key as c_long,
+                    output_error.code.as_ptr(),
+                    output_error.message.buffer,
+                    output_error.message.capacity,
+                ),
+                coolprop.AbstractState_phase(
+                    self.ptr,
+                    phase_error.code.as_ptr(),
+                    phase_error.message.buffer,
+                    phase_error.message.capacity,
+                ),
+            )
+        });
+        Ok((
+            Self::keyed_output_result(key, value, output_error)?,
+            Self::phase_result(phase, phase_error)?,
+        ))
+    }
+
+    /// Get a first partial derivative value.
+    ///
+    /// # Args
+    ///
+    /// - `of` -- key of the parameter being differentiated
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    /// - `wrt` -- key of the parameter with respect to which the derivative is taken
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    /// - `constant` -- key of the parameter held constant
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    ///
+    /// # Errors
+    ///
+    /// For undefined state or invalid inputs, a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// To calculate the derivative of mass specific enthalpy
+    /// with respect to temperature at constant pressure _(i.e., specific heat)_
+    /// of water at _1 atm_ and _20 °C_:
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::io::{FluidInputPair, FluidParam};
+    /// use rfluids::native::AbstractState;
+    ///
+    /// let mut water = AbstractState::new("HEOS", "Water").unwrap();
+    /// water.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+    /// let result = water
+    ///     .first_partial_deriv(FluidParam::HMass, FluidParam::T, FluidParam::P)
+    ///     .unwrap();
+    /// assert_relative_eq!(result, water.keyed_output(FluidParam::CpMass).unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Partial derivatives](https://coolprop.github.io/CoolProp/coolprop/LowLevelAPI.html#partial-derivatives)
+    /// - [`FluidParam`](crate::io::FluidParam)
+    pub fn first_partial_deriv(
+        &self,
+        of: impl Into<u8>,
+        wrt: impl Into<u8>,
+        constant: impl Into<u8>,
+    ) -> Result<f64, CoolPropError> {
+        let error = ErrorBuffer::default();
+        let value = self.ffi(|| unsafe {
+            COOLPROP.lock().unwrap().AbstractState_first_partial_deriv(
+                self.ptr,
+                of.into() as c_long,
+                wrt.into() as c_long,
+                constant.into() as c_long,
+                error.code.as_ptr(),
+                error.message.buffer,
+                error.message.capacity,
+            )
+        });
+        Self::result((), error)?;
+        if !value.is_finite() {
+            return Err(CoolPropError::UnavailableOutput(
+                "Unable to get the partial derivative due to invalid or undefined state!".into(),
+            ));
+        }
+        Ok(value)
+    }
+
+    /// Get a second partial derivative value.
+    ///
+    /// # Args
+    ///
+    /// - `of` -- key of the parameter being differentiated
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    /// - `wrt1` -- key of the parameter with respect to which
+    ///   the first derivative is taken
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    /// - `constant1` -- key of the parameter held constant in the first derivative
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    /// - `wrt2` -- key of the parameter with respect to which
+    ///   the second derivative is taken
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    /// - `constant2` -- key of the parameter held constant in the second derivative
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    ///
+    /// # Errors
+    ///
+    /// For undefined state or invalid inputs, a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// To calculate the second derivative of mass specific enthalpy
+    /// with respect to temperature at constant pressure
+    /// _(i.e., derivative of specific heat with respect to temperature)_
+    /// of water at _1 atm_ and _20 °C_:
+    ///
+    /// ```
+    /// use rfluids::io::{FluidInputPair, FluidParam};
+    /// use rfluids::native::AbstractState;
+    ///
+    /// let mut water = AbstractState::new("HEOS", "Water").unwrap();
+    /// water.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+    /// let result = water
+    ///     .second_partial_deriv(
+    ///         FluidParam::HMass,
+    ///         FluidParam::T,
+    ///         FluidParam::P,
+    ///         FluidParam::T,
+    ///         FluidParam::P,
+    ///     )
+    ///     .unwrap();
+    /// assert!(result < 0.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Partial derivatives](https://coolprop.github.io/CoolProp/coolprop/LowLevelAPI.html#partial-derivatives)
+    /// - [`FluidParam`](crate::io::FluidParam)
+    pub fn second_partial_deriv(
+        &self,
+        of: impl Into<u8>,
+        wrt1: impl Into<u8>,
+        constant1: impl Into<u8>,
+        wrt2: impl Into<u8>,
+        constant2: impl Into<u8>,
+    ) -> Result<f64, CoolPropError> {
+        let error = ErrorBuffer::default();
+        let value = self.ffi(|| unsafe {
+            COOLPROP.lock().unwrap().AbstractState_second_partial_deriv(
+                self.ptr,
+                of.into() as c_long,
+                wrt1.into() as c_long,
+                constant1.into() as c_long,
+                wrt2.into() as c_long,
+                constant2.into() as c_long,
+                error.code.as_ptr(),
+                error.message.buffer,
+                error.message.capacity,
+            )
+        });
+        Self::result((), error)?;
+        if !value.is_finite() {
+            return Err(CoolPropError::UnavailableOutput(
+                "Unable to get the partial derivative due to invalid or undefined state!".into(),
+            ));
+        }
+        Ok(value)
+    }
+
+    /// Calculate the first derivative along the saturation curve.
+    ///
+    /// # Args
+    ///
+    /// - `of` -- key of the parameter being differentiated
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    /// - `wrt` -- key of the parameter with respect to which the derivative is taken
+    ///   _(raw [`u8`] or [`FluidParam`](crate::io::FluidParam))_.
+    ///
+    /// # Errors
+    ///
+    /// For undefined state or invalid inputs, a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// To calculate the derivative of saturation pressure
+    /// with respect to saturation temperature _(i.e., Clausius-Clapeyron slope)_
+    /// of water at _100 °C_:
+    ///
+    /// ```
+    /// use rfluids::io::{FluidInputPair, FluidParam};
+    /// use rfluids::native::AbstractState;
+    ///
+    /// let mut water = AbstractState::new("HEOS", "Water").unwrap();
+    /// water.update(FluidInputPair::QT, 0.0, 373.15).unwrap();
+    /// let result = water
+    ///     .first_saturation_deriv(FluidParam::P, FluidParam::T)
+    ///     .unwrap();
+    /// assert!(result > 0.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Partial derivatives](https://coolprop.github.io/CoolProp/coolprop/LowLevelAPI.html#partial-derivatives)
+    /// - [`FluidParam`](crate::io::FluidParam)
+    pub fn first_saturation_deriv(
+        &self,
+        of: impl Into<u8>,
+        wrt: impl Into<u8>,
+    ) -> Result<f64, CoolPropError> {
+        let error = ErrorBuffer::default();
+        let value = self.ffi(|| unsafe {
+            COOLPROP
+                .lock()
+                .unwrap()
+                .AbstractState_first_saturation_deriv(
+                    self.ptr,
+                    of.into() as c_long,
+                    wrt.into() as c_long,
+                    error.code.as_ptr(),
+                    error.message.buffer,
+                    error.message.capacity,
+                )
+        });
+        Self::result((), error)?;
+        if !value.is_finite() {
+            return Err(CoolPropError::UnavailableOutput(
+                "Unable to get the saturation derivative due to invalid or undefined state!".into(),
+            ));
+        }
+        Ok(value)
+    }
+
     /// Specify the phase state for all further calculations.
     ///
     /// # Args
@@ -290,16 +719,18 @@ impl AbstractState {
     /// - [`Phase`](crate::io::Phase)
     pub fn specify_phase(&mut self, phase: impl AsRef<str>) -> Result<(), CoolPropError> {
         let error = ErrorBuffer::default();
-        unsafe {
+        self.ffi(|| unsafe {
             COOLPROP.lock().unwrap().AbstractState_specify_phase(
                 self.ptr,
                 const_ptr_c_char!(phase.as_ref()),
-                error.code,
+                error.code.as_ptr(),
                 error.message.buffer,
                 error.message.capacity,
             );
-        }
-        Self::result((), error)
+        });
+        Self::result((), error)?;
+        self.phase = Some(phase.as_ref().into());
+        Ok(())
     }
 
     /// Unspecify the phase state and go back to calculating it based on the inputs.
@@ -324,14 +755,49 @@ impl AbstractState {
     /// - [Imposing the phase (optional)](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#imposing-the-phase-optional)
     pub fn unspecify_phase(&mut self) {
         let error = ErrorBuffer::blank();
-        unsafe {
+        self.ffi(|| unsafe {
             COOLPROP.lock().unwrap().AbstractState_unspecify_phase(
                 self.ptr,
-                error.code,
+                error.code.as_ptr(),
                 error.message.buffer,
                 error.message.capacity,
             );
-        }
+        });
+        self.phase = None;
+    }
+
+    /// Cumulative statistics of the CoolProp native calls
+    /// made through this instance _(excluding its creation and disposal)_.
+    ///
+    /// Available only with the `profiling` feature enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::io::{FluidInputPair, FluidParam};
+    /// use rfluids::native::AbstractState;
+    ///
+    /// let mut water = AbstractState::new("HEOS", "Water").unwrap();
+    /// water.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+    /// water.keyed_output(FluidParam::DMass).unwrap();
+    /// let stats = water.stats();
+    /// assert_eq!(stats.calls, 2);
+    /// assert!(!stats.elapsed.is_zero());
+    /// ```
+    #[cfg(feature = "profiling")]
+    pub fn stats(&self) -> FfiStats {
+        self.profiler.stats()
+    }
+
+    #[cfg(feature = "profiling")]
+    fn ffi<T>(&self, call: impl FnOnce() -> T) -> T {
+        self.profiler.measure(call)
+    }
+
+    #[cfg(not(feature = "profiling"))]
+    #[inline(always)]
+    fn ffi<T>(&self, call: impl FnOnce() -> T) -> T {
+        call()
     }
 
     fn result<T>(value: T, error: ErrorBuffer) -> Result<T, CoolPropError> {
@@ -339,14 +805,21 @@ impl AbstractState {
         if error_message.trim().is_empty() {
             Ok(value)
         } else {
-            Err(CoolPropError(error_message))
+            Err(CoolPropError::CalculationFailed(error_message))
         }
     }
 
+    fn phase_result(value: c_int, error: ErrorBuffer) -> Result<u8, CoolPropError> {
+        let value = Self::result(value, error)?;
+        u8::try_from(value).map_err(|_| {
+            CoolPropError::UnavailableOutput(format!("Unable to get the phase state '{}'!", value))
+        })
+    }
+
     fn keyed_output_result(key: u8, value: f64, error: ErrorBuffer) -> Result<f64, CoolPropError> {
         Self::result((), error)?;
         if !value.is_finite() {
-            return Err(CoolPropError(format!(
+            return Err(CoolPropError::UnavailableOutput(format!(
                 "Unable to get the output with key '{}' due to invalid or undefined state!",
                 key
             )));
@@ -361,7 +834,7 @@ impl Drop for AbstractState {
         unsafe {
             COOLPROP.lock().unwrap().AbstractState_free(
                 self.ptr,
-                error.code,
+                error.code.as_ptr(),
                 error.message.buffer,
                 error.message.capacity,
             );
@@ -427,6 +900,33 @@ mod tests {
         assert_eq!(result.unwrap_err().to_string(), expected_message);
     }
 
+    #[test]
+    fn try_clone_copies_fractions_and_phase() {
+        let mut sut = AbstractState::new("HEOS", "Water&Ethanol").unwrap();
+        sut.set_fractions(&[0.6, 0.4]).unwrap();
+        sut.specify_phase(Phase::Liquid).unwrap();
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        let mut result = sut.try_clone().unwrap();
+        assert_ne!(result.ptr, sut.ptr);
+        assert_eq!(result.fractions, Some(vec![0.6, 0.4]));
+        assert_eq!(result.phase, Some(Phase::Liquid.as_ref().into()));
+        result.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        assert_relative_eq!(
+            result.keyed_output(FluidParam::DMass).unwrap(),
+            sut.keyed_output(FluidParam::DMass).unwrap()
+        );
+    }
+
+    #[test]
+    fn try_clone_after_unspecify_phase_does_not_impose_phase() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        sut.specify_phase(Phase::Gas).unwrap();
+        sut.unspecify_phase();
+        let mut result = sut.try_clone().unwrap();
+        assert!(result.phase.is_none());
+        assert!(result.update(FluidInputPair::PT, 101325.0, 293.15).is_ok());
+    }
+
     #[test]
     fn set_fractions_valid_inputs_returns_ok() {
         let mut sut = AbstractState::new("HEOS", "Water&Ethanol").unwrap();
@@ -445,6 +945,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_binary_interaction_valid_inputs_changes_state() {
+        let mut sut = AbstractState::new("HEOS", "R32&R125").unwrap();
+        sut.set_fractions(&[0.7, 0.3]).unwrap();
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        let density = sut.keyed_output(FluidParam::DMass).unwrap();
+        sut.set_binary_interaction(0, 1, "betaT", 1.1).unwrap();
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        assert_ne!(sut.keyed_output(FluidParam::DMass).unwrap(), density);
+    }
+
+    #[rstest]
+    #[case("Water", 0, 1, "betaT")]
+    #[case("R32&R125", 0, 5, "betaT")]
+    #[case("R32&R125", 0, 1, "Hello, World!")]
+    fn set_binary_interaction_invalid_inputs_returns_err(
+        #[case] fluid_names: &str,
+        #[case] i: usize,
+        #[case] j: usize,
+        #[case] parameter: &str,
+    ) {
+        let mut sut = AbstractState::new("HEOS", fluid_names).unwrap();
+        let result = sut.set_binary_interaction(i, j, parameter, 1.0);
+        assert!(matches!(result, Err(CoolPropError::CalculationFailed(_))));
+        assert!(sut.binary_interactions.is_empty());
+    }
+
+    #[test]
+    fn try_clone_copies_binary_interactions() {
+        let mut sut = AbstractState::new("HEOS", "R32&R125").unwrap();
+        sut.set_fractions(&[0.7, 0.3]).unwrap();
+        sut.set_binary_interaction(0, 1, "gammaT", 1.05).unwrap();
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        let mut result = sut.try_clone().unwrap();
+        assert_eq!(result.binary_interactions, sut.binary_interactions);
+        result.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        assert_relative_eq!(
+            result.keyed_output(FluidParam::DMass).unwrap(),
+            sut.keyed_output(FluidParam::DMass).unwrap()
+        );
+    }
+
     #[test]
     fn update_valid_inputs_returns_ok() {
         let mut sut = AbstractState::new("HEOS", "Water").unwrap();
@@ -474,6 +1016,7 @@ mod tests {
     fn keyed_output_invalid_input_returns_err() {
         let sut = AbstractState::new("HEOS", "Water").unwrap();
         let result = sut.keyed_output(255);
+        assert!(matches!(result, Err(CoolPropError::CalculationFailed(_))));
         assert_eq!(
             result.unwrap_err().to_string(),
             "Error: Unable to match the key [255] in get_parameter_information for info [short]"
@@ -484,12 +1027,167 @@ mod tests {
     fn keyed_output_non_trivial_with_not_defined_state_returns_err() {
         let sut = AbstractState::new("HEOS", "Water").unwrap();
         let result = sut.keyed_output(FluidParam::DMass);
+        assert!(matches!(result, Err(CoolPropError::UnavailableOutput(_))));
         assert_eq!(
             result.unwrap_err().to_string(),
             "Unable to get the output with key '36' due to invalid or undefined state!"
         );
     }
 
+    #[test]
+    fn phase_valid_state_returns_ok() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        sut.update(FluidInputPair::PQ, 101325.0, 0.5).unwrap();
+        assert_eq!(sut.phase(), Ok(Phase::TwoPhase as u8));
+    }
+
+    #[test]
+    fn phase_with_not_defined_state_returns_unknown() {
+        let sut = AbstractState::new("HEOS", "Water").unwrap();
+        assert_eq!(sut.phase(), Ok(Phase::Unknown as u8));
+    }
+
+    #[test]
+    fn phase_unsupported_backend_returns_err() {
+        let mut sut = AbstractState::new("INCOMP", "MPG").unwrap();
+        sut.set_fractions(&[0.6]).unwrap();
+        sut.update(FluidInputPair::PT, 100e3, 253.15).unwrap();
+        assert!(matches!(
+            sut.phase(),
+            Err(CoolPropError::CalculationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn keyed_output_and_phase_valid_state_returns_same_as_separate_calls() {
+        let mut sut = AbstractState::new("HEOS", "R32&R125").unwrap();
+        sut.set_fractions(&[0.7, 0.3]).unwrap();
+        sut.update(FluidInputPair::PQ, 500e3, 0.5).unwrap();
+        assert_eq!(
+            sut.keyed_output_and_phase(FluidParam::DMass),
+            Ok((
+                sut.keyed_output(FluidParam::DMass).unwrap(),
+                Phase::TwoPhase as u8
+            ))
+        );
+    }
+
+    #[test]
+    fn keyed_output_and_phase_unsupported_backend_returns_err() {
+        let mut sut = AbstractState::new("INCOMP", "MPG").unwrap();
+        sut.set_fractions(&[0.6]).unwrap();
+        sut.update(FluidInputPair::PT, 100e3, 253.15).unwrap();
+        assert!(sut.keyed_output(FluidParam::DMass).is_ok());
+        assert!(matches!(
+            sut.keyed_output_and_phase(FluidParam::DMass),
+            Err(CoolPropError::CalculationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn first_partial_deriv_valid_inputs_returns_ok() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        let result = sut.first_partial_deriv(FluidParam::HMass, FluidParam::T, FluidParam::P);
+        assert_relative_eq!(
+            result.unwrap(),
+            sut.keyed_output(FluidParam::CpMass).unwrap()
+        );
+    }
+
+    #[test]
+    fn first_partial_deriv_invalid_inputs_returns_err() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        let result = sut.first_partial_deriv(FluidParam::Phase, FluidParam::T, FluidParam::P);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Error: input to get_dT_drho[Phase] is invalid"
+        );
+    }
+
+    #[test]
+    fn first_partial_deriv_with_not_defined_state_returns_err() {
+        let sut = AbstractState::new("HEOS", "Water").unwrap();
+        let result = sut.first_partial_deriv(FluidParam::HMass, FluidParam::T, FluidParam::P);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn second_partial_deriv_valid_inputs_returns_ok() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        let mut specific_heat = |temperature| {
+            sut.update(FluidInputPair::PT, 101325.0, temperature)
+                .unwrap();
+            sut.keyed_output(FluidParam::CpMass).unwrap()
+        };
+        let expected = (specific_heat(293.16) - specific_heat(293.14)) / 0.02;
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        let result = sut.second_partial_deriv(
+            FluidParam::HMass,
+            FluidParam::T,
+            FluidParam::P,
+            FluidParam::T,
+            FluidParam::P,
+        );
+        assert_relative_eq!(result.unwrap(), expected, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn second_partial_deriv_invalid_inputs_returns_err() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        let result = sut.second_partial_deriv(
+            FluidParam::HMass,
+            FluidParam::P,
+            FluidParam::P,
+            FluidParam::T,
+            FluidParam::P,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn second_partial_deriv_with_not_defined_state_returns_err() {
+        let sut = AbstractState::new("HEOS", "Water").unwrap();
+        let result = sut.second_partial_deriv(
+            FluidParam::HMass,
+            FluidParam::T,
+            FluidParam::P,
+            FluidParam::T,
+            FluidParam::P,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn first_saturation_deriv_valid_inputs_returns_ok() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        sut.update(FluidInputPair::QT, 0.0, 373.15).unwrap();
+        let result = sut.first_saturation_deriv(FluidParam::P, FluidParam::T);
+        assert_relative_eq!(
+            result.unwrap(),
+            1.0 / sut
+                .first_saturation_deriv(FluidParam::T, FluidParam::P)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn first_saturation_deriv_invalid_inputs_returns_err() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        sut.update(FluidInputPair::QT, 0.0, 373.15).unwrap();
+        let result = sut.first_saturation_deriv(FluidParam::Q, FluidParam::T);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn first_saturation_deriv_with_not_defined_state_returns_err() {
+        let sut = AbstractState::new("HEOS", "Water").unwrap();
+        let result = sut.first_saturation_deriv(FluidParam::P, FluidParam::T);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn specify_phase_valid_input_specifies_phase_for_all_further_calculations() {
         let mut sut = AbstractState::new("HEOS", "Water").unwrap();
@@ -522,4 +1220,31 @@ mod tests {
         result = sut.update(FluidInputPair::PT, 101325.0, 293.15);
         assert!(result.is_ok());
     }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn stats_counts_calls_made_through_instance() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        assert_eq!(sut.stats(), FfiStats::default());
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        let _ = sut.keyed_output(FluidParam::DMass);
+        let _ = sut.keyed_output(FluidParam::Phase);
+        let _ = sut.update(FluidInputPair::PT, -1.0, 293.15);
+        let result = sut.stats();
+        assert_eq!(result.calls, 4);
+        assert!(!result.elapsed.is_zero());
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn stats_of_clone_are_independent() {
+        let mut sut = AbstractState::new("HEOS", "Water").unwrap();
+        sut.specify_phase(Phase::Liquid).unwrap();
+        let clone = sut.try_clone().unwrap();
+        assert_eq!(sut.stats().calls, 1);
+        assert_eq!(clone.stats().calls, 1);
+        sut.update(FluidInputPair::PT, 101325.0, 293.15).unwrap();
+        assert_eq!(sut.stats().calls, 2);
+        assert_eq!(clone.stats().calls, 1);
+    }
 }
@@ -1,8 +1,14 @@
 //! Implementation of the CoolProp native API.
 
-pub use high_level_api::CoolProp;
+#[cfg(test)]
+pub(crate) use high_level_api::ReferenceStateGuard;
+pub use high_level_api::{coolprop_gitrevision, coolprop_version, CoolProp};
 pub use low_level_api::AbstractState;
+#[cfg(feature = "profiling")]
+pub use profiling::FfiStats;
 
 mod common;
 mod high_level_api;
 mod low_level_api;
+#[cfg(feature = "profiling")]
+mod profiling;
@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Cumulative statistics of the CoolProp native calls
+/// _(see [`AbstractState::stats`](crate::native::AbstractState::stats))_.
+///
+/// The elapsed time includes waiting for the CoolProp lock,
+/// which is shared by all threads.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct FfiStats {
+    /// Number of calls.
+    pub calls: u64,
+    /// Total elapsed time of the calls.
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct FfiProfiler {
+    calls: AtomicU64,
+    nanos: AtomicU64,
+}
+
+impl FfiProfiler {
+    pub fn measure<T>(&self, call: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let value = call();
+        let nanos = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.nanos.fetch_add(nanos, Ordering::Relaxed);
+        value
+    }
+
+    pub fn stats(&self) -> FfiStats {
+        FfiStats {
+            calls: self.calls.load(Ordering::Relaxed),
+            elapsed: Duration::from_nanos(self.nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
@@ -291,10 +291,10 @@ impl BinaryMix {
     /// use rfluids::uom::si::f64::Ratio;
     /// use rfluids::uom::si::ratio::percent;
     ///
-    /// assert!(BinaryMix::try_from(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).is_ok());
-    /// assert!(BinaryMix::try_from(BinaryMixKind::MPG, Ratio::new::<percent>(100.0)).is_err());
+    /// assert!(BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).is_ok());
+    /// assert!(BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(100.0)).is_err());
     /// ```
-    pub fn try_from(kind: BinaryMixKind, fraction: Ratio) -> Result<Self, BinaryMixError> {
+    pub fn try_new(kind: BinaryMixKind, fraction: Ratio) -> Result<Self, BinaryMixError> {
         if !(kind.min_fraction()..=kind.max_fraction()).contains(&fraction) {
             return Err(BinaryMixError::InvalidFraction {
                 specified: fraction,
@@ -320,11 +320,11 @@ impl BinaryMix {
     /// use rfluids::uom::si::f64::Ratio;
     /// use rfluids::uom::si::ratio::percent;
     ///
-    /// assert!(BinaryMix::try_from(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).is_ok());
-    /// assert!(BinaryMix::try_from(BinaryMixKind::MPG, Ratio::new::<percent>(100.0)).is_err());
+    /// assert!(BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).is_ok());
+    /// assert!(BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(100.0)).is_err());
     /// ```
     pub fn with(&self, other_fraction: Ratio) -> Result<Self, BinaryMixError> {
-        Self::try_from(self.kind, other_fraction)
+        Self::try_new(self.kind, other_fraction)
     }
 }
 
@@ -538,13 +538,13 @@ mod tests {
         #[test]
         fn try_new_with_valid_fraction_returns_ok() {
             for kind in BinaryMixKind::iter() {
-                assert!(BinaryMix::try_from(kind, kind.min_fraction()).is_ok());
-                assert!(BinaryMix::try_from(
+                assert!(BinaryMix::try_new(kind, kind.min_fraction()).is_ok());
+                assert!(BinaryMix::try_new(
                     kind,
                     0.5 * (kind.min_fraction() + kind.max_fraction())
                 )
                 .is_ok());
-                assert!(BinaryMix::try_from(kind, kind.max_fraction()).is_ok());
+                assert!(BinaryMix::try_new(kind, kind.max_fraction()).is_ok());
             }
         }
 
@@ -553,7 +553,7 @@ mod tests {
             let delta = Ratio::new::<part_per_billion>(1.0);
             for kind in BinaryMixKind::iter() {
                 assert_eq!(
-                    BinaryMix::try_from(kind, kind.min_fraction() - delta).unwrap_err(),
+                    BinaryMix::try_new(kind, kind.min_fraction() - delta).unwrap_err(),
                     BinaryMixError::InvalidFraction {
                         specified: kind.min_fraction() - delta,
                         min: kind.min_fraction(),
@@ -561,7 +561,7 @@ mod tests {
                     }
                 );
                 assert_eq!(
-                    BinaryMix::try_from(kind, kind.max_fraction() + delta).unwrap_err(),
+                    BinaryMix::try_new(kind, kind.max_fraction() + delta).unwrap_err(),
                     BinaryMixError::InvalidFraction {
                         specified: kind.max_fraction() + delta,
                         min: kind.min_fraction(),
@@ -573,7 +573,7 @@ mod tests {
 
         #[test]
         fn with_other_fraction_returns_binary_mix_with_same_kind_and_other_fraction() {
-            let sut = BinaryMix::try_from(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap();
+            let sut = BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap();
             let other_fraction = Ratio::new::<percent>(20.0);
             let sut_with_other_fraction = sut.with(other_fraction).unwrap();
             assert_eq!(sut_with_other_fraction.kind, sut.kind);
@@ -1,15 +1,29 @@
-use crate::error::CustomMixError;
-use crate::io::FluidTrivialParam::MolarMass;
+use crate::error::{CoolPropError, CustomMixError};
+use crate::io::FluidTrivialParam;
 use crate::native::AbstractState;
 use crate::substance::{BackendName, Pure, Refrigerant, RefrigerantCategory};
-use crate::uom::si::f64::Ratio;
+use crate::uom::si::f64::{MolarMass, Ratio};
+use crate::uom::si::molar_mass::kilogram_per_mole;
 use crate::uom::si::ratio::ratio;
 use crate::uom::ConstZero;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+/// Molar masses of the refrigerant components obtained from CoolProp _(in SI units)_,
+/// cached to avoid repeated backend creation.
+static MOLAR_MASSES: LazyLock<Mutex<HashMap<Refrigerant, f64>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
 /// CoolProp custom mixture
 /// _(only pure substances and pure refrigerants are supported)_.
 ///
+/// **NB.** It implements [`Eq`] and [`Hash`], so it can be used as a [`HashMap`] key.
+/// Equality is exact _(same kind, same components and bitwise equal fractions)_,
+/// while fractions are hashed rounded to 9 decimal places.
+/// So mixtures with fractions that differ only by floating-point noise
+/// _(e.g., obtained via different conversions)_ are different keys.
+///
 /// # See also
 ///
 /// - [Custom mixtures](https://coolprop.github.io/CoolProp/fluid_properties/Mixtures.html)
@@ -25,6 +39,8 @@ pub enum CustomMix {
 }
 
 impl CustomMix {
+    const FRACTIONS_SUM_TOLERANCE: f64 = 1e-6;
+
     /// Creates and returns a new [`CustomMix::MoleBased`] instance.
     ///
     /// # Args
@@ -58,7 +74,43 @@ impl CustomMix {
     pub fn mole_based(
         components: HashMap<CustomMixComponent, Ratio>,
     ) -> Result<Self, CustomMixError> {
-        Self::validate(&components)?;
+        Self::mole_based_with_tolerance(components, Self::FRACTIONS_SUM_TOLERANCE)
+    }
+
+    /// Creates and returns a new [`CustomMix::MoleBased`] instance
+    /// with the custom tolerance of the fractions sum.
+    ///
+    /// # Args
+    ///
+    /// - `components` -- hash map of components and their _mole_ fractions.
+    /// - `tolerance` -- maximum absolute deviation of the fractions sum from `1.0`
+    ///   _(must be positive and finite; the default one is `1e-6`)_.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`CustomMixError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{CustomMix, Pure};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let components = HashMap::from([
+    ///     (Pure::Methane.into(), Ratio::new::<percent>(33.3)),
+    ///     (Pure::Ethane.into(), Ratio::new::<percent>(33.3)),
+    ///     (Pure::nPropane.into(), Ratio::new::<percent>(33.3)),
+    /// ]);
+    /// assert!(CustomMix::mole_based(components.clone()).is_err());
+    /// assert!(CustomMix::mole_based_with_tolerance(components, 1e-2).is_ok());
+    /// ```
+    pub fn mole_based_with_tolerance(
+        components: HashMap<CustomMixComponent, Ratio>,
+        tolerance: f64,
+    ) -> Result<Self, CustomMixError> {
+        Self::validate(&components, tolerance)?;
         Ok(Self::MoleBased(components))
     }
 
@@ -95,13 +147,133 @@ impl CustomMix {
     pub fn mass_based(
         components: HashMap<CustomMixComponent, Ratio>,
     ) -> Result<Self, CustomMixError> {
-        Self::validate(&components)?;
+        Self::mass_based_with_tolerance(components, Self::FRACTIONS_SUM_TOLERANCE)
+    }
+
+    /// Creates and returns a new [`CustomMix::MassBased`] instance
+    /// with the custom tolerance of the fractions sum.
+    ///
+    /// # Args
+    ///
+    /// - `components` -- hash map of components and their _mass_ fractions.
+    /// - `tolerance` -- maximum absolute deviation of the fractions sum from `1.0`
+    ///   _(must be positive and finite; the default one is `1e-6`)_.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`CustomMixError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{CustomMix, Pure};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let components = HashMap::from([
+    ///     (Pure::Water.into(), Ratio::new::<percent>(66.7)),
+    ///     (Pure::Ethanol.into(), Ratio::new::<percent>(33.4)),
+    /// ]);
+    /// assert!(CustomMix::mass_based(components.clone()).is_err());
+    /// assert!(CustomMix::mass_based_with_tolerance(components, 2e-3).is_ok());
+    /// ```
+    pub fn mass_based_with_tolerance(
+        components: HashMap<CustomMixComponent, Ratio>,
+        tolerance: f64,
+    ) -> Result<Self, CustomMixError> {
+        Self::validate(&components, tolerance)?;
         Ok(Self::MassBased(components))
     }
 
+    /// Creates and returns a new [`CustomMix::MoleBased`] instance
+    /// with fractions scaled so that their sum is exactly `1.0`.
+    ///
+    /// **NB.** The specified fractions are changed:
+    /// each of them is divided by their sum before validation.
+    ///
+    /// # Args
+    ///
+    /// - `components` -- hash map of components and their _mole_ fractions
+    ///   _(must be positive, but their sum may differ from `1.0`)_.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`CustomMixError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::substance::{CustomMix, Pure};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let mix = CustomMix::mole_based_normalized(HashMap::from([
+    ///     (Pure::Water.into(), Ratio::new::<percent>(79.9)),
+    ///     (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+    /// ]))
+    /// .unwrap();
+    /// assert_relative_eq!(
+    ///     mix.components()[&Pure::Water.into()].get::<percent>(),
+    ///     79.9 / 99.9 * 100.0
+    /// );
+    /// ```
+    pub fn mole_based_normalized(
+        components: HashMap<CustomMixComponent, Ratio>,
+    ) -> Result<Self, CustomMixError> {
+        Self::mole_based(Self::normalize(components)?)
+    }
+
+    /// Creates and returns a new [`CustomMix::MassBased`] instance
+    /// with fractions scaled so that their sum is exactly `1.0`.
+    ///
+    /// **NB.** The specified fractions are changed:
+    /// each of them is divided by their sum before validation.
+    ///
+    /// # Args
+    ///
+    /// - `components` -- hash map of components and their _mass_ fractions
+    ///   _(must be positive, but their sum may differ from `1.0`)_.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`CustomMixError`] is returned.
+    pub fn mass_based_normalized(
+        components: HashMap<CustomMixComponent, Ratio>,
+    ) -> Result<Self, CustomMixError> {
+        Self::mass_based(Self::normalize(components)?)
+    }
+
+    /// Creates and returns a new [`CustomMixBuilder`] instance
+    /// for incremental construction of the mixture.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{CustomMix, Refrigerant};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let mut builder = CustomMix::builder();
+    /// for (refrigerant, fraction) in [(Refrigerant::R32, 70.0), (Refrigerant::R125, 30.0)] {
+    ///     builder = builder.with_component(refrigerant, Ratio::new::<percent>(fraction));
+    /// }
+    /// assert!(builder.mole_based().is_ok());
+    /// ```
+    pub fn builder() -> CustomMixBuilder {
+        CustomMixBuilder::default()
+    }
+
     /// Clone and convert to [`CustomMix::MoleBased`]
     /// _(mass fractions will be converted to mole fractions)_.
     ///
+    /// # Errors
+    ///
+    /// If the molar mass of any component can't be obtained from CoolProp,
+    /// a [`CoolPropError`] is returned.
+    ///
     /// # Examples
     ///
     /// ```
@@ -115,33 +287,106 @@ impl CustomMix {
     ///     (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
     /// ]))
     /// .unwrap();
-    /// assert_eq!(mole_based_mix.to_mole_based(), mole_based_mix);
+    /// assert_eq!(mole_based_mix.to_mole_based().unwrap(), mole_based_mix);
     ///
     /// let mass_based_mix = CustomMix::mass_based(HashMap::from([
     ///     (Refrigerant::R32.into(), Ratio::new::<percent>(50.0)),
     ///     (Refrigerant::R125.into(), Ratio::new::<percent>(50.0)),
     /// ]))
     /// .unwrap();
-    /// assert_ne!(mass_based_mix.to_mole_based(), mass_based_mix);
+    /// assert_ne!(mass_based_mix.to_mole_based().unwrap(), mass_based_mix);
     /// ```
-    pub fn to_mole_based(&self) -> Self {
+    pub fn to_mole_based(&self) -> Result<Self, CoolPropError> {
         match self {
-            CustomMix::MassBased(c) => {
-                let mut components = c.clone().into_iter().collect::<Vec<_>>();
-                let mut sum = 0.0;
-                for component in &mut components {
-                    component.1 /= Self::molar_mass(&component.0);
-                    sum += component.1.value;
-                }
-                for component in &mut components {
-                    component.1 /= sum;
-                }
-                Self::MoleBased(HashMap::from_iter(components))
-            }
-            _ => self.clone(),
+            CustomMix::MassBased(_) => Ok(Self::MoleBased(self.convert(mass_to_mole_fractions)?)),
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// Clone and convert to [`CustomMix::MassBased`]
+    /// _(mole fractions will be converted to mass fractions)_.
+    ///
+    /// # Errors
+    ///
+    /// If the molar mass of any component can't be obtained from CoolProp,
+    /// a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{CustomMix, Pure, Refrigerant};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let mass_based_mix = CustomMix::mass_based(HashMap::from([
+    ///     (Pure::Water.into(), Ratio::new::<percent>(60.0)),
+    ///     (Pure::Ethanol.into(), Ratio::new::<percent>(40.0)),
+    /// ]))
+    /// .unwrap();
+    /// assert_eq!(mass_based_mix.to_mass_based().unwrap(), mass_based_mix);
+    ///
+    /// let mole_based_mix = CustomMix::mole_based(HashMap::from([
+    ///     (Refrigerant::R32.into(), Ratio::new::<percent>(70.0)),
+    ///     (Refrigerant::R125.into(), Ratio::new::<percent>(30.0)),
+    /// ]))
+    /// .unwrap();
+    /// assert_ne!(mole_based_mix.to_mass_based().unwrap(), mole_based_mix);
+    /// ```
+    pub fn to_mass_based(&self) -> Result<Self, CoolPropError> {
+        match self {
+            CustomMix::MoleBased(_) => Ok(Self::MassBased(self.convert(mole_to_mass_fractions)?)),
+            _ => Ok(self.clone()),
         }
     }
 
+    /// Molar mass of the mixture
+    /// _(sum of the component molar masses weighted by their mole fractions)_.
+    ///
+    /// # Errors
+    ///
+    /// If the molar mass of any component can't be obtained from CoolProp,
+    /// a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::substance::{CustomMix, CustomMixComponent, Pure};
+    /// use rfluids::uom::si::f64::{MolarMass, Ratio};
+    /// use rfluids::uom::si::molar_mass::gram_per_mole;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let mix = CustomMix::mole_based(HashMap::from([
+    ///     (
+    ///         CustomMixComponent::with_molar_mass(Pure::Water, MolarMass::new::<gram_per_mole>(18.0)),
+    ///         Ratio::new::<percent>(50.0),
+    ///     ),
+    ///     (
+    ///         CustomMixComponent::with_molar_mass(Pure::Ethanol, MolarMass::new::<gram_per_mole>(46.0)),
+    ///         Ratio::new::<percent>(50.0),
+    ///     ),
+    /// ]))
+    /// .unwrap();
+    /// assert_relative_eq!(mix.molar_mass().unwrap().get::<gram_per_mole>(), 32.0);
+    /// ```
+    pub fn molar_mass(&self) -> Result<MolarMass, CoolPropError> {
+        let molar_mass = match self {
+            CustomMix::MoleBased(components) => components
+                .iter()
+                .map(|(c, f)| Ok(Self::component_molar_mass(c)? * f.value))
+                .sum::<Result<f64, CoolPropError>>()?,
+            CustomMix::MassBased(components) => {
+                1.0 / components
+                    .iter()
+                    .map(|(c, f)| Ok(f.value / Self::component_molar_mass(c)?))
+                    .sum::<Result<f64, CoolPropError>>()?
+            }
+        };
+        Ok(MolarMass::new::<kilogram_per_mole>(molar_mass))
+    }
+
     /// Specified components and their fractions.
     pub fn components(&self) -> &HashMap<CustomMixComponent, Ratio> {
         match self {
@@ -150,95 +395,936 @@ impl CustomMix {
         }
     }
 
-    fn validate(components: &HashMap<CustomMixComponent, Ratio>) -> Result<(), CustomMixError> {
+    /// Names of the components separated by the `&` symbol
+    /// _(in alphabetical order)_.
+    pub(crate) fn name(&self) -> String {
+        let mut names = self
+            .components()
+            .keys()
+            .map(|c| c.as_ref())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.join("&")
+    }
+
+    /// Mole fractions of the components
+    /// _(in the same order as in [`name`](CustomMix::name))_.
+    pub(crate) fn mole_fractions(&self) -> Result<Vec<f64>, CoolPropError> {
+        let mix = self.to_mole_based()?;
+        let mut components = mix
+            .components()
+            .iter()
+            .map(|(c, f)| (c.as_ref(), f.value))
+            .collect::<Vec<_>>();
+        components.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        Ok(components.into_iter().map(|c| c.1).collect())
+    }
+
+    fn normalize(
+        components: HashMap<CustomMixComponent, Ratio>,
+    ) -> Result<HashMap<CustomMixComponent, Ratio>, CustomMixError> {
+        if components
+            .values()
+            .any(|f| !f.value.is_finite() || f.value <= 0.0)
+        {
+            return Err(CustomMixError::InvalidFraction);
+        }
+        let sum = components.values().map(|f| f.value).sum::<f64>();
+        Ok(components.into_iter().map(|(c, f)| (c, f / sum)).collect())
+    }
+
+    fn validate(
+        components: &HashMap<CustomMixComponent, Ratio>,
+        tolerance: f64,
+    ) -> Result<(), CustomMixError> {
+        if !tolerance.is_finite() || tolerance <= 0.0 {
+            return Err(CustomMixError::InvalidTolerance);
+        }
         if components.len() < 2 {
             return Err(CustomMixError::NotEnoughComponents);
         }
         if components.keys().any(|c| {
             matches!(
-                c, CustomMixComponent::Refrigerant(r) if r.category() != RefrigerantCategory::Pure
+                c.without_molar_mass(),
+                CustomMixComponent::Refrigerant(r) if r.category() != RefrigerantCategory::Pure
             )
         }) {
             return Err(CustomMixError::InvalidComponent);
         }
+        if components.keys().any(|c| {
+            c.overridden_molar_mass()
+                .is_some_and(|m| !m.value.is_finite() || m.value <= 0.0)
+        }) {
+            return Err(CustomMixError::InvalidMolarMass);
+        }
+        let mut names = components.keys().map(|c| c.as_ref()).collect::<Vec<_>>();
+        names.sort_unstable();
+        if let Some(name) = names.windows(2).find(|w| w[0] == w[1]).map(|w| w[0]) {
+            return Err(CustomMixError::DuplicateComponent(name.into()));
+        }
         if components
             .values()
             .any(|f| f <= &Ratio::ZERO || f >= &Ratio::new::<ratio>(1.0))
         {
             return Err(CustomMixError::InvalidFraction);
         }
-        if (components.values().map(|f| f.value).sum::<f64>() - 1.0).abs() > 1e-6 {
+        if (components.values().map(|f| f.value).sum::<f64>() - 1.0).abs() > tolerance {
             return Err(CustomMixError::InvalidFractionsSum);
         }
         Ok(())
     }
 
-    fn molar_mass(component: &CustomMixComponent) -> f64 {
-        AbstractState::new(component.backend_name(), component.as_ref())
-            .unwrap()
-            .keyed_output(MolarMass)
-            .unwrap()
+    fn convert(
+        &self,
+        conversion: fn(&[(MolarMass, Ratio)]) -> Vec<Ratio>,
+    ) -> Result<HashMap<CustomMixComponent, Ratio>, CoolPropError> {
+        let components = self.components().iter().collect::<Vec<_>>();
+        let fractions = conversion(
+            &components
+                .iter()
+                .map(|(c, f)| {
+                    Ok((
+                        MolarMass::new::<kilogram_per_mole>(Self::component_molar_mass(c)?),
+                        **f,
+                    ))
+                })
+                .collect::<Result<Vec<_>, CoolPropError>>()?,
+        );
+        Ok(components
+            .into_iter()
+            .map(|(c, _)| *c)
+            .zip(fractions)
+            .collect())
     }
-}
 
-impl BackendName for CustomMix {
-    fn backend_name(&self) -> &'static str {
-        "HEOS"
+    fn component_molar_mass(component: &CustomMixComponent) -> Result<f64, CoolPropError> {
+        let refrigerant = match component {
+            CustomMixComponent::Pure(pure) => {
+                return Ok(pure.molar_mass().get::<kilogram_per_mole>())
+            }
+            CustomMixComponent::Refrigerant(refrigerant) => refrigerant,
+            CustomMixComponent::PureWithMolarMass(_, molar_mass)
+            | CustomMixComponent::RefrigerantWithMolarMass(_, molar_mass) => {
+                return Ok(molar_mass.get::<kilogram_per_mole>())
+            }
+        };
+        let cached = MOLAR_MASSES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(refrigerant)
+            .copied();
+        if let Some(molar_mass) = cached {
+            return Ok(molar_mass);
+        }
+        // The backend is created outside the lock,
+        // so that concurrent calls are not blocked by FFI
+        let molar_mass = AbstractState::new(refrigerant.backend_name(), refrigerant.as_ref())?
+            .keyed_output(FluidTrivialParam::MolarMass)?;
+        MOLAR_MASSES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(*refrigerant, molar_mass);
+        Ok(molar_mass)
     }
 }
 
-/// Custom mixture component.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-pub enum CustomMixComponent {
-    /// Pure substance.
-    Pure(Pure),
+/// Converts mole fractions to mass fractions.
+///
+/// # Args
+///
+/// - `components` -- molar masses and mole fractions of the components.
+///
+/// # Returns
+///
+/// Mass fractions of the components _(in the same order)_.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use rfluids::substance::mole_to_mass_fractions;
+/// use rfluids::uom::si::f64::{MolarMass, Ratio};
+/// use rfluids::uom::si::molar_mass::gram_per_mole;
+/// use rfluids::uom::si::ratio::percent;
+///
+/// let result = mole_to_mass_fractions(&[
+///     (MolarMass::new::<gram_per_mole>(18.0), Ratio::new::<percent>(50.0)),
+///     (MolarMass::new::<gram_per_mole>(54.0), Ratio::new::<percent>(50.0)),
+/// ]);
+/// assert_relative_eq!(result[0].get::<percent>(), 25.0);
+/// assert_relative_eq!(result[1].get::<percent>(), 75.0);
+/// ```
+///
+/// # See also
+///
+/// - [`mass_to_mole_fractions`]
+/// - [`CustomMix::to_mass_based`]
+pub fn mole_to_mass_fractions(components: &[(MolarMass, Ratio)]) -> Vec<Ratio> {
+    let masses = components
+        .iter()
+        .map(|(molar_mass, fraction)| molar_mass.value * fraction.value)
+        .collect::<Vec<_>>();
+    normalized(masses)
+}
 
-    /// Pure refrigerant.
-    Refrigerant(Refrigerant),
+/// Converts mass fractions to mole fractions.
+///
+/// # Args
+///
+/// - `components` -- molar masses and mass fractions of the components.
+///
+/// # Returns
+///
+/// Mole fractions of the components _(in the same order)_.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use rfluids::substance::mass_to_mole_fractions;
+/// use rfluids::uom::si::f64::{MolarMass, Ratio};
+/// use rfluids::uom::si::molar_mass::gram_per_mole;
+/// use rfluids::uom::si::ratio::percent;
+///
+/// let result = mass_to_mole_fractions(&[
+///     (MolarMass::new::<gram_per_mole>(18.0), Ratio::new::<percent>(25.0)),
+///     (MolarMass::new::<gram_per_mole>(54.0), Ratio::new::<percent>(75.0)),
+/// ]);
+/// assert_relative_eq!(result[0].get::<percent>(), 50.0);
+/// assert_relative_eq!(result[1].get::<percent>(), 50.0);
+/// ```
+///
+/// # See also
+///
+/// - [`mole_to_mass_fractions`]
+/// - [`CustomMix::to_mole_based`]
+pub fn mass_to_mole_fractions(components: &[(MolarMass, Ratio)]) -> Vec<Ratio> {
+    let moles = components
+        .iter()
+        .map(|(molar_mass, fraction)| fraction.value / molar_mass.value)
+        .collect::<Vec<_>>();
+    normalized(moles)
 }
 
-impl BackendName for CustomMixComponent {
-    fn backend_name(&self) -> &'static str {
-        match self {
-            CustomMixComponent::Pure(pure) => pure.backend_name(),
-            CustomMixComponent::Refrigerant(refrigerant) => refrigerant.backend_name(),
-        }
-    }
+fn normalized(values: Vec<f64>) -> Vec<Ratio> {
+    let sum: f64 = values.iter().sum();
+    values
+        .into_iter()
+        .map(|value| Ratio::new::<ratio>(value / sum))
+        .collect()
 }
 
-impl AsRef<str> for CustomMixComponent {
-    fn as_ref(&self) -> &str {
-        match self {
-            CustomMixComponent::Pure(pure) => pure.as_ref(),
-            CustomMixComponent::Refrigerant(refrigerant) => refrigerant.as_ref(),
-        }
+/// Builder of [`CustomMix`].
+///
+/// **NB.** If the same component is specified more than once,
+/// its fractions are summed.
+#[derive(Debug, Clone, Default)]
+pub struct CustomMixBuilder {
+    components: HashMap<CustomMixComponent, Ratio>,
+}
+
+impl CustomMixBuilder {
+    /// Adds the specified component with its fraction.
+    ///
+    /// # Args
+    ///
+    /// - `component` -- pure substance or pure refrigerant.
+    /// - `fraction` -- _mole_ or _mass_ fraction
+    ///   _(depending on the terminal method)_.
+    pub fn with_component(
+        mut self,
+        component: impl Into<CustomMixComponent>,
+        fraction: Ratio,
+    ) -> Self {
+        self.components
+            .entry(component.into())
+            .and_modify(|f| *f += fraction)
+            .or_insert(fraction);
+        self
+    }
+
+    /// Creates and returns a new [`CustomMix::MoleBased`] instance
+    /// _(specified fractions are treated as mole fractions)_.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`CustomMixError`] is returned.
+    pub fn mole_based(self) -> Result<CustomMix, CustomMixError> {
+        CustomMix::mole_based(self.components)
+    }
+
+    /// Creates and returns a new [`CustomMix::MassBased`] instance
+    /// _(specified fractions are treated as mass fractions)_.
+    ///
+    /// # Errors
+    ///
+    /// For invalid inputs, a [`CustomMixError`] is returned.
+    pub fn mass_based(self) -> Result<CustomMix, CustomMixError> {
+        CustomMix::mass_based(self.components)
     }
 }
 
-impl From<Pure> for CustomMixComponent {
-    fn from(value: Pure) -> Self {
-        Self::Pure(value)
+impl BackendName for CustomMix {
+    fn backend_name(&self) -> &'static str {
+        "HEOS"
     }
 }
 
-impl From<Refrigerant> for CustomMixComponent {
-    fn from(value: Refrigerant) -> Self {
-        Self::Refrigerant(value)
+impl Eq for CustomMix {}
+
+impl Hash for CustomMix {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        let mut components: Vec<_> = self
+            .components()
+            .iter()
+            .map(|(component, fraction)| {
+                (component.as_ref(), (fraction.value * 1e9).round() as i64)
+            })
+            .collect();
+        components.sort_unstable();
+        components.hash(state);
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl IntoIterator for CustomMix {
+    type Item = (CustomMixComponent, Ratio);
+    type IntoIter = std::collections::hash_map::IntoIter<CustomMixComponent, Ratio>;
 
-    mod custom_mix {
-        use super::*;
-        use crate::uom::si::ratio::percent;
-        use approx::relative_eq;
-        use rstest::*;
+    /// Consumes the mixture and returns an iterator over its components and fractions
+    /// _(mole or mass fractions, depending on the mixture kind)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{CustomMix, Pure};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let mix = CustomMix::mole_based(HashMap::from([
+    ///     (Pure::Water.into(), Ratio::new::<percent>(80.0)),
+    ///     (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+    /// ]))
+    /// .unwrap();
+    /// let components: HashMap<_, _> = mix.into_iter().collect();
+    /// assert_eq!(components[&Pure::Ethanol.into()], Ratio::new::<percent>(20.0));
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            CustomMix::MoleBased(components) => components.into_iter(),
+            CustomMix::MassBased(components) => components.into_iter(),
+        }
+    }
+}
 
-        #[rstest]
-        #[case(HashMap::from([(Pure::Water.into(), 60.0), (Pure::Ethanol.into(), 40.0)]))]
+impl<'a> IntoIterator for &'a CustomMix {
+    type Item = (&'a CustomMixComponent, &'a Ratio);
+    type IntoIter = std::collections::hash_map::Iter<'a, CustomMixComponent, Ratio>;
+
+    /// Returns an iterator over the mixture components and fractions
+    /// _(mole or mass fractions, depending on the mixture kind)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{CustomMix, Pure};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let mix = CustomMix::mass_based(HashMap::from([
+    ///     (Pure::Water.into(), Ratio::new::<percent>(60.0)),
+    ///     (Pure::Ethanol.into(), Ratio::new::<percent>(40.0)),
+    /// ]))
+    /// .unwrap();
+    /// let mut sum = Ratio::new::<percent>(0.0);
+    /// for (_component, fraction) in &mix {
+    ///     sum += *fraction;
+    /// }
+    /// assert_eq!(sum, Ratio::new::<percent>(100.0));
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.components().iter()
+    }
+}
+
+/// Custom mixture component.
+///
+/// **NB.** Components with the overridden molar mass
+/// _(see [`CustomMixComponent::with_molar_mass`])_
+/// are compared and hashed together with the bits of the molar mass,
+/// so they are different from the same substance without the override.
+#[derive(Debug, Copy, Clone)]
+pub enum CustomMixComponent {
+    /// Pure substance.
+    Pure(Pure),
+
+    /// Pure refrigerant.
+    Refrigerant(Refrigerant),
+
+    /// Pure substance with the overridden molar mass.
+    PureWithMolarMass(Pure, MolarMass),
+
+    /// Pure refrigerant with the overridden molar mass.
+    RefrigerantWithMolarMass(Refrigerant, MolarMass),
+}
+
+impl CustomMixComponent {
+    /// Creates and returns a new component with the overridden molar mass
+    /// _(it will be used for conversions between mass and mole fractions
+    /// instead of the CoolProp value)_.
+    ///
+    /// If the molar mass of the specified component is already overridden,
+    /// it's replaced.
+    ///
+    /// **NB.** The molar mass is validated only when the mixture is created
+    /// _(it must be positive and finite)_.
+    ///
+    /// # Args
+    ///
+    /// - `component` -- pure substance or pure refrigerant.
+    /// - `molar_mass` -- molar mass to be used instead of the CoolProp value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{CustomMix, CustomMixComponent, Refrigerant};
+    /// use rfluids::uom::si::f64::{MolarMass, Ratio};
+    /// use rfluids::uom::si::molar_mass::gram_per_mole;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// let component =
+    ///     CustomMixComponent::with_molar_mass(Refrigerant::R32, MolarMass::new::<gram_per_mole>(52.0));
+    /// assert_eq!(component.as_ref(), "R32");
+    /// assert_ne!(component, Refrigerant::R32.into());
+    ///
+    /// let mix = CustomMix::mass_based(HashMap::from([
+    ///     (component, Ratio::new::<percent>(50.0)),
+    ///     (Refrigerant::R125.into(), Ratio::new::<percent>(50.0)),
+    /// ]));
+    /// assert!(mix.is_ok());
+    /// ```
+    pub fn with_molar_mass(
+        component: impl Into<CustomMixComponent>,
+        molar_mass: MolarMass,
+    ) -> Self {
+        match component.into() {
+            CustomMixComponent::Pure(pure) | CustomMixComponent::PureWithMolarMass(pure, _) => {
+                Self::PureWithMolarMass(pure, molar_mass)
+            }
+            CustomMixComponent::Refrigerant(refrigerant)
+            | CustomMixComponent::RefrigerantWithMolarMass(refrigerant, _) => {
+                Self::RefrigerantWithMolarMass(refrigerant, molar_mass)
+            }
+        }
+    }
+
+    /// Same component without the overridden molar mass.
+    pub(crate) fn without_molar_mass(self) -> Self {
+        match self {
+            CustomMixComponent::PureWithMolarMass(pure, _) => Self::Pure(pure),
+            CustomMixComponent::RefrigerantWithMolarMass(refrigerant, _) => {
+                Self::Refrigerant(refrigerant)
+            }
+            _ => self,
+        }
+    }
+
+    /// Overridden molar mass _(if any)_.
+    pub(crate) fn overridden_molar_mass(&self) -> Option<MolarMass> {
+        match self {
+            CustomMixComponent::PureWithMolarMass(_, molar_mass)
+            | CustomMixComponent::RefrigerantWithMolarMass(_, molar_mass) => Some(*molar_mass),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for CustomMixComponent {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (CustomMixComponent::Pure(a), CustomMixComponent::Pure(b)) => a == b,
+            (CustomMixComponent::Refrigerant(a), CustomMixComponent::Refrigerant(b)) => a == b,
+            (
+                CustomMixComponent::PureWithMolarMass(a, m),
+                CustomMixComponent::PureWithMolarMass(b, n),
+            ) => a == b && m.value.to_bits() == n.value.to_bits(),
+            (
+                CustomMixComponent::RefrigerantWithMolarMass(a, m),
+                CustomMixComponent::RefrigerantWithMolarMass(b, n),
+            ) => a == b && m.value.to_bits() == n.value.to_bits(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for CustomMixComponent {}
+
+impl Hash for CustomMixComponent {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            CustomMixComponent::Pure(pure) => pure.hash(state),
+            CustomMixComponent::Refrigerant(refrigerant) => refrigerant.hash(state),
+            CustomMixComponent::PureWithMolarMass(pure, molar_mass) => {
+                (pure, molar_mass.value.to_bits()).hash(state);
+            }
+            CustomMixComponent::RefrigerantWithMolarMass(refrigerant, molar_mass) => {
+                (refrigerant, molar_mass.value.to_bits()).hash(state);
+            }
+        }
+    }
+}
+
+impl BackendName for CustomMixComponent {
+    fn backend_name(&self) -> &'static str {
+        match self {
+            CustomMixComponent::Pure(pure) | CustomMixComponent::PureWithMolarMass(pure, _) => {
+                pure.backend_name()
+            }
+            CustomMixComponent::Refrigerant(refrigerant)
+            | CustomMixComponent::RefrigerantWithMolarMass(refrigerant, _) => {
+                refrigerant.backend_name()
+            }
+        }
+    }
+}
+
+impl AsRef<str> for CustomMixComponent {
+    fn as_ref(&self) -> &str {
+        match self {
+            CustomMixComponent::Pure(pure) | CustomMixComponent::PureWithMolarMass(pure, _) => {
+                pure.as_ref()
+            }
+            CustomMixComponent::Refrigerant(refrigerant)
+            | CustomMixComponent::RefrigerantWithMolarMass(refrigerant, _) => refrigerant.as_ref(),
+        }
+    }
+}
+
+impl From<Pure> for CustomMixComponent {
+    fn from(value: Pure) -> Self {
+        Self::Pure(value)
+    }
+}
+
+impl From<Refrigerant> for CustomMixComponent {
+    fn from(value: Refrigerant) -> Self {
+        Self::Refrigerant(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use super::*;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "type")]
+    enum CustomMixRepr {
+        MoleBased(ComponentsRepr),
+        MassBased(ComponentsRepr),
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ComponentsRepr {
+        components: KindsRepr,
+        #[serde(default, skip_serializing_if = "KindsRepr::is_empty")]
+        molar_masses: KindsRepr,
+    }
+
+    /// Values by component names, grouped by component kind
+    /// _(the same name can refer to both a pure substance and a refrigerant,
+    /// e.g., `SulfurHexafluoride`)_.
+    #[derive(Default, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct KindsRepr {
+        #[serde(rename = "Pure", default, skip_serializing_if = "BTreeMap::is_empty")]
+        pure: BTreeMap<String, f64>,
+        #[serde(
+            rename = "Refrigerant",
+            default,
+            skip_serializing_if = "BTreeMap::is_empty"
+        )]
+        refrigerant: BTreeMap<String, f64>,
+    }
+
+    impl KindsRepr {
+        fn is_empty(&self) -> bool {
+            self.pure.is_empty() && self.refrigerant.is_empty()
+        }
+
+        fn into_components(self) -> Result<Vec<(CustomMixComponent, f64)>, String> {
+            fn parse<T: FromStr + Into<CustomMixComponent>>(
+                values: BTreeMap<String, f64>,
+            ) -> impl Iterator<Item = Result<(CustomMixComponent, f64), String>> {
+                values.into_iter().map(|(name, value)| {
+                    T::from_str(&name)
+                        .map(|c| (c.into(), value))
+                        .map_err(|_| format!("Unknown substance name `{}`!", name))
+                })
+            }
+            parse::<Pure>(self.pure)
+                .chain(parse::<Refrigerant>(self.refrigerant))
+                .collect()
+        }
+    }
+
+    impl<'a> FromIterator<(&'a CustomMixComponent, f64)> for KindsRepr {
+        fn from_iter<I: IntoIterator<Item = (&'a CustomMixComponent, f64)>>(iter: I) -> Self {
+            let mut result = Self::default();
+            for (component, value) in iter {
+                let values = match component.without_molar_mass() {
+                    CustomMixComponent::Refrigerant(_) => &mut result.refrigerant,
+                    _ => &mut result.pure,
+                };
+                let name = component.as_ref();
+                values.insert(name.to_string(), value);
+            }
+            result
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    enum ComponentRepr {
+        Pure(Pure),
+        Refrigerant(Refrigerant),
+        PureWithMolarMass(Pure, f64),
+        RefrigerantWithMolarMass(Refrigerant, f64),
+    }
+
+    impl From<&CustomMix> for ComponentsRepr {
+        fn from(value: &CustomMix) -> Self {
+            Self {
+                components: value
+                    .components()
+                    .iter()
+                    .map(|(c, f)| (c, f.get::<ratio>()))
+                    .collect(),
+                molar_masses: value
+                    .components()
+                    .keys()
+                    .filter_map(|c| {
+                        Some((c, c.overridden_molar_mass()?.get::<kilogram_per_mole>()))
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    impl Serialize for CustomMix {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                CustomMix::MoleBased(..) => CustomMixRepr::MoleBased(self.into()),
+                CustomMix::MassBased(..) => CustomMixRepr::MassBased(self.into()),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CustomMix {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (repr, mole_based) = match CustomMixRepr::deserialize(deserializer)? {
+                CustomMixRepr::MoleBased(repr) => (repr, true),
+                CustomMixRepr::MassBased(repr) => (repr, false),
+            };
+            let mut molar_masses: HashMap<_, _> = repr
+                .molar_masses
+                .into_components()
+                .map_err(de::Error::custom)?
+                .into_iter()
+                .collect();
+            let components = repr
+                .components
+                .into_components()
+                .map_err(de::Error::custom)?
+                .into_iter()
+                .map(|(c, f)| {
+                    let c = match molar_masses.remove(&c) {
+                        Some(m) => CustomMixComponent::with_molar_mass(
+                            c,
+                            MolarMass::new::<kilogram_per_mole>(m),
+                        ),
+                        None => c,
+                    };
+                    (c, Ratio::new::<ratio>(f))
+                })
+                .collect();
+            if let Some(component) = molar_masses.keys().next() {
+                return Err(de::Error::custom(format!(
+                    "Molar mass is specified for missing component `{}`!",
+                    component.as_ref()
+                )));
+            }
+            if mole_based {
+                CustomMix::mole_based(components)
+            } else {
+                CustomMix::mass_based(components)
+            }
+            .map_err(de::Error::custom)
+        }
+    }
+
+    impl Serialize for CustomMixComponent {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match *self {
+                CustomMixComponent::Pure(pure) => ComponentRepr::Pure(pure),
+                CustomMixComponent::Refrigerant(refrigerant) => {
+                    ComponentRepr::Refrigerant(refrigerant)
+                }
+                CustomMixComponent::PureWithMolarMass(pure, molar_mass) => {
+                    ComponentRepr::PureWithMolarMass(pure, molar_mass.get::<kilogram_per_mole>())
+                }
+                CustomMixComponent::RefrigerantWithMolarMass(refrigerant, molar_mass) => {
+                    ComponentRepr::RefrigerantWithMolarMass(
+                        refrigerant,
+                        molar_mass.get::<kilogram_per_mole>(),
+                    )
+                }
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CustomMixComponent {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(match ComponentRepr::deserialize(deserializer)? {
+                ComponentRepr::Pure(pure) => pure.into(),
+                ComponentRepr::Refrigerant(refrigerant) => refrigerant.into(),
+                ComponentRepr::PureWithMolarMass(pure, molar_mass) => {
+                    CustomMixComponent::with_molar_mass(
+                        pure,
+                        MolarMass::new::<kilogram_per_mole>(molar_mass),
+                    )
+                }
+                ComponentRepr::RefrigerantWithMolarMass(refrigerant, molar_mass) => {
+                    CustomMixComponent::with_molar_mass(
+                        refrigerant,
+                        MolarMass::new::<kilogram_per_mole>(molar_mass),
+                    )
+                }
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::uom::si::ratio::percent;
+        use serde_json::json;
+        use strum::IntoEnumIterator;
+
+        #[test]
+        fn custom_mix_round_trip_returns_same_mix() {
+            let r32 = CustomMixComponent::with_molar_mass(
+                Refrigerant::R32,
+                MolarMass::new::<kilogram_per_mole>(0.052),
+            );
+            let sut = CustomMix::mass_based(HashMap::from([
+                (r32, Ratio::new::<percent>(50.0)),
+                (Pure::Water.into(), Ratio::new::<percent>(50.0)),
+            ]))
+            .unwrap();
+            let json = serde_json::to_value(&sut).unwrap();
+            assert_eq!(
+                json,
+                json!({
+                    "type": "MassBased",
+                    "components": {
+                        "Pure": { "Water": 0.5 },
+                        "Refrigerant": { "R32": 0.5 }
+                    },
+                    "molar_masses": { "Refrigerant": { "R32": 0.052 } }
+                })
+            );
+            let result: CustomMix = serde_json::from_value(json).unwrap();
+            assert_eq!(result, sut);
+            assert!(result.components().contains_key(&r32));
+        }
+
+        #[test]
+        fn custom_mix_molar_mass_of_missing_component_returns_err() {
+            let result = serde_json::from_value::<CustomMix>(json!({
+                "type": "MassBased",
+                "components": {
+                    "Pure": { "Water": 0.5 },
+                    "Refrigerant": { "R32": 0.5 }
+                },
+                "molar_masses": { "Pure": { "Ethanol": 0.046 } }
+            }));
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "Molar mass is specified for missing component `Ethanol`!"
+            );
+        }
+
+        #[test]
+        fn custom_mix_without_molar_masses_omits_them() {
+            let sut = CustomMix::mole_based(HashMap::from([
+                (Pure::Water.into(), Ratio::new::<percent>(80.0)),
+                (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+            ]))
+            .unwrap();
+            let json = serde_json::to_value(&sut).unwrap();
+            assert_eq!(
+                json,
+                json!({
+                    "type": "MoleBased",
+                    "components": { "Pure": { "Ethanol": 0.2, "Water": 0.8 } }
+                })
+            );
+            assert_eq!(serde_json::from_value::<CustomMix>(json).unwrap(), sut);
+        }
+
+        #[test]
+        fn custom_mix_invalid_recipe_returns_err() {
+            let result = serde_json::from_value::<CustomMix>(json!({
+                "type": "MoleBased",
+                "components": { "Pure": { "Water": 0.6, "Ethanol": 0.6 } }
+            }));
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                CustomMixError::InvalidFractionsSum.to_string()
+            );
+        }
+
+        #[test]
+        fn custom_mix_unknown_component_returns_err() {
+            let result = serde_json::from_value::<CustomMix>(json!({
+                "type": "MoleBased",
+                "components": { "Pure": { "Water": 0.5, "Hello, World!": 0.5 } }
+            }));
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn custom_mix_component_of_wrong_kind_returns_err() {
+            let result = serde_json::from_value::<CustomMix>(json!({
+                "type": "MoleBased",
+                "components": { "Pure": { "Water": 0.5 }, "Refrigerant": { "Ethanol": 0.5 } }
+            }));
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn custom_mix_unknown_kind_returns_err() {
+            let result = serde_json::from_value::<CustomMix>(json!({
+                "type": "MoleBased",
+                "components": { "Pure": { "Water": 0.5 }, "Incomp": { "Ethanol": 0.5 } }
+            }));
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn custom_mix_with_same_named_pure_and_refrigerant_round_trips() {
+            let sut = CustomMix::mole_based(HashMap::from([
+                (Refrigerant::R846.into(), Ratio::new::<percent>(40.0)),
+                (Pure::SulfurDioxide.into(), Ratio::new::<percent>(60.0)),
+            ]))
+            .unwrap();
+            let json = serde_json::to_value(&sut).unwrap();
+            assert_eq!(
+                json,
+                json!({
+                    "type": "MoleBased",
+                    "components": {
+                        "Pure": { "SulfurDioxide": 0.6 },
+                        "Refrigerant": { "SulfurHexafluoride": 0.4 }
+                    }
+                })
+            );
+            assert_eq!(serde_json::from_value::<CustomMix>(json).unwrap(), sut);
+        }
+
+        #[test]
+        fn custom_mix_unknown_type_returns_err() {
+            let result = serde_json::from_value::<CustomMix>(json!({
+                "type": "VolumeBased",
+                "components": { "Pure": { "Water": 0.5, "Ethanol": 0.5 } }
+            }));
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn custom_mix_component_round_trip_returns_same_component() {
+            for sut in Pure::iter()
+                .map(CustomMixComponent::from)
+                .chain(Refrigerant::iter().map(CustomMixComponent::from))
+            {
+                let json = serde_json::to_string(&sut).unwrap();
+                let result: CustomMixComponent = serde_json::from_str(&json).unwrap();
+                assert_eq!(result, sut);
+            }
+        }
+
+        #[test]
+        fn custom_mix_component_with_molar_mass_round_trips() {
+            let sut = CustomMixComponent::with_molar_mass(
+                Pure::Water,
+                MolarMass::new::<kilogram_per_mole>(0.018),
+            );
+            let json = serde_json::to_value(sut).unwrap();
+            assert_eq!(json, json!({ "PureWithMolarMass": ["Water", 0.018] }));
+            assert_eq!(
+                serde_json::from_value::<CustomMixComponent>(json).unwrap(),
+                sut
+            );
+        }
+
+        #[test]
+        fn custom_mix_of_each_pure_refrigerant_round_trips() {
+            for refrigerant in Refrigerant::pure().filter(|r| *r != Refrigerant::R32) {
+                let sut = CustomMix::mass_based(HashMap::from([
+                    (Refrigerant::R32.into(), Ratio::new::<percent>(50.0)),
+                    (refrigerant.into(), Ratio::new::<percent>(50.0)),
+                ]))
+                .unwrap();
+                let json = serde_json::to_string(&sut).unwrap();
+                assert_eq!(serde_json::from_str::<CustomMix>(&json).unwrap(), sut);
+            }
+        }
+
+        #[test]
+        fn custom_mix_component_is_serialized_with_kind() {
+            assert_eq!(
+                serde_json::to_value(CustomMixComponent::from(Refrigerant::R846)).unwrap(),
+                json!({ "Refrigerant": "SulfurHexafluoride" })
+            );
+            assert_eq!(
+                serde_json::to_value(CustomMixComponent::from(Pure::SulfurHexafluoride)).unwrap(),
+                json!({ "Pure": "SulfurHexafluoride" })
+            );
+        }
+
+        #[test]
+        fn pure_and_refrigerant_are_serialized_as_names() {
+            assert_eq!(serde_json::to_string(&Pure::Water).unwrap(), "\"Water\"");
+            assert_eq!(serde_json::to_string(&Refrigerant::R32).unwrap(), "\"R32\"");
+            assert_eq!(
+                serde_json::from_str::<Pure>("\"H2O\"").unwrap(),
+                Pure::Water
+            );
+            assert!(serde_json::from_str::<Refrigerant>("\"Water\"").is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod custom_mix {
+        use super::*;
+        use crate::uom::si::ratio::percent;
+        use approx::{assert_relative_eq, relative_eq};
+        use rstest::*;
+
+        #[rstest]
+        #[case(HashMap::from([(Pure::Water.into(), 60.0), (Pure::Ethanol.into(), 40.0)]))]
         #[case(HashMap::from([(Refrigerant::R32.into(), 50.0), (Refrigerant::R125.into(), 50.0)]))]
         fn mole_or_mass_based_from_valid_input_returns_ok(
             #[case] components: HashMap<CustomMixComponent, f64>,
@@ -305,6 +1391,99 @@ mod tests {
             );
         }
 
+        #[rstest]
+        #[case(1e-2, true)]
+        #[case(1e-4, false)]
+        fn mole_or_mass_based_with_tolerance_checks_fractions_sum(
+            #[case] tolerance: f64,
+            #[case] is_ok: bool,
+        ) {
+            let components = HashMap::from([
+                (Refrigerant::R32.into(), Ratio::new::<percent>(50.1)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(50.1)),
+            ]);
+            assert_eq!(
+                CustomMix::mole_based_with_tolerance(components.clone(), tolerance).is_ok(),
+                is_ok
+            );
+            assert_eq!(
+                CustomMix::mass_based_with_tolerance(components, tolerance).is_ok(),
+                is_ok
+            );
+        }
+
+        #[rstest]
+        #[case(0.0)]
+        #[case(-1e-6)]
+        #[case(f64::NAN)]
+        #[case(f64::INFINITY)]
+        fn mole_or_mass_based_with_invalid_tolerance_returns_err(#[case] tolerance: f64) {
+            let components = HashMap::from([
+                (Refrigerant::R32.into(), Ratio::new::<percent>(50.0)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(50.0)),
+            ]);
+            assert_eq!(
+                CustomMix::mole_based_with_tolerance(components.clone(), tolerance).unwrap_err(),
+                CustomMixError::InvalidTolerance
+            );
+            assert_eq!(
+                CustomMix::mass_based_with_tolerance(components, tolerance).unwrap_err(),
+                CustomMixError::InvalidTolerance
+            );
+        }
+
+        #[test]
+        fn mole_or_mass_based_normalized_scales_fractions() {
+            let components = HashMap::from([
+                (Refrigerant::R32.into(), Ratio::new::<percent>(69.9)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(30.0)),
+            ]);
+            assert!(CustomMix::mole_based(components.clone()).is_err());
+            for sut in [
+                CustomMix::mole_based_normalized(components.clone()).unwrap(),
+                CustomMix::mass_based_normalized(components.clone()).unwrap(),
+            ] {
+                assert_relative_eq!(sut.components().values().map(|f| f.value).sum::<f64>(), 1.0);
+                assert_relative_eq!(
+                    sut.components()[&Refrigerant::R125.into()].value,
+                    30.0 / 99.9
+                );
+            }
+        }
+
+        #[rstest]
+        #[case(HashMap::from([(Pure::Water.into(), 50.0)]), CustomMixError::NotEnoughComponents)]
+        #[case(
+            HashMap::from([(Pure::Water.into(), 50.0), (Pure::Ethanol.into(), 0.0)]),
+            CustomMixError::InvalidFraction
+        )]
+        #[case(
+            HashMap::from([(Pure::Water.into(), 50.0), (Pure::Ethanol.into(), -10.0)]),
+            CustomMixError::InvalidFraction
+        )]
+        #[case(
+            HashMap::from([(Pure::Water.into(), 50.0), (Pure::Ethanol.into(), f64::NAN)]),
+            CustomMixError::InvalidFraction
+        )]
+        fn mole_or_mass_based_normalized_from_invalid_input_returns_err(
+            #[case] components: HashMap<CustomMixComponent, f64>,
+            #[case] expected: CustomMixError,
+        ) {
+            let components = HashMap::from_iter(
+                components
+                    .into_iter()
+                    .map(|c| (c.0, Ratio::new::<percent>(c.1))),
+            );
+            assert_eq!(
+                CustomMix::mole_based_normalized(components.clone()).unwrap_err(),
+                expected
+            );
+            assert_eq!(
+                CustomMix::mass_based_normalized(components).unwrap_err(),
+                expected
+            );
+        }
+
         #[test]
         fn to_mole_based_from_mole_based_returns_same() {
             let sut = CustomMix::mole_based(HashMap::from([
@@ -312,7 +1491,7 @@ mod tests {
                 (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
             ]))
             .unwrap();
-            let result = sut.to_mole_based();
+            let result = sut.to_mole_based().unwrap();
             assert_eq!(result, sut);
             assert!(matches(result, [("Water", 0.8), ("Ethanol", 0.2)]));
         }
@@ -324,7 +1503,7 @@ mod tests {
                 (Refrigerant::R125.into(), Ratio::new::<percent>(50.0)),
             ]))
             .unwrap();
-            let result = sut.to_mole_based();
+            let result = sut.to_mole_based().unwrap();
             assert_ne!(result, sut);
             assert!(matches(sut, [("R32", 0.5), ("R125", 0.5)]));
             assert!(matches(
@@ -333,6 +1512,184 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn to_mole_based_from_mass_based_uses_overridden_molar_masses() {
+            let sut = CustomMix::mass_based(HashMap::from([
+                (r32_with_molar_mass(0.05), Ratio::new::<percent>(50.0)),
+                (r125_with_molar_mass(0.12), Ratio::new::<percent>(50.0)),
+            ]))
+            .unwrap();
+            let result = sut.to_mole_based().unwrap();
+            assert!(matches(
+                result,
+                [("R32", 0.7058823529411765), ("R125", 0.29411764705882354)]
+            ));
+        }
+
+        #[test]
+        fn to_mass_based_from_mass_based_returns_same() {
+            let sut = CustomMix::mass_based(HashMap::from([
+                (Pure::Water.into(), Ratio::new::<percent>(60.0)),
+                (Pure::Ethanol.into(), Ratio::new::<percent>(40.0)),
+            ]))
+            .unwrap();
+            let result = sut.to_mass_based().unwrap();
+            assert_eq!(result, sut);
+            assert!(matches(result, [("Water", 0.6), ("Ethanol", 0.4)]));
+        }
+
+        #[test]
+        fn to_mass_based_from_mole_based_returns_other_with_converted_fractions() {
+            let sut = CustomMix::mole_based(HashMap::from([
+                (
+                    Refrigerant::R32.into(),
+                    Ratio::new::<ratio>(0.6976146993758624),
+                ),
+                (
+                    Refrigerant::R125.into(),
+                    Ratio::new::<ratio>(0.30238530062413754),
+                ),
+            ]))
+            .unwrap();
+            let result = sut.to_mass_based().unwrap();
+            assert_ne!(result, sut);
+            assert!(matches(result, [("R32", 0.5), ("R125", 0.5)]));
+        }
+
+        #[test]
+        fn to_mass_based_from_mole_based_uses_overridden_molar_masses() {
+            let sut = CustomMix::mole_based(HashMap::from([
+                (r32_with_molar_mass(0.05), Ratio::new::<percent>(70.0)),
+                (r125_with_molar_mass(0.12), Ratio::new::<percent>(30.0)),
+            ]))
+            .unwrap();
+            let result = sut.to_mass_based().unwrap();
+            assert!(matches(
+                result,
+                [("R32", 0.49295774647887325), ("R125", 0.5070422535211268)]
+            ));
+        }
+
+        #[test]
+        fn to_mole_based_and_back_to_mass_based_round_trips() {
+            let sut = CustomMix::mass_based(HashMap::from([
+                (Refrigerant::R32.into(), Ratio::new::<percent>(23.0)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(25.0)),
+                (Refrigerant::R134a.into(), Ratio::new::<percent>(52.0)),
+            ]))
+            .unwrap();
+            let result = sut.to_mole_based().unwrap().to_mass_based().unwrap();
+            for (component, fraction) in sut.components() {
+                assert_relative_eq!(
+                    result.components()[component].value,
+                    fraction.value,
+                    epsilon = 1e-6
+                );
+            }
+        }
+
+        #[test]
+        fn molar_mass_uses_overridden_molar_masses() {
+            let mole_based = CustomMix::mole_based(HashMap::from([
+                (
+                    CustomMixComponent::with_molar_mass(
+                        Pure::Water,
+                        MolarMass::new::<kilogram_per_mole>(0.018),
+                    ),
+                    Ratio::new::<percent>(75.0),
+                ),
+                (
+                    CustomMixComponent::with_molar_mass(
+                        Pure::Ethanol,
+                        MolarMass::new::<kilogram_per_mole>(0.046),
+                    ),
+                    Ratio::new::<percent>(25.0),
+                ),
+            ]))
+            .unwrap();
+            let mass_based = mole_based.to_mass_based().unwrap();
+            assert_relative_eq!(mole_based.molar_mass().unwrap().value, 0.025);
+            assert_relative_eq!(mass_based.molar_mass().unwrap().value, 0.025);
+        }
+
+        #[test]
+        fn mole_or_mass_based_with_same_substance_twice_returns_err() {
+            let components = HashMap::from([
+                (Refrigerant::R32.into(), Ratio::new::<percent>(30.0)),
+                (r32_with_molar_mass(0.052), Ratio::new::<percent>(30.0)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(40.0)),
+            ]);
+            let expected = CustomMixError::DuplicateComponent("R32".into());
+            assert_eq!(
+                CustomMix::mole_based(components.clone()).unwrap_err(),
+                expected
+            );
+            assert_eq!(CustomMix::mass_based(components).unwrap_err(), expected);
+        }
+
+        #[rstest]
+        #[case(0.0)]
+        #[case(-0.05)]
+        #[case(f64::NAN)]
+        #[case(f64::INFINITY)]
+        fn mole_or_mass_based_with_invalid_molar_mass_returns_err(#[case] molar_mass: f64) {
+            let components = HashMap::from([
+                (r32_with_molar_mass(molar_mass), Ratio::new::<percent>(50.0)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(50.0)),
+            ]);
+            assert_eq!(
+                CustomMix::mole_based(components.clone()).unwrap_err(),
+                CustomMixError::InvalidMolarMass
+            );
+            assert_eq!(
+                CustomMix::mass_based(components).unwrap_err(),
+                CustomMixError::InvalidMolarMass
+            );
+        }
+
+        #[test]
+        fn molar_mass_does_not_depend_on_fractions_kind() {
+            let sut = CustomMix::mass_based(HashMap::from([
+                (Refrigerant::R32.into(), Ratio::new::<percent>(50.0)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(50.0)),
+            ]))
+            .unwrap();
+            assert_relative_eq!(
+                sut.molar_mass().unwrap().value,
+                sut.to_mole_based().unwrap().molar_mass().unwrap().value,
+                max_relative = 1e-12
+            );
+            assert_relative_eq!(sut.molar_mass().unwrap().value, 0.0726, max_relative = 1e-3);
+        }
+
+        #[test]
+        fn molar_mass_of_pure_components_uses_static_data() {
+            let sut = CustomMix::mole_based(HashMap::from([
+                (Pure::Water.into(), Ratio::new::<percent>(50.0)),
+                (Pure::Ethanol.into(), Ratio::new::<percent>(50.0)),
+            ]))
+            .unwrap();
+            assert_eq!(
+                sut.molar_mass().unwrap().value,
+                0.5 * Pure::Water.molar_mass().value + 0.5 * Pure::Ethanol.molar_mass().value
+            );
+        }
+
+        #[test]
+        fn molar_mass_of_refrigerant_components_is_cached() {
+            let sut = CustomMix::mole_based(HashMap::from([
+                (Refrigerant::R32.into(), Ratio::new::<percent>(50.0)),
+                (Pure::Water.into(), Ratio::new::<percent>(50.0)),
+            ]))
+            .unwrap();
+            let result = sut.molar_mass().unwrap();
+            let cached = MOLAR_MASSES.lock().unwrap()[&Refrigerant::R32];
+            assert_relative_eq!(
+                result.value,
+                0.5 * cached + 0.5 * Pure::Water.molar_mass().value
+            );
+        }
+
         #[test]
         fn backend_name_returns_heos() {
             let sut = CustomMix::mass_based(HashMap::from([
@@ -343,6 +1700,82 @@ mod tests {
             assert_eq!(sut.backend_name(), "HEOS");
         }
 
+        #[test]
+        fn can_be_used_as_hash_map_key() {
+            let water_ethanol = || {
+                CustomMix::mole_based(HashMap::from([
+                    (Pure::Water.into(), Ratio::new::<percent>(60.0)),
+                    (Pure::Ethanol.into(), Ratio::new::<percent>(40.0)),
+                ]))
+                .unwrap()
+            };
+            let mut cache = HashMap::from([(water_ethanol(), 1)]);
+            assert_eq!(cache.get(&water_ethanol()), Some(&1));
+            let mass_based = CustomMix::mass_based(water_ethanol().components().clone()).unwrap();
+            cache.insert(mass_based.clone(), 2);
+            assert_eq!(cache.len(), 2);
+            assert_eq!(cache[&mass_based], 2);
+        }
+
+        #[test]
+        fn hash_does_not_depend_on_components_order() {
+            let hash = |mix: &CustomMix| {
+                let mut hasher = std::hash::DefaultHasher::new();
+                mix.hash(&mut hasher);
+                hasher.finish()
+            };
+            let components = [
+                (Pure::Water.into(), Ratio::new::<percent>(60.0)),
+                (Pure::Ethanol.into(), Ratio::new::<percent>(40.0)),
+            ];
+            let sut = CustomMix::mole_based(HashMap::from(components)).unwrap();
+            let mut reversed = components;
+            reversed.reverse();
+            let other = CustomMix::mole_based(HashMap::from_iter(reversed)).unwrap();
+            assert_eq!(sut, other);
+            assert_eq!(hash(&sut), hash(&other));
+        }
+
+        #[test]
+        fn into_iter_by_ref_yields_all_components() {
+            let sut = CustomMix::mole_based(HashMap::from([
+                (Pure::Water.into(), Ratio::new::<percent>(60.0)),
+                (Pure::Ethanol.into(), Ratio::new::<percent>(40.0)),
+            ]))
+            .unwrap();
+            let result: HashMap<_, _> = (&sut).into_iter().collect();
+            assert_eq!(result.len(), 2);
+            for (component, fraction) in sut.components() {
+                assert_eq!(result[component], fraction);
+            }
+        }
+
+        #[test]
+        fn into_iter_by_value_yields_stored_fractions() {
+            let sut = CustomMix::mass_based(HashMap::from([
+                (Pure::Water.into(), Ratio::new::<percent>(60.0)),
+                (Pure::Ethanol.into(), Ratio::new::<percent>(40.0)),
+            ]))
+            .unwrap();
+            let expected = sut.components().clone();
+            let result: HashMap<_, _> = sut.into_iter().collect();
+            assert_eq!(result, expected);
+        }
+
+        fn r32_with_molar_mass(molar_mass: f64) -> CustomMixComponent {
+            CustomMixComponent::with_molar_mass(
+                Refrigerant::R32,
+                MolarMass::new::<kilogram_per_mole>(molar_mass),
+            )
+        }
+
+        fn r125_with_molar_mass(molar_mass: f64) -> CustomMixComponent {
+            CustomMixComponent::with_molar_mass(
+                Refrigerant::R125,
+                MolarMass::new::<kilogram_per_mole>(molar_mass),
+            )
+        }
+
         fn matches(mix: CustomMix, expected: [(&str, f64); 2]) -> bool {
             mix.components().len() == expected.len()
                 && mix
@@ -358,8 +1791,136 @@ mod tests {
         }
     }
 
+    mod fractions_conversion {
+        use super::*;
+        use crate::uom::si::molar_mass::gram_per_mole;
+        use crate::uom::si::ratio::percent;
+        use approx::assert_relative_eq;
+
+        fn water_ethanol(fractions: [f64; 2]) -> [(MolarMass, Ratio); 2] {
+            [
+                (
+                    MolarMass::new::<gram_per_mole>(18.015268),
+                    Ratio::new::<percent>(fractions[0]),
+                ),
+                (
+                    MolarMass::new::<gram_per_mole>(46.06844),
+                    Ratio::new::<percent>(fractions[1]),
+                ),
+            ]
+        }
+
+        #[test]
+        fn mole_to_mass_and_back_round_trips() {
+            let components = water_ethanol([80.0, 20.0]);
+            let mass_fractions = mole_to_mass_fractions(&components);
+            assert_relative_eq!(
+                mass_fractions.iter().map(|f| f.value).sum::<f64>(),
+                1.0,
+                epsilon = 1e-12
+            );
+            let result = mass_to_mole_fractions(&water_ethanol([
+                mass_fractions[0].get::<percent>(),
+                mass_fractions[1].get::<percent>(),
+            ]));
+            assert_relative_eq!(result[0].get::<percent>(), 80.0, epsilon = 1e-9);
+            assert_relative_eq!(result[1].get::<percent>(), 20.0, epsilon = 1e-9);
+        }
+
+        #[test]
+        fn mole_to_mass_fractions_matches_custom_mix_conversion() {
+            let sut = CustomMix::mole_based(HashMap::from([
+                (Pure::Water.into(), Ratio::new::<percent>(80.0)),
+                (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+            ]))
+            .unwrap();
+            let result = mole_to_mass_fractions(&water_ethanol([80.0, 20.0]));
+            let mass_based = sut.to_mass_based().unwrap();
+            assert_relative_eq!(
+                mass_based.components()[&Pure::Water.into()].value,
+                result[0].value,
+                max_relative = 1e-6
+            );
+            assert_relative_eq!(
+                mass_based.components()[&Pure::Ethanol.into()].value,
+                result[1].value,
+                max_relative = 1e-6
+            );
+        }
+
+        #[test]
+        fn empty_input_returns_empty_output() {
+            assert!(mole_to_mass_fractions(&[]).is_empty());
+            assert!(mass_to_mole_fractions(&[]).is_empty());
+        }
+    }
+
+    mod custom_mix_builder {
+        use super::*;
+        use crate::uom::si::molar_mass::gram_per_mole;
+        use crate::uom::si::ratio::percent;
+
+        #[test]
+        fn mole_or_mass_based_from_valid_input_returns_ok() {
+            let sut = CustomMix::builder()
+                .with_component(Refrigerant::R32, Ratio::new::<percent>(70.0))
+                .with_component(Refrigerant::R125, Ratio::new::<percent>(30.0));
+            let expected = HashMap::from([
+                (Refrigerant::R32.into(), Ratio::new::<percent>(70.0)),
+                (Refrigerant::R125.into(), Ratio::new::<percent>(30.0)),
+            ]);
+            assert_eq!(
+                sut.clone().mole_based(),
+                CustomMix::mole_based(expected.clone())
+            );
+            assert_eq!(sut.mass_based(), CustomMix::mass_based(expected));
+        }
+
+        #[test]
+        fn with_same_component_sums_fractions() {
+            let sut = CustomMix::builder()
+                .with_component(Pure::Water, Ratio::new::<percent>(40.0))
+                .with_component(Pure::Ethanol, Ratio::new::<percent>(20.0))
+                .with_component(Pure::Water, Ratio::new::<percent>(40.0));
+            let result = sut.mole_based().unwrap();
+            assert_eq!(
+                result.components()[&Pure::Water.into()],
+                Ratio::new::<percent>(80.0)
+            );
+        }
+
+        #[test]
+        fn with_component_with_molar_mass_keeps_it() {
+            let water = CustomMixComponent::with_molar_mass(
+                Pure::Water,
+                MolarMass::new::<gram_per_mole>(18.0),
+            );
+            let sut = CustomMix::builder()
+                .with_component(water, Ratio::new::<percent>(60.0))
+                .with_component(Pure::Ethanol, Ratio::new::<percent>(40.0));
+            let result = sut.mass_based().unwrap();
+            assert_eq!(result.components()[&water], Ratio::new::<percent>(60.0));
+        }
+
+        #[test]
+        fn mole_or_mass_based_from_invalid_input_returns_err() {
+            let sut =
+                CustomMix::builder().with_component(Refrigerant::R32, Ratio::new::<percent>(70.0));
+            assert_eq!(
+                sut.clone().mole_based(),
+                Err(CustomMixError::NotEnoughComponents)
+            );
+            assert_eq!(
+                sut.with_component(Refrigerant::R125, Ratio::new::<percent>(20.0))
+                    .mass_based(),
+                Err(CustomMixError::InvalidFractionsSum)
+            );
+        }
+    }
+
     mod custom_mix_component {
         use super::*;
+        use std::collections::HashSet;
 
         #[test]
         pub fn custom_mix_component_is_transparent() {
@@ -380,5 +1941,53 @@ mod tests {
                 Refrigerant::R32.as_ref()
             );
         }
+
+        #[test]
+        fn with_molar_mass_is_transparent() {
+            let sut = CustomMixComponent::with_molar_mass(
+                Pure::Water,
+                MolarMass::new::<kilogram_per_mole>(0.018),
+            );
+            assert_eq!(sut.backend_name(), Pure::Water.backend_name());
+            assert_eq!(sut.as_ref(), Pure::Water.as_ref());
+            assert_eq!(sut.without_molar_mass(), Pure::Water.into());
+        }
+
+        #[test]
+        fn with_molar_mass_replaces_overridden_molar_mass() {
+            let sut = CustomMixComponent::with_molar_mass(
+                CustomMixComponent::with_molar_mass(
+                    Refrigerant::R32,
+                    MolarMass::new::<kilogram_per_mole>(0.05),
+                ),
+                MolarMass::new::<kilogram_per_mole>(0.06),
+            );
+            assert_eq!(
+                sut,
+                CustomMixComponent::RefrigerantWithMolarMass(
+                    Refrigerant::R32,
+                    MolarMass::new::<kilogram_per_mole>(0.06)
+                )
+            );
+            assert_eq!(
+                sut.overridden_molar_mass(),
+                Some(MolarMass::new::<kilogram_per_mole>(0.06))
+            );
+        }
+
+        #[test]
+        fn with_molar_mass_differs_from_plain_component() {
+            let sut = CustomMixComponent::with_molar_mass(
+                Refrigerant::R32,
+                MolarMass::new::<kilogram_per_mole>(0.052),
+            );
+            let nan = CustomMixComponent::with_molar_mass(
+                Refrigerant::R32,
+                MolarMass::new::<kilogram_per_mole>(f64::NAN),
+            );
+            assert_ne!(sut, Refrigerant::R32.into());
+            assert_eq!(nan, nan);
+            assert_eq!(HashSet::from([sut, sut, Refrigerant::R32.into()]).len(), 2);
+        }
     }
 }
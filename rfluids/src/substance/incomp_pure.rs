@@ -1,7 +1,11 @@
 use crate::substance::BackendName;
+use crate::uom::si::f64::ThermodynamicTemperature;
+use crate::uom::si::thermodynamic_temperature::kelvin;
+use std::str::FromStr;
+use strum::EnumProperty;
 #[cfg(test)]
 use strum_macros::EnumIter;
-use strum_macros::{AsRefStr, EnumString};
+use strum_macros::{AsRefStr, EnumProperty, EnumString};
 
 /// CoolProp incompressible pure substances.
 ///
@@ -22,191 +26,414 @@ use strum_macros::{AsRefStr, EnumString};
 ///
 /// - [Incompressible substances](https://coolprop.github.io/CoolProp/fluid_properties/Incomps.html)
 //noinspection SpellCheckingInspection
-#[derive(AsRefStr, EnumString, Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(AsRefStr, EnumString, EnumProperty, Debug, Copy, Clone, Eq, PartialEq)]
 #[strum(ascii_case_insensitive)]
 #[cfg_attr(test, derive(EnumIter))]
 pub enum IncompPure {
-    #[strum(to_string = "AS10")]
+    #[strum(
+        to_string = "AS10",
+        props(min_temperature = "263.15", max_temperature = "303.15")
+    )]
     AS10,
 
-    #[strum(to_string = "AS20")]
+    #[strum(
+        to_string = "AS20",
+        props(min_temperature = "253.15", max_temperature = "303.15")
+    )]
     AS20,
 
-    #[strum(to_string = "AS30")]
+    #[strum(
+        to_string = "AS30",
+        props(min_temperature = "243.15", max_temperature = "303.15")
+    )]
     AS30,
 
-    #[strum(to_string = "AS40")]
+    #[strum(
+        to_string = "AS40",
+        props(min_temperature = "233.15", max_temperature = "303.15")
+    )]
     AS40,
 
-    #[strum(to_string = "AS55")]
+    #[strum(
+        to_string = "AS55",
+        props(min_temperature = "218.15", max_temperature = "303.15")
+    )]
     AS55,
 
-    #[strum(to_string = "DEB")]
+    #[strum(
+        to_string = "DEB",
+        props(min_temperature = "193.15", max_temperature = "373.15")
+    )]
     DEB,
 
-    #[strum(to_string = "DowJ")]
+    #[strum(
+        to_string = "DowJ",
+        props(min_temperature = "193.15", max_temperature = "618.15")
+    )]
     DowJ,
 
-    #[strum(to_string = "DowJ2")]
+    #[strum(
+        to_string = "DowJ2",
+        props(min_temperature = "200.15", max_temperature = "588.15")
+    )]
     DowJ2,
 
-    #[strum(to_string = "DowQ")]
+    #[strum(
+        to_string = "DowQ",
+        props(min_temperature = "238.15", max_temperature = "633.15")
+    )]
     DowQ,
 
-    #[strum(to_string = "DowQ2")]
+    #[strum(
+        to_string = "DowQ2",
+        props(min_temperature = "238.15", max_temperature = "603.15")
+    )]
     DowQ2,
 
-    #[strum(to_string = "DSF")]
+    #[strum(
+        to_string = "DSF",
+        props(min_temperature = "273.15", max_temperature = "588.15")
+    )]
     DSF,
 
-    #[strum(to_string = "HC10")]
+    #[strum(
+        to_string = "HC10",
+        props(min_temperature = "263.15", max_temperature = "491.15")
+    )]
     HC10,
 
-    #[strum(to_string = "HC20")]
+    #[strum(
+        to_string = "HC20",
+        props(min_temperature = "253.15", max_temperature = "483.15")
+    )]
     HC20,
 
-    #[strum(to_string = "HC30")]
+    #[strum(
+        to_string = "HC30",
+        props(min_temperature = "243.15", max_temperature = "483.15")
+    )]
     HC30,
 
-    #[strum(to_string = "HC40")]
+    #[strum(
+        to_string = "HC40",
+        props(min_temperature = "233.15", max_temperature = "473.15")
+    )]
     HC40,
 
-    #[strum(to_string = "HC50")]
+    #[strum(
+        to_string = "HC50",
+        props(min_temperature = "223.15", max_temperature = "483.15")
+    )]
     HC50,
 
-    #[strum(to_string = "HCB")]
+    #[strum(
+        to_string = "HCB",
+        props(min_temperature = "193.15", max_temperature = "373.15")
+    )]
     HCB,
 
-    #[strum(to_string = "HCM")]
+    #[strum(
+        to_string = "HCM",
+        props(min_temperature = "193.15", max_temperature = "373.15")
+    )]
     HCM,
 
-    #[strum(to_string = "HFE")]
+    #[strum(
+        to_string = "HFE",
+        props(min_temperature = "193.15", max_temperature = "373.15")
+    )]
     HFE,
 
-    #[strum(to_string = "HFE2")]
+    #[strum(
+        to_string = "HFE2",
+        props(min_temperature = "192.82", max_temperature = "337.42")
+    )]
     HFE2,
 
-    #[strum(to_string = "HY20")]
+    #[strum(
+        to_string = "HY20",
+        props(min_temperature = "253.15", max_temperature = "323.15")
+    )]
     HY20,
 
-    #[strum(to_string = "HY30")]
+    #[strum(
+        to_string = "HY30",
+        props(min_temperature = "243.15", max_temperature = "323.15")
+    )]
     HY30,
 
-    #[strum(to_string = "HY40")]
+    #[strum(
+        to_string = "HY40",
+        props(min_temperature = "233.15", max_temperature = "293.15")
+    )]
     HY40,
 
-    #[strum(to_string = "HY45")]
+    #[strum(
+        to_string = "HY45",
+        props(min_temperature = "228.15", max_temperature = "293.15")
+    )]
     HY45,
 
-    #[strum(to_string = "HY50")]
+    #[strum(
+        to_string = "HY50",
+        props(min_temperature = "223.15", max_temperature = "293.15")
+    )]
     HY50,
 
-    #[strum(to_string = "NaK")]
+    #[strum(
+        to_string = "NaK",
+        props(min_temperature = "573.15", max_temperature = "873.15")
+    )]
     NaK,
 
-    #[strum(to_string = "NBS")]
+    #[strum(
+        to_string = "NBS",
+        props(min_temperature = "274.15", max_temperature = "373.15")
+    )]
     NBS,
 
-    #[strum(to_string = "PBB")]
+    #[strum(
+        to_string = "PBB",
+        props(min_temperature = "323.15", max_temperature = "573.15")
+    )]
     PBB,
 
-    #[strum(to_string = "PCL")]
+    #[strum(
+        to_string = "PCL",
+        props(min_temperature = "233.15", max_temperature = "453.15")
+    )]
     PCL,
 
-    #[strum(to_string = "PCR")]
+    #[strum(
+        to_string = "PCR",
+        props(min_temperature = "173.15", max_temperature = "493.15")
+    )]
     PCR,
 
-    #[strum(to_string = "PGLT")]
+    #[strum(
+        to_string = "PGLT",
+        props(min_temperature = "258.15", max_temperature = "588.15")
+    )]
     PGLT,
 
-    #[strum(to_string = "PHE")]
+    #[strum(
+        to_string = "PHE",
+        props(min_temperature = "273.15", max_temperature = "603.15")
+    )]
     PHE,
 
-    #[strum(to_string = "PHR")]
+    #[strum(
+        to_string = "PHR",
+        props(min_temperature = "258.15", max_temperature = "643.15")
+    )]
     PHR,
 
-    #[strum(to_string = "PLR")]
+    #[strum(
+        to_string = "PLR",
+        props(min_temperature = "188.15", max_temperature = "503.15")
+    )]
     PLR,
 
-    #[strum(to_string = "PMR")]
+    #[strum(
+        to_string = "PMR",
+        props(min_temperature = "233.15", max_temperature = "588.15")
+    )]
     PMR,
 
-    #[strum(to_string = "PMS1")]
+    #[strum(
+        to_string = "PMS1",
+        props(min_temperature = "193.15", max_temperature = "373.15")
+    )]
     PMS1,
 
-    #[strum(to_string = "PMS2")]
+    #[strum(
+        to_string = "PMS2",
+        props(min_temperature = "193.15", max_temperature = "373.15")
+    )]
     PMS2,
 
-    #[strum(to_string = "PNF")]
+    #[strum(
+        to_string = "PNF",
+        props(min_temperature = "263.15", max_temperature = "588.15")
+    )]
     PNF,
 
-    #[strum(to_string = "PNF2")]
+    #[strum(
+        to_string = "PNF2",
+        props(min_temperature = "263.15", max_temperature = "593.15")
+    )]
     PNF2,
 
-    #[strum(to_string = "S800")]
+    #[strum(
+        to_string = "S800",
+        props(min_temperature = "233.15", max_temperature = "671.15")
+    )]
     S800,
 
-    #[strum(to_string = "SAB")]
+    #[strum(
+        to_string = "SAB",
+        props(min_temperature = "193.15", max_temperature = "373.15")
+    )]
     SAB,
 
-    #[strum(to_string = "T66")]
+    #[strum(
+        to_string = "T66",
+        props(min_temperature = "273.15", max_temperature = "653.15")
+    )]
     T66,
 
-    #[strum(to_string = "T72")]
+    #[strum(
+        to_string = "T72",
+        props(min_temperature = "263.15", max_temperature = "653.15")
+    )]
     T72,
 
-    #[strum(to_string = "TCO")]
+    #[strum(
+        to_string = "TCO",
+        props(min_temperature = "193.15", max_temperature = "373.15")
+    )]
     TCO,
 
-    #[strum(to_string = "TD12")]
+    #[strum(
+        to_string = "TD12",
+        props(min_temperature = "188.15", max_temperature = "503.15")
+    )]
     TD12,
 
-    #[strum(to_string = "TVP1")]
+    #[strum(
+        to_string = "TVP1",
+        props(min_temperature = "285.15", max_temperature = "670.15")
+    )]
     TVP1,
 
-    #[strum(to_string = "TVP1869")]
+    #[strum(
+        to_string = "TVP1869",
+        props(min_temperature = "193.15", max_temperature = "293.15")
+    )]
     TVP1869,
 
-    #[strum(to_string = "TX22")]
+    #[strum(
+        to_string = "TX22",
+        props(min_temperature = "273.15", max_temperature = "623.15")
+    )]
     TX22,
 
-    #[strum(to_string = "TY10")]
+    #[strum(
+        to_string = "TY10",
+        props(min_temperature = "263.15", max_temperature = "313.15")
+    )]
     TY10,
 
-    #[strum(to_string = "TY15")]
+    #[strum(
+        to_string = "TY15",
+        props(min_temperature = "253.15", max_temperature = "313.15")
+    )]
     TY15,
 
-    #[strum(to_string = "TY20")]
+    #[strum(
+        to_string = "TY20",
+        props(min_temperature = "233.15", max_temperature = "313.15")
+    )]
     TY20,
 
-    #[strum(to_string = "TY24")]
+    #[strum(
+        to_string = "TY24",
+        props(min_temperature = "218.15", max_temperature = "313.15")
+    )]
     TY24,
 
-    #[strum(to_string = "Water", serialize = "H2O")]
+    #[strum(
+        to_string = "Water",
+        serialize = "H2O",
+        props(min_temperature = "273.15", max_temperature = "473.15")
+    )]
     Water,
 
-    #[strum(to_string = "XLT")]
+    #[strum(
+        to_string = "XLT",
+        props(min_temperature = "173.15", max_temperature = "533.15")
+    )]
     XLT,
 
-    #[strum(to_string = "XLT2")]
+    #[strum(
+        to_string = "XLT2",
+        props(min_temperature = "173.15", max_temperature = "533.15")
+    )]
     XLT2,
 
-    #[strum(to_string = "ZS10")]
+    #[strum(
+        to_string = "ZS10",
+        props(min_temperature = "265.15", max_temperature = "363.15")
+    )]
     ZS10,
 
-    #[strum(to_string = "ZS25")]
+    #[strum(
+        to_string = "ZS25",
+        props(min_temperature = "250.15", max_temperature = "363.15")
+    )]
     ZS25,
 
-    #[strum(to_string = "ZS40")]
+    #[strum(
+        to_string = "ZS40",
+        props(min_temperature = "235.15", max_temperature = "363.15")
+    )]
     ZS40,
 
-    #[strum(to_string = "ZS45")]
+    #[strum(
+        to_string = "ZS45",
+        props(min_temperature = "230.15", max_temperature = "363.15")
+    )]
     ZS45,
 
-    #[strum(to_string = "ZS55")]
+    #[strum(
+        to_string = "ZS55",
+        props(min_temperature = "218.15", max_temperature = "363.15")
+    )]
     ZS55,
 }
 
+impl IncompPure {
+    /// Minimum possible temperature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::IncompPure;
+    /// use rfluids::uom::si::f64::ThermodynamicTemperature;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// assert_eq!(
+    ///     IncompPure::Water.min_temperature(),
+    ///     ThermodynamicTemperature::new::<degree_celsius>(0.0)
+    /// );
+    /// ```
+    pub fn min_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(
+            f64::from_str(self.get_str("min_temperature").unwrap()).unwrap(),
+        )
+    }
+
+    /// Maximum possible temperature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::IncompPure;
+    /// use rfluids::uom::si::f64::ThermodynamicTemperature;
+    /// use rfluids::uom::si::thermodynamic_temperature::degree_celsius;
+    ///
+    /// assert_eq!(
+    ///     IncompPure::Water.max_temperature(),
+    ///     ThermodynamicTemperature::new::<degree_celsius>(200.0)
+    /// );
+    /// ```
+    pub fn max_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<kelvin>(
+            f64::from_str(self.get_str("max_temperature").unwrap()).unwrap(),
+        )
+    }
+}
+
 impl BackendName for IncompPure {
     fn backend_name(&self) -> &'static str {
         "INCOMP"
@@ -217,8 +444,10 @@ impl BackendName for IncompPure {
 mod tests {
     use super::IncompPure::*;
     use super::*;
+    use crate::io::FluidTrivialParam;
+    use crate::native::AbstractState;
+    use approx::assert_relative_eq;
     use rstest::*;
-    use std::str::FromStr;
     use strum::IntoEnumIterator;
 
     #[test]
@@ -228,6 +457,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn temperature_limits_match_coolprop() {
+        for substance in IncompPure::iter() {
+            let backend = AbstractState::new(substance.backend_name(), substance.as_ref()).unwrap();
+            assert_relative_eq!(
+                substance.min_temperature().value,
+                backend.keyed_output(FluidTrivialParam::TMin).unwrap(),
+                max_relative = 1e-9
+            );
+            assert_relative_eq!(
+                substance.max_temperature().value,
+                backend.keyed_output(FluidTrivialParam::TMax).unwrap(),
+                max_relative = 1e-9
+            );
+            assert!(substance.min_temperature() < substance.max_temperature());
+        }
+    }
+
     //noinspection SpellCheckingInspection
     #[rstest]
     #[case(AS10, "AS10")]
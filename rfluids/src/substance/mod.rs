@@ -16,6 +16,12 @@ mod predefined_mix;
 mod pure;
 mod refrigerant;
 
+use crate::error::{CoolPropError, SubstanceError};
+use crate::uom::si::f64::Ratio;
+use crate::uom::si::ratio::ratio;
+use std::borrow::Cow;
+use std::str::FromStr;
+
 /// CoolProp backend name.
 pub trait BackendName {
     /// Returns CoolProp backend name.
@@ -55,7 +61,8 @@ pub trait BackendName {
 ///  - [`Refrigerant`]
 ///  - [`PredefinedMix`]
 ///  - [`BinaryMix`]
-#[derive(Debug, Copy, Clone, PartialEq)]
+///  - [`CustomMix`]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Substance {
     /// Pure or pseudo-pure substance.
     Pure(Pure),
@@ -71,6 +78,111 @@ pub enum Substance {
 
     /// Incompressible binary mixture _(mass-based or volume-based)_.
     BinaryMix(BinaryMix),
+
+    /// Custom mixture _(mole-based or mass-based)_.
+    CustomMix(CustomMix),
+}
+
+impl Substance {
+    /// CoolProp name
+    /// _(for custom mixtures -- names of the components separated by the `&` symbol)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{CustomMix, Pure, Substance};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// assert_eq!(Substance::from(Pure::Water).name(), "Water");
+    /// assert_eq!(
+    ///     Substance::from(
+    ///         CustomMix::mole_based(HashMap::from([
+    ///             (Pure::Water.into(), Ratio::new::<percent>(80.0)),
+    ///             (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+    ///         ]))
+    ///         .unwrap()
+    ///     )
+    ///     .name(),
+    ///     "Ethanol&Water"
+    /// );
+    /// ```
+    pub fn name(&self) -> Cow<'_, str> {
+        match self {
+            Substance::Pure(pure) => pure.as_ref().into(),
+            Substance::IncompPure(incomp_pure) => incomp_pure.as_ref().into(),
+            Substance::Refrigerant(refrigerant) => refrigerant.as_ref().into(),
+            Substance::PredefinedMix(predefined_mix) => predefined_mix.as_ref().into(),
+            Substance::BinaryMix(binary_mix) => binary_mix.kind.as_ref().into(),
+            Substance::CustomMix(custom_mix) => custom_mix.name().into(),
+        }
+    }
+
+    /// Full CoolProp name, including the backend prefix and the fractions
+    /// _(if any)_, which can be used directly in the CoolProp high-level API
+    /// _(e.g., `"HEOS::Water"`, `"INCOMP::MPG[0.4]"`
+    /// or `"HEOS::Ethanol[0.2]&Water[0.8]"`)_.
+    ///
+    /// For binary mixtures, the fraction is specified in the CoolProp notation
+    /// _(from 0 to 1 in square brackets)_.
+    /// For custom mixtures, the mole fractions are specified for each component.
+    ///
+    /// # Errors
+    ///
+    /// If the mass fractions of the custom mixture can't be converted to mole fractions
+    /// _(see [`CustomMix::to_mole_based`])_, a [`CoolPropError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{BinaryMix, BinaryMixKind, CustomMix, Pure, Substance};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    /// use std::collections::HashMap;
+    ///
+    /// assert_eq!(Substance::from(Pure::Water).coolprop_name().unwrap(), "HEOS::Water");
+    /// assert_eq!(
+    ///     Substance::from(
+    ///         BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap()
+    ///     )
+    ///     .coolprop_name()
+    ///     .unwrap(),
+    ///     "INCOMP::MPG[0.4]"
+    /// );
+    /// assert_eq!(
+    ///     Substance::from(
+    ///         CustomMix::mole_based(HashMap::from([
+    ///             (Pure::Water.into(), Ratio::new::<percent>(80.0)),
+    ///             (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+    ///         ]))
+    ///         .unwrap()
+    ///     )
+    ///     .coolprop_name()
+    ///     .unwrap(),
+    ///     "HEOS::Ethanol[0.2]&Water[0.8]"
+    /// );
+    /// ```
+    pub fn coolprop_name(&self) -> Result<String, CoolPropError> {
+        let name = match self {
+            Substance::BinaryMix(binary_mix) => {
+                format!(
+                    "{}[{}]",
+                    binary_mix.kind.as_ref(),
+                    binary_mix.fraction.value
+                )
+            }
+            Substance::CustomMix(custom_mix) => custom_mix
+                .name()
+                .split('&')
+                .zip(custom_mix.mole_fractions()?)
+                .map(|(name, fraction)| format!("{name}[{fraction}]"))
+                .collect::<Vec<_>>()
+                .join("&"),
+            _ => self.name().into_owned(),
+        };
+        Ok(format!("{}::{name}", self.backend_name()))
+    }
 }
 
 impl BackendName for Substance {
@@ -81,18 +193,7 @@ impl BackendName for Substance {
             Substance::Refrigerant(refrigerant) => refrigerant.backend_name(),
             Substance::PredefinedMix(predefined_mix) => predefined_mix.backend_name(),
             Substance::BinaryMix(binary_mix) => binary_mix.kind.backend_name(),
-        }
-    }
-}
-
-impl AsRef<str> for Substance {
-    fn as_ref(&self) -> &str {
-        match self {
-            Substance::Pure(pure) => pure.as_ref(),
-            Substance::IncompPure(incomp_pure) => incomp_pure.as_ref(),
-            Substance::Refrigerant(refrigerant) => refrigerant.as_ref(),
-            Substance::PredefinedMix(predefined_mix) => predefined_mix.as_ref(),
-            Substance::BinaryMix(binary_mix) => binary_mix.kind.as_ref(),
+            Substance::CustomMix(custom_mix) => custom_mix.backend_name(),
         }
     }
 }
@@ -127,10 +228,80 @@ impl From<BinaryMix> for Substance {
     }
 }
 
+impl From<CustomMix> for Substance {
+    fn from(value: CustomMix) -> Self {
+        Self::CustomMix(value)
+    }
+}
+
+impl TryFrom<&str> for Substance {
+    type Error = SubstanceError;
+
+    /// Parses the substance name _(case-insensitive)_,
+    /// trying [`Pure`], [`IncompPure`], [`Refrigerant`], [`PredefinedMix`]
+    /// and [`BinaryMix`] in order and returning the first match.
+    ///
+    /// Binary mixtures are specified in the CoolProp notation --
+    /// the kind followed by the fraction _(from 0 to 1)_ in square brackets
+    /// _(e.g., `"MPG[0.4]"`)_.
+    ///
+    /// # Errors
+    ///
+    /// If there is no substance with the specified name,
+    /// a [`SubstanceError::NotFound`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{BinaryMix, BinaryMixKind, Pure, Refrigerant, Substance};
+    /// use rfluids::uom::si::f64::Ratio;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// assert_eq!(Substance::try_from("water"), Ok(Pure::Water.into()));
+    /// assert_eq!(Substance::try_from("R32"), Ok(Refrigerant::R32.into()));
+    /// assert_eq!(
+    ///     Substance::try_from("MPG[0.4]"),
+    ///     Ok(BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0))
+    ///         .unwrap()
+    ///         .into())
+    /// );
+    /// assert!(Substance::try_from("Hello, World!").is_err());
+    /// ```
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let name = value.trim();
+        Pure::from_str(name)
+            .map(Self::from)
+            .or_else(|_| IncompPure::from_str(name).map(Self::from))
+            .or_else(|_| Refrigerant::from_str(name).map(Self::from))
+            .or_else(|_| PredefinedMix::from_str(name).map(Self::from))
+            .ok()
+            .or_else(|| parse_binary_mix(name).map(Self::from))
+            .ok_or_else(|| SubstanceError::NotFound(value.into()))
+    }
+}
+
+impl FromStr for Substance {
+    type Err = SubstanceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+fn parse_binary_mix(name: &str) -> Option<BinaryMix> {
+    let (kind, fraction) = name.strip_suffix(']')?.split_once('[')?;
+    let kind = BinaryMixKind::from_str(kind.trim()).ok()?;
+    let fraction = fraction.trim().parse::<f64>().ok()?;
+    BinaryMix::try_new(kind, Ratio::new::<ratio>(fraction)).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::native::CoolProp;
+    use crate::uom::si::ratio::percent;
     use rstest::*;
+    use std::collections::HashMap;
     use strum::IntoEnumIterator;
 
     #[fixture]
@@ -142,36 +313,118 @@ mod tests {
             .chain(PredefinedMix::iter().map(Substance::from))
             .chain(BinaryMixKind::iter().map(|kind| {
                 Substance::from(
-                    BinaryMix::try_from(kind, 0.5 * (kind.min_fraction() + kind.max_fraction()))
+                    BinaryMix::try_new(kind, 0.5 * (kind.min_fraction() + kind.max_fraction()))
                         .unwrap(),
                 )
             }))
+            .chain([Substance::from(
+                CustomMix::mole_based(HashMap::from([
+                    (Pure::Water.into(), Ratio::new::<percent>(80.0)),
+                    (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+                ]))
+                .unwrap(),
+            )])
             .collect()
     }
 
+    #[rstest]
+    #[case("Water", Pure::Water.into())]
+    #[case(" WATER ", Pure::Water.into())]
+    #[case("DowQ", IncompPure::DowQ.into())]
+    #[case("r32", Refrigerant::R32.into())]
+    #[case("Air.Mix", PredefinedMix::Air.into())]
+    #[case(
+        "mpg[0.4]",
+        BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap().into()
+    )]
+    fn try_from_valid_name_returns_ok(#[case] name: &str, #[case] expected: Substance) {
+        assert_eq!(Substance::try_from(name), Ok(expected.clone()));
+        assert_eq!(Substance::from_str(name), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("Hello, World!")]
+    #[case("MPG")]
+    #[case("MPG[1.5]")]
+    #[case("MPG[abc]")]
+    #[case("Water[0.4]")]
+    fn try_from_invalid_name_returns_err(#[case] name: &str) {
+        assert_eq!(
+            Substance::try_from(name),
+            Err(SubstanceError::NotFound(name.into()))
+        );
+    }
+
+    #[rstest]
+    fn try_from_name_of_each_substance_returns_ok(all_substances: Vec<Substance>) {
+        for substance in all_substances {
+            match &substance {
+                Substance::BinaryMix(_) | Substance::CustomMix(_) => {}
+                _ => assert!(Substance::try_from(substance.name().as_ref()).is_ok()),
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(Pure::Water.into(), "HEOS::Water")]
+    #[case(IncompPure::DowQ.into(), "INCOMP::DowQ")]
+    #[case(Refrigerant::R32.into(), "HEOS::R32")]
+    #[case(PredefinedMix::Air.into(), "HEOS::Air.mix")]
+    #[case(
+        BinaryMix::try_new(BinaryMixKind::MPG, Ratio::new::<percent>(40.0)).unwrap().into(),
+        "INCOMP::MPG[0.4]"
+    )]
+    #[case(
+        CustomMix::mole_based(HashMap::from([
+            (Pure::Water.into(), Ratio::new::<percent>(80.0)),
+            (Pure::Ethanol.into(), Ratio::new::<percent>(20.0)),
+        ]))
+        .unwrap()
+        .into(),
+        "HEOS::Ethanol[0.2]&Water[0.8]"
+    )]
+    fn coolprop_name_returns_expected_value(#[case] substance: Substance, #[case] expected: &str) {
+        assert_eq!(substance.coolprop_name().unwrap(), expected);
+    }
+
+    #[rstest]
+    fn coolprop_name_of_each_substance_is_valid(all_substances: Vec<Substance>) {
+        for substance in all_substances {
+            let name = substance.coolprop_name().unwrap();
+            assert!(
+                CoolProp::props_si("Tmin", "", 0.0, "", 0.0, &name).is_ok(),
+                "{name}"
+            );
+        }
+    }
+
     #[rstest]
     fn substance_is_transparent(all_substances: Vec<Substance>) {
         for substance in all_substances {
-            match substance {
+            match &substance {
                 Substance::Pure(pure) => {
                     assert_eq!(substance.backend_name(), pure.backend_name());
-                    assert_eq!(substance.as_ref(), pure.as_ref());
+                    assert_eq!(substance.name(), pure.as_ref());
                 }
                 Substance::IncompPure(incomp_pure) => {
                     assert_eq!(substance.backend_name(), incomp_pure.backend_name());
-                    assert_eq!(substance.as_ref(), incomp_pure.as_ref());
+                    assert_eq!(substance.name(), incomp_pure.as_ref());
                 }
                 Substance::Refrigerant(refrigerant) => {
                     assert_eq!(substance.backend_name(), refrigerant.backend_name());
-                    assert_eq!(substance.as_ref(), refrigerant.as_ref());
+                    assert_eq!(substance.name(), refrigerant.as_ref());
                 }
                 Substance::PredefinedMix(predefined_mix) => {
                     assert_eq!(substance.backend_name(), predefined_mix.backend_name());
-                    assert_eq!(substance.as_ref(), predefined_mix.as_ref());
+                    assert_eq!(substance.name(), predefined_mix.as_ref());
                 }
                 Substance::BinaryMix(binary_mix) => {
                     assert_eq!(substance.backend_name(), binary_mix.kind.backend_name());
-                    assert_eq!(substance.as_ref(), binary_mix.kind.as_ref());
+                    assert_eq!(substance.name(), binary_mix.kind.as_ref());
+                }
+                Substance::CustomMix(custom_mix) => {
+                    assert_eq!(substance.backend_name(), custom_mix.backend_name());
+                    assert_eq!(substance.name(), "Ethanol&Water");
                 }
             }
         }
@@ -1,4 +1,6 @@
-use crate::substance::BackendName;
+use crate::substance::{BackendName, Pure};
+use crate::uom::si::f64::Ratio;
+use std::marker::PhantomData;
 #[cfg(test)]
 use strum_macros::EnumIter;
 use strum_macros::{AsRefStr, EnumString};
@@ -64,16 +66,140 @@ pub enum PredefinedMix {
     TypicalNaturalGas,
 }
 
+impl PredefinedMix {
+    /// Components and their mole fractions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::substance::{PredefinedMix, Pure};
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let components = PredefinedMix::Air.components();
+    /// assert_eq!(components.len(), 3);
+    /// assert_eq!(components[0].0, Pure::Nitrogen);
+    /// assert_relative_eq!(components[0].1.get::<percent>(), 78.12);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Predefined mixtures](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#predefined-mixtures)
+    /// - [`Refrigerant::components`](crate::substance::Refrigerant::components)
+    ///   _(components and mass fractions of refrigerant blends)_
+    pub fn components(&self) -> &'static [(Pure, Ratio)] {
+        match self {
+            PredefinedMix::Air => AIR,
+            PredefinedMix::Amarillo => AMARILLO,
+            PredefinedMix::Ekofisk => EKOFISK,
+            PredefinedMix::GulfCoast | PredefinedMix::GulfCoastGasNIST => GULF_COAST,
+            PredefinedMix::HighCO2 => HIGH_CO2,
+            PredefinedMix::HighN2 => HIGH_N2,
+            PredefinedMix::NaturalGasSample | PredefinedMix::TypicalNaturalGas => {
+                NATURAL_GAS_SAMPLE
+            }
+        }
+    }
+}
+
 impl BackendName for PredefinedMix {
     fn backend_name(&self) -> &'static str {
         "HEOS"
     }
 }
 
+const fn mole_fraction(value: f64) -> Ratio {
+    Ratio {
+        dimension: PhantomData,
+        units: PhantomData,
+        value,
+    }
+}
+
+const AIR: &[(Pure, Ratio)] = &[
+    (Pure::Nitrogen, mole_fraction(0.7812)),
+    (Pure::Argon, mole_fraction(0.0092)),
+    (Pure::Oxygen, mole_fraction(0.2096)),
+];
+
+const AMARILLO: &[(Pure, Ratio)] = &[
+    (Pure::Methane, mole_fraction(0.906724)),
+    (Pure::Nitrogen, mole_fraction(0.031284)),
+    (Pure::CarbonDioxide, mole_fraction(0.004676)),
+    (Pure::Ethane, mole_fraction(0.045279)),
+    (Pure::nPropane, mole_fraction(0.00828)),
+    (Pure::Isobutane, mole_fraction(0.001037)),
+    (Pure::nButane, mole_fraction(0.001563)),
+    (Pure::Isopentane, mole_fraction(0.000321)),
+    (Pure::nPentane, mole_fraction(0.000443)),
+    (Pure::nHexane, mole_fraction(0.000393)),
+];
+
+const EKOFISK: &[(Pure, Ratio)] = &[
+    (Pure::Methane, mole_fraction(0.859063)),
+    (Pure::Nitrogen, mole_fraction(0.010068)),
+    (Pure::CarbonDioxide, mole_fraction(0.014954)),
+    (Pure::Ethane, mole_fraction(0.084919)),
+    (Pure::nPropane, mole_fraction(0.023015)),
+    (Pure::Isobutane, mole_fraction(0.003486)),
+    (Pure::nButane, mole_fraction(0.003506)),
+    (Pure::Isopentane, mole_fraction(0.000509)),
+    (Pure::nPentane, mole_fraction(0.00048)),
+];
+
+const GULF_COAST: &[(Pure, Ratio)] = &[
+    (Pure::Methane, mole_fraction(0.965222)),
+    (Pure::Nitrogen, mole_fraction(0.002595)),
+    (Pure::CarbonDioxide, mole_fraction(0.005956)),
+    (Pure::Ethane, mole_fraction(0.018186)),
+    (Pure::nPropane, mole_fraction(0.004596)),
+    (Pure::Isobutane, mole_fraction(0.000977)),
+    (Pure::nButane, mole_fraction(0.001007)),
+    (Pure::Isopentane, mole_fraction(0.000473)),
+    (Pure::nPentane, mole_fraction(0.000324)),
+    (Pure::nHexane, mole_fraction(0.000664)),
+];
+
+const HIGH_CO2: &[(Pure, Ratio)] = &[
+    (Pure::Methane, mole_fraction(0.81212)),
+    (Pure::Nitrogen, mole_fraction(0.05702)),
+    (Pure::CarbonDioxide, mole_fraction(0.07585)),
+    (Pure::Ethane, mole_fraction(0.04303)),
+    (Pure::nPropane, mole_fraction(0.00895)),
+    (Pure::Isobutane, mole_fraction(0.00151)),
+    (Pure::nButane, mole_fraction(0.00152)),
+];
+
+const HIGH_N2: &[(Pure, Ratio)] = &[
+    (Pure::Methane, mole_fraction(0.81441)),
+    (Pure::Nitrogen, mole_fraction(0.13465)),
+    (Pure::CarbonDioxide, mole_fraction(0.00985)),
+    (Pure::Ethane, mole_fraction(0.033)),
+    (Pure::nPropane, mole_fraction(0.00605)),
+    (Pure::Isobutane, mole_fraction(0.001)),
+    (Pure::nButane, mole_fraction(0.00104)),
+];
+
+const NATURAL_GAS_SAMPLE: &[(Pure, Ratio)] = &[
+    (Pure::Methane, mole_fraction(0.95123)),
+    (Pure::Nitrogen, mole_fraction(0.00089)),
+    (Pure::CarbonDioxide, mole_fraction(0.02555)),
+    (Pure::Ethane, mole_fraction(0.01835)),
+    (Pure::nPropane, mole_fraction(0.00238)),
+    (Pure::Isobutane, mole_fraction(0.0004)),
+    (Pure::nButane, mole_fraction(0.00016)),
+    (Pure::Isopentane, mole_fraction(0.00014)),
+    (Pure::nPentane, mole_fraction(0.00011)),
+    (Pure::nHexane, mole_fraction(0.00079)),
+];
+
 #[cfg(test)]
 mod tests {
     use super::PredefinedMix::*;
     use super::*;
+    use crate::fluid::Fluid;
+    use crate::io::FluidTrivialParam;
+    use approx::assert_relative_eq;
     use rstest::*;
     use std::str::FromStr;
     use strum::IntoEnumIterator;
@@ -85,6 +211,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn components_fractions_sum_to_one() {
+        for substance in PredefinedMix::iter() {
+            let sum: f64 = substance.components().iter().map(|c| c.1.value).sum();
+            assert_relative_eq!(sum, 1.0, max_relative = 1e-6);
+        }
+    }
+
+    #[test]
+    fn components_are_consistent_with_coolprop() {
+        for substance in PredefinedMix::iter() {
+            let expected: f64 = substance
+                .components()
+                .iter()
+                .map(|c| {
+                    c.1.value
+                        * Fluid::from(c.0)
+                            .trivial_output(FluidTrivialParam::MolarMass)
+                            .unwrap()
+                })
+                .sum();
+            assert_relative_eq!(
+                Fluid::from(substance)
+                    .trivial_output(FluidTrivialParam::MolarMass)
+                    .unwrap(),
+                expected,
+                max_relative = 1e-6
+            );
+        }
+    }
+
     //noinspection SpellCheckingInspection
     #[rstest]
     #[case(Air, "Air.mix")]
@@ -1,7 +1,9 @@
 use crate::substance::BackendName;
-#[cfg(test)]
-use strum_macros::EnumIter;
-use strum_macros::{AsRefStr, EnumString};
+use crate::uom::si::f64::MolarMass;
+use crate::uom::si::molar_mass::kilogram_per_mole;
+use std::str::FromStr;
+use strum::{EnumProperty, IntoEnumIterator};
+use strum_macros::{AsRefStr, EnumIter, EnumProperty, EnumString};
 
 /// CoolProp pure or pseudo-pure substances.
 ///
@@ -22,161 +24,331 @@ use strum_macros::{AsRefStr, EnumString};
 ///
 /// - [Pure and pseudo-pure substances](https://coolprop.github.io/CoolProp/fluid_properties/PurePseudoPure.html)
 //noinspection SpellCheckingInspection
-#[derive(AsRefStr, EnumString, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(AsRefStr, EnumString, EnumProperty, EnumIter, Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[strum(ascii_case_insensitive)]
-#[cfg_attr(test, derive(EnumIter))]
 pub enum Pure {
-    #[strum(to_string = "Acetone")]
+    #[strum(to_string = "Acetone", props(molar_mass = "0.05807914"))]
     Acetone,
 
-    #[strum(to_string = "Air")]
+    #[strum(to_string = "Air", props(molar_mass = "0.02896546"))]
     Air,
 
-    #[strum(to_string = "Ammonia", serialize = "NH3")]
+    #[strum(
+        to_string = "Ammonia",
+        serialize = "NH3",
+        props(molar_mass = "0.01703052")
+    )]
     Ammonia,
 
-    #[strum(to_string = "Argon", serialize = "Ar")]
+    #[strum(to_string = "Argon", serialize = "Ar", props(molar_mass = "0.039948"))]
     Argon,
 
-    #[strum(to_string = "Benzene")]
+    #[strum(to_string = "Benzene", props(molar_mass = "0.07811180000000001"))]
     Benzene,
 
-    #[strum(to_string = "1-Butene", serialize = "1Butene", serialize = "Butene")]
+    #[strum(
+        to_string = "1-Butene",
+        serialize = "1Butene",
+        serialize = "Butene",
+        props(molar_mass = "0.05610631999999999")
+    )]
     Butene,
 
-    #[strum(to_string = "CarbonDioxide", serialize = "CO2")]
+    #[strum(
+        to_string = "CarbonDioxide",
+        serialize = "CO2",
+        props(molar_mass = "0.0440098")
+    )]
     CarbonDioxide,
 
-    #[strum(to_string = "CarbonMonoxide", serialize = "CO")]
+    #[strum(
+        to_string = "CarbonMonoxide",
+        serialize = "CO",
+        props(molar_mass = "0.0280101")
+    )]
     CarbonMonoxide,
 
-    #[strum(to_string = "CarbonylSulfide", serialize = "COS")]
+    #[strum(
+        to_string = "CarbonylSulfide",
+        serialize = "COS",
+        props(molar_mass = "0.0600751")
+    )]
     CarbonylSulfide,
 
-    #[strum(to_string = "cis-2-Butene", serialize = "C2BUTENE")]
+    #[strum(
+        to_string = "cis-2-Butene",
+        serialize = "C2BUTENE",
+        props(molar_mass = "0.05610631999999999")
+    )]
     cis2Butene,
 
-    #[strum(to_string = "Cyclohexane", serialize = "CYCLOHEX")]
+    #[strum(
+        to_string = "Cyclohexane",
+        serialize = "CYCLOHEX",
+        props(molar_mass = "0.08415948000000001")
+    )]
     Cyclohexane,
 
-    #[strum(to_string = "Cyclopentane", serialize = "CYCLOPEN")]
+    #[strum(
+        to_string = "Cyclopentane",
+        serialize = "CYCLOPEN",
+        props(molar_mass = "0.07013290000000001")
+    )]
     Cyclopentane,
 
-    #[strum(to_string = "Cyclopropane", serialize = "CYCLOPRO")]
+    #[strum(
+        to_string = "Cyclopropane",
+        serialize = "CYCLOPRO",
+        props(molar_mass = "0.042081")
+    )]
     Cyclopropane,
 
-    #[strum(to_string = "D4", serialize = "Octamethylcyclotetrasiloxane")]
+    #[strum(
+        to_string = "D4",
+        serialize = "Octamethylcyclotetrasiloxane",
+        props(molar_mass = "0.29661576")
+    )]
     D4,
 
-    #[strum(to_string = "D5", serialize = "Decamethylcyclopentasiloxane")]
+    #[strum(
+        to_string = "D5",
+        serialize = "Decamethylcyclopentasiloxane",
+        props(molar_mass = "0.3707697")
+    )]
     D5,
 
-    #[strum(to_string = "D6", serialize = "Dodecamethylcyclohexasiloxane")]
+    #[strum(
+        to_string = "D6",
+        serialize = "Dodecamethylcyclohexasiloxane",
+        props(molar_mass = "0.444924")
+    )]
     D6,
 
-    #[strum(to_string = "Deuterium", serialize = "D2")]
+    #[strum(
+        to_string = "Deuterium",
+        serialize = "D2",
+        props(molar_mass = "0.0040282")
+    )]
     Deuterium,
 
-    #[strum(to_string = "Dichloroethane", serialize = "1,2-dichloroethane")]
+    #[strum(
+        to_string = "Dichloroethane",
+        serialize = "1,2-dichloroethane",
+        props(molar_mass = "0.098959")
+    )]
     Dichloroethane,
 
-    #[strum(to_string = "DiethylEther", serialize = "DEE")]
+    #[strum(
+        to_string = "DiethylEther",
+        serialize = "DEE",
+        props(molar_mass = "0.0741216")
+    )]
     DiethylEther,
 
-    #[strum(to_string = "DimethylCarbonate", serialize = "DMC")]
+    #[strum(
+        to_string = "DimethylCarbonate",
+        serialize = "DMC",
+        props(molar_mass = "0.0900779")
+    )]
     DimethylCarbonate,
 
-    #[strum(to_string = "DimethylEther", serialize = "DME")]
+    #[strum(
+        to_string = "DimethylEther",
+        serialize = "DME",
+        props(molar_mass = "0.04606844")
+    )]
     DimethylEther,
 
-    #[strum(to_string = "Ethane", serialize = "n-C2H6")]
+    #[strum(
+        to_string = "Ethane",
+        serialize = "n-C2H6",
+        props(molar_mass = "0.03006904")
+    )]
     Ethane,
 
-    #[strum(to_string = "Ethanol", serialize = "C2H6O")]
+    #[strum(
+        to_string = "Ethanol",
+        serialize = "C2H6O",
+        props(molar_mass = "0.04606844")
+    )]
     Ethanol,
 
-    #[strum(to_string = "EthylBenzene", serialize = "EBENZENE")]
+    #[strum(
+        to_string = "EthylBenzene",
+        serialize = "EBENZENE",
+        props(molar_mass = "0.106165")
+    )]
     EthylBenzene,
 
-    #[strum(to_string = "Ethylene")]
+    #[strum(to_string = "Ethylene", props(molar_mass = "0.02805376"))]
     Ethylene,
 
-    #[strum(to_string = "EthyleneOxide")]
+    #[strum(to_string = "EthyleneOxide", props(molar_mass = "0.04405256"))]
     EthyleneOxide,
 
-    #[strum(to_string = "Fluorine")]
+    #[strum(to_string = "Fluorine", props(molar_mass = "0.03799681000000001"))]
     Fluorine,
 
-    #[strum(to_string = "HeavyWater", serialize = "D2O")]
+    #[strum(
+        to_string = "HeavyWater",
+        serialize = "D2O",
+        props(molar_mass = "0.020027508")
+    )]
     HeavyWater,
 
-    #[strum(to_string = "Helium", serialize = "He")]
+    #[strum(
+        to_string = "Helium",
+        serialize = "He",
+        props(molar_mass = "0.004002602")
+    )]
     Helium,
 
-    #[strum(to_string = "HFE143m", serialize = "HFE-143m")]
+    #[strum(
+        to_string = "HFE143m",
+        serialize = "HFE-143m",
+        props(molar_mass = "0.10004")
+    )]
     HFE143m,
 
-    #[strum(to_string = "Hydrogen", serialize = "H2")]
+    #[strum(
+        to_string = "Hydrogen",
+        serialize = "H2",
+        props(molar_mass = "0.00201588")
+    )]
     Hydrogen,
 
-    #[strum(to_string = "HydrogenChloride", serialize = "HCl")]
+    #[strum(
+        to_string = "HydrogenChloride",
+        serialize = "HCl",
+        props(molar_mass = "0.0364609")
+    )]
     HydrogenChloride,
 
-    #[strum(to_string = "HydrogenSulfide", serialize = "H2S")]
+    #[strum(
+        to_string = "HydrogenSulfide",
+        serialize = "H2S",
+        props(molar_mass = "0.03408088")
+    )]
     HydrogenSulfide,
 
-    #[strum(to_string = "Isobutane", serialize = "IBUTANE")]
+    #[strum(
+        to_string = "Isobutane",
+        serialize = "IBUTANE",
+        props(molar_mass = "0.0581222")
+    )]
     Isobutane,
 
-    #[strum(to_string = "Isobutene", serialize = "IBUTENE")]
+    #[strum(
+        to_string = "Isobutene",
+        serialize = "IBUTENE",
+        props(molar_mass = "0.05610631999999999")
+    )]
     Isobutene,
 
-    #[strum(to_string = "Isohexane", serialize = "IHEXANE")]
+    #[strum(
+        to_string = "Isohexane",
+        serialize = "IHEXANE",
+        props(molar_mass = "0.08617535999999999")
+    )]
     Isohexane,
 
-    #[strum(to_string = "Isopentane", serialize = "IPENTANE")]
+    #[strum(
+        to_string = "Isopentane",
+        serialize = "IPENTANE",
+        props(molar_mass = "0.07214878")
+    )]
     Isopentane,
 
-    #[strum(to_string = "Krypton")]
+    #[strum(to_string = "Krypton", props(molar_mass = "0.083798"))]
     Krypton,
 
-    #[strum(to_string = "MD2M", serialize = "Decamethyltetrasiloxane")]
+    #[strum(
+        to_string = "MD2M",
+        serialize = "Decamethyltetrasiloxane",
+        props(molar_mass = "0.3106854")
+    )]
     MD2M,
 
-    #[strum(to_string = "MD3M", serialize = "Dodecamethylpentasiloxane")]
+    #[strum(
+        to_string = "MD3M",
+        serialize = "Dodecamethylpentasiloxane",
+        props(molar_mass = "0.384839")
+    )]
     MD3M,
 
-    #[strum(to_string = "MD4M", serialize = "Tetradecamethylhexasiloxane")]
+    #[strum(
+        to_string = "MD4M",
+        serialize = "Tetradecamethylhexasiloxane",
+        props(molar_mass = "0.45899328")
+    )]
     MD4M,
 
-    #[strum(to_string = "MDM", serialize = "Octamethyltrisiloxane")]
+    #[strum(
+        to_string = "MDM",
+        serialize = "Octamethyltrisiloxane",
+        props(molar_mass = "0.23653146")
+    )]
     MDM,
 
-    #[strum(to_string = "Methane", serialize = "CH4", serialize = "n-C1H4")]
+    #[strum(
+        to_string = "Methane",
+        serialize = "CH4",
+        serialize = "n-C1H4",
+        props(molar_mass = "0.0160428")
+    )]
     Methane,
 
-    #[strum(to_string = "Methanol")]
+    #[strum(to_string = "Methanol", props(molar_mass = "0.03204216"))]
     Methanol,
 
-    #[strum(to_string = "MethylLinoleate", serialize = "MLINOLEA")]
+    #[strum(
+        to_string = "MethylLinoleate",
+        serialize = "MLINOLEA",
+        props(molar_mass = "0.29447206")
+    )]
     MethylLinoleate,
 
-    #[strum(to_string = "MethylLinolenate", serialize = "MLINOLEN")]
+    #[strum(
+        to_string = "MethylLinolenate",
+        serialize = "MLINOLEN",
+        props(molar_mass = "0.29245618")
+    )]
     MethylLinolenate,
 
-    #[strum(to_string = "MethylOleate", serialize = "MOLEATE")]
+    #[strum(
+        to_string = "MethylOleate",
+        serialize = "MOLEATE",
+        props(molar_mass = "0.29648794")
+    )]
     MethylOleate,
 
-    #[strum(to_string = "MethylPalmitate", serialize = "MPALMITA")]
+    #[strum(
+        to_string = "MethylPalmitate",
+        serialize = "MPALMITA",
+        props(molar_mass = "0.27045066")
+    )]
     MethylPalmitate,
 
-    #[strum(to_string = "MethylStearate", serialize = "MSTEARAT")]
+    #[strum(
+        to_string = "MethylStearate",
+        serialize = "MSTEARAT",
+        props(molar_mass = "0.29850382")
+    )]
     MethylStearate,
 
-    #[strum(to_string = "MM", serialize = "Hexamethyldisiloxane")]
+    #[strum(
+        to_string = "MM",
+        serialize = "Hexamethyldisiloxane",
+        props(molar_mass = "0.16237752")
+    )]
     MM,
 
-    #[strum(to_string = "m-Xylene", serialize = "mXylene", serialize = "MC8H10")]
+    #[strum(
+        to_string = "m-Xylene",
+        serialize = "mXylene",
+        serialize = "MC8H10",
+        props(molar_mass = "0.106165")
+    )]
     mXylene,
 
     #[strum(
@@ -184,7 +356,8 @@ pub enum Pure {
         serialize = "nButane",
         serialize = "Butane",
         serialize = "NC4H10",
-        serialize = "n-C4H10"
+        serialize = "n-C4H10",
+        props(molar_mass = "0.0581222")
     )]
     nButane,
 
@@ -193,7 +366,8 @@ pub enum Pure {
         serialize = "nDecane",
         serialize = "Decane",
         serialize = "NC10H22",
-        serialize = "n-C10H22"
+        serialize = "n-C10H22",
+        props(molar_mass = "0.14228168")
     )]
     nDecane,
 
@@ -202,14 +376,15 @@ pub enum Pure {
         serialize = "nDodecane",
         serialize = "Dodecane",
         serialize = "NC12H26",
-        serialize = "n-C12H26"
+        serialize = "n-C12H26",
+        props(molar_mass = "0.17033484")
     )]
     nDodecane,
 
-    #[strum(to_string = "Neon", serialize = "Ne")]
+    #[strum(to_string = "Neon", serialize = "Ne", props(molar_mass = "0.020179"))]
     Neon,
 
-    #[strum(to_string = "Neopentane")]
+    #[strum(to_string = "Neopentane", props(molar_mass = "0.07214878"))]
     Neopentane,
 
     #[strum(
@@ -217,7 +392,8 @@ pub enum Pure {
         serialize = "nHeptane",
         serialize = "Heptane",
         serialize = "NC7H16",
-        serialize = "n-C7H16"
+        serialize = "n-C7H16",
+        props(molar_mass = "0.100202")
     )]
     nHeptane,
 
@@ -226,14 +402,23 @@ pub enum Pure {
         serialize = "nHexane",
         serialize = "Hexane",
         serialize = "NC6H14",
-        serialize = "n-C6H14"
+        serialize = "n-C6H14",
+        props(molar_mass = "0.08617535999999999")
     )]
     nHexane,
 
-    #[strum(to_string = "Nitrogen", serialize = "N2")]
+    #[strum(
+        to_string = "Nitrogen",
+        serialize = "N2",
+        props(molar_mass = "0.02801348")
+    )]
     Nitrogen,
 
-    #[strum(to_string = "NitrousOxide", serialize = "N2O")]
+    #[strum(
+        to_string = "NitrousOxide",
+        serialize = "N2O",
+        props(molar_mass = "0.0440128")
+    )]
     NitrousOxide,
 
     #[strum(
@@ -241,7 +426,8 @@ pub enum Pure {
         serialize = "nNonane",
         serialize = "Nonane",
         serialize = "NC9H20",
-        serialize = "n-C9H20"
+        serialize = "n-C9H20",
+        props(molar_mass = "0.1282551")
     )]
     nNonane,
 
@@ -250,11 +436,16 @@ pub enum Pure {
         serialize = "nOctane",
         serialize = "Octane",
         serialize = "NC8H18",
-        serialize = "n-C8H18"
+        serialize = "n-C8H18",
+        props(molar_mass = "0.114229")
     )]
     nOctane,
 
-    #[strum(to_string = "Novec649", serialize = "Novec1230")]
+    #[strum(
+        to_string = "Novec649",
+        serialize = "Novec1230",
+        props(molar_mass = "0.3160438")
+    )]
     Novec649,
 
     #[strum(
@@ -262,7 +453,8 @@ pub enum Pure {
         serialize = "nPentane",
         serialize = "Pentane",
         serialize = "NC5H12",
-        serialize = "n-C5H12"
+        serialize = "n-C5H12",
+        props(molar_mass = "0.07214878")
     )]
     nPentane,
 
@@ -272,7 +464,8 @@ pub enum Pure {
         serialize = "Propane",
         serialize = "C3H8",
         serialize = "NC3H8",
-        serialize = "n-C3H8"
+        serialize = "n-C3H8",
+        props(molar_mass = "0.04409562")
     )]
     nPropane,
 
@@ -281,73 +474,187 @@ pub enum Pure {
         serialize = "nUndecane",
         serialize = "Undecane",
         serialize = "NC11H24",
-        serialize = "n-C11H24"
+        serialize = "n-C11H24",
+        props(molar_mass = "0.15630826")
     )]
     nUndecane,
 
-    #[strum(to_string = "OrthoDeuterium", serialize = "o-D2")]
+    #[strum(
+        to_string = "OrthoDeuterium",
+        serialize = "o-D2",
+        props(molar_mass = "0.0040282")
+    )]
     Orthodeuterium,
 
-    #[strum(to_string = "OrthoHydrogen", serialize = "o-H2")]
+    #[strum(
+        to_string = "OrthoHydrogen",
+        serialize = "o-H2",
+        props(molar_mass = "0.00201594")
+    )]
     Orthohydrogen,
 
-    #[strum(to_string = "Oxygen", serialize = "O2")]
+    #[strum(
+        to_string = "Oxygen",
+        serialize = "O2",
+        props(molar_mass = "0.0319988")
+    )]
     Oxygen,
 
-    #[strum(to_string = "o-Xylene", serialize = "oXylene", serialize = "OC8H10")]
+    #[strum(
+        to_string = "o-Xylene",
+        serialize = "oXylene",
+        serialize = "OC8H10",
+        props(molar_mass = "0.106165")
+    )]
     oXylene,
 
-    #[strum(to_string = "ParaDeuterium", serialize = "p-D2")]
+    #[strum(
+        to_string = "ParaDeuterium",
+        serialize = "p-D2",
+        props(molar_mass = "0.0040282")
+    )]
     Paradeuterium,
 
-    #[strum(to_string = "ParaHydrogen", serialize = "p-H2")]
+    #[strum(
+        to_string = "ParaHydrogen",
+        serialize = "p-H2",
+        props(molar_mass = "0.00201588")
+    )]
     Parahydrogen,
 
-    #[strum(to_string = "Propylene")]
+    #[strum(to_string = "Propylene", props(molar_mass = "0.04207974"))]
     Propylene,
 
-    #[strum(to_string = "Propyne")]
+    #[strum(to_string = "Propyne", props(molar_mass = "0.04006000000000001"))]
     Propyne,
 
-    #[strum(to_string = "p-Xylene", serialize = "pXylene", serialize = "PC8H10")]
+    #[strum(
+        to_string = "p-Xylene",
+        serialize = "pXylene",
+        serialize = "PC8H10",
+        props(molar_mass = "0.106165")
+    )]
     pXylene,
 
-    #[strum(to_string = "SES36")]
+    #[strum(to_string = "SES36", props(molar_mass = "0.18485"))]
     SES36,
 
-    #[strum(to_string = "SulfurDioxide", serialize = "SO2")]
+    #[strum(
+        to_string = "SulfurDioxide",
+        serialize = "SO2",
+        props(molar_mass = "0.0640638")
+    )]
     SulfurDioxide,
 
-    #[strum(to_string = "SulfurHexafluoride", serialize = "SF6")]
+    #[strum(
+        to_string = "SulfurHexafluoride",
+        serialize = "SF6",
+        props(molar_mass = "0.1460554192")
+    )]
     SulfurHexafluoride,
 
-    #[strum(to_string = "Toluene")]
+    #[strum(to_string = "Toluene", props(molar_mass = "0.09213842"))]
     Toluene,
 
-    #[strum(to_string = "trans-2-Butene", serialize = "T2BUTENE")]
+    #[strum(
+        to_string = "trans-2-Butene",
+        serialize = "T2BUTENE",
+        props(molar_mass = "0.05610631999999999")
+    )]
     trans2Butene,
 
-    #[strum(to_string = "Water", serialize = "H2O")]
+    #[strum(
+        to_string = "Water",
+        serialize = "H2O",
+        props(molar_mass = "0.018015268")
+    )]
     Water,
 
-    #[strum(to_string = "Xenon", serialize = "Xe")]
+    #[strum(to_string = "Xenon", serialize = "Xe", props(molar_mass = "0.131293"))]
     Xenon,
 }
 
+impl Pure {
+    /// Molar mass _(the same as in CoolProp, but without any native calls)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::substance::Pure;
+    /// use rfluids::uom::si::molar_mass::gram_per_mole;
+    ///
+    /// assert_relative_eq!(Pure::Water.molar_mass().get::<gram_per_mole>(), 18.015268);
+    /// ```
+    pub fn molar_mass(&self) -> MolarMass {
+        MolarMass::new::<kilogram_per_mole>(
+            f64::from_str(self.get_str("molar_mass").unwrap()).unwrap(),
+        )
+    }
+
+    /// All pure substances sorted by molar mass in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::Pure;
+    ///
+    /// let result = Pure::sorted_by_molar_mass().collect::<Vec<_>>();
+    /// assert_eq!(result.first(), Some(&Pure::Hydrogen));
+    /// assert!(result.windows(2).all(|w| w[0].molar_mass() <= w[1].molar_mass()));
+    /// ```
+    pub fn sorted_by_molar_mass() -> impl Iterator<Item = Pure> {
+        let mut substances = Self::iter().collect::<Vec<_>>();
+        substances.sort_by(|a, b| a.molar_mass().value.total_cmp(&b.molar_mass().value));
+        substances.into_iter()
+    }
+}
+
 impl BackendName for Pure {
     fn backend_name(&self) -> &'static str {
         "HEOS"
     }
 }
 
+#[cfg(feature = "serde")]
+serde_via_str!(Pure);
+
 #[cfg(test)]
 mod tests {
     use super::Pure::*;
     use super::*;
+    use crate::io::FluidTrivialParam;
+    use crate::native::AbstractState;
+    use approx::assert_relative_eq;
     use rstest::*;
     use std::str::FromStr;
     use strum::IntoEnumIterator;
 
+    #[test]
+    fn molar_mass_matches_coolprop() {
+        for substance in Pure::iter() {
+            let expected = AbstractState::new(substance.backend_name(), substance.as_ref())
+                .unwrap()
+                .keyed_output(FluidTrivialParam::MolarMass)
+                .unwrap();
+            assert_relative_eq!(
+                substance.molar_mass().get::<kilogram_per_mole>(),
+                expected,
+                max_relative = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn sorted_by_molar_mass_returns_all_substances_in_ascending_order() {
+        let result = Pure::sorted_by_molar_mass().collect::<Vec<_>>();
+        assert_eq!(result.len(), Pure::iter().count());
+        assert!(result
+            .windows(2)
+            .all(|w| w[0].molar_mass() <= w[1].molar_mass()));
+        assert_eq!(result[0], Hydrogen);
+    }
+
     #[test]
     fn backend_name_returns_heos() {
         for substance in Pure::iter() {
@@ -1,8 +1,10 @@
 use crate::substance::BackendName;
+use crate::uom::si::f64::Ratio;
 use regex::Regex;
-#[cfg(test)]
-use strum_macros::EnumIter;
-use strum_macros::{AsRefStr, EnumString};
+use std::marker::PhantomData;
+use std::str::FromStr;
+use strum::{EnumProperty, IntoEnumIterator};
+use strum_macros::{AsRefStr, EnumIter, EnumProperty, EnumString};
 
 /// CoolProp refrigerants.
 ///
@@ -24,408 +26,762 @@ use strum_macros::{AsRefStr, EnumString};
 /// - [Pure and pseudo-pure substances](https://coolprop.github.io/CoolProp/fluid_properties/PurePseudoPure.html)
 /// - [List of REFPROP-only refrigerants which are not available in CoolProp yet](https://github.com/portyanikhin/rfluids/blob/main/rfluids/src/substance/refprop_refrigerants.txt)
 //noinspection SpellCheckingInspection
-#[derive(AsRefStr, EnumString, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(AsRefStr, EnumString, EnumProperty, EnumIter, Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[strum(ascii_case_insensitive)]
-#[cfg_attr(test, derive(EnumIter))]
 pub enum Refrigerant {
-    #[strum(to_string = "R11")]
+    #[strum(
+        to_string = "R11",
+        props(gwp = "4750", odp = "1.0", safety_class = "A1")
+    )]
     R11,
 
-    #[strum(to_string = "R12")]
+    #[strum(
+        to_string = "R12",
+        props(gwp = "10900", odp = "1.0", safety_class = "A1")
+    )]
     R12,
 
-    #[strum(to_string = "R13")]
+    #[strum(
+        to_string = "R13",
+        props(gwp = "14400", odp = "1.0", safety_class = "A1")
+    )]
     R13,
 
-    #[strum(to_string = "R13I1", serialize = "CF3I")]
+    #[strum(
+        to_string = "R13I1",
+        serialize = "CF3I",
+        props(gwp = "0.4", safety_class = "A1")
+    )]
     R13I1,
 
-    #[strum(to_string = "R14")]
+    #[strum(to_string = "R14", props(gwp = "7390", odp = "0", safety_class = "A1"))]
     R14,
 
-    #[strum(to_string = "R21")]
+    #[strum(
+        to_string = "R21",
+        props(gwp = "151", odp = "0.04", safety_class = "B1")
+    )]
     R21,
 
-    #[strum(to_string = "R22")]
+    #[strum(
+        to_string = "R22",
+        props(gwp = "1810", odp = "0.055", safety_class = "A1")
+    )]
     R22,
 
-    #[strum(to_string = "R23")]
+    #[strum(
+        to_string = "R23",
+        props(gwp = "14800", odp = "0", safety_class = "A1")
+    )]
     R23,
 
-    #[strum(to_string = "R32")]
+    #[strum(to_string = "R32", props(gwp = "675", odp = "0", safety_class = "A2L"))]
     R32,
 
-    #[strum(to_string = "R40")]
+    #[strum(
+        to_string = "R40",
+        props(gwp = "13", odp = "0.02", safety_class = "B2")
+    )]
     R40,
 
-    #[strum(to_string = "R41")]
+    #[strum(to_string = "R41", props(gwp = "92", odp = "0"))]
     R41,
 
-    #[strum(to_string = "R50")]
+    #[strum(to_string = "R50", props(gwp = "25", odp = "0", safety_class = "A3"))]
     R50,
 
-    #[strum(to_string = "R113")]
+    #[strum(
+        to_string = "R113",
+        props(gwp = "6130", odp = "0.8", safety_class = "A1")
+    )]
     R113,
 
-    #[strum(to_string = "R114")]
+    #[strum(
+        to_string = "R114",
+        props(gwp = "10000", odp = "1.0", safety_class = "A1")
+    )]
     R114,
 
-    #[strum(to_string = "R115")]
+    #[strum(
+        to_string = "R115",
+        props(gwp = "7370", odp = "0.6", safety_class = "A1")
+    )]
     R115,
 
-    #[strum(to_string = "R116")]
+    #[strum(
+        to_string = "R116",
+        props(gwp = "12200", odp = "0", safety_class = "A1")
+    )]
     R116,
 
-    #[strum(to_string = "R123")]
+    #[strum(
+        to_string = "R123",
+        props(gwp = "77", odp = "0.02", safety_class = "B1")
+    )]
     R123,
 
-    #[strum(to_string = "R124")]
+    #[strum(
+        to_string = "R124",
+        props(gwp = "609", odp = "0.022", safety_class = "A1")
+    )]
     R124,
 
-    #[strum(to_string = "R125")]
+    #[strum(
+        to_string = "R125",
+        props(gwp = "3500", odp = "0", safety_class = "A1")
+    )]
     R125,
 
-    #[strum(to_string = "R134a")]
+    #[strum(
+        to_string = "R134a",
+        props(gwp = "1430", odp = "0", safety_class = "A1")
+    )]
     R134a,
 
-    #[strum(to_string = "R141b")]
+    #[strum(to_string = "R141b", props(gwp = "725", odp = "0.11"))]
     R141b,
 
-    #[strum(to_string = "R142b")]
+    #[strum(
+        to_string = "R142b",
+        props(gwp = "2310", odp = "0.065", safety_class = "A2")
+    )]
     R142b,
 
-    #[strum(to_string = "R143a")]
+    #[strum(
+        to_string = "R143a",
+        props(gwp = "4470", odp = "0", safety_class = "A2L")
+    )]
     R143a,
 
-    #[strum(to_string = "RE143a")]
+    #[strum(to_string = "RE143a", props(gwp = "756", odp = "0"))]
     RE143a,
 
-    #[strum(to_string = "R152a")]
+    #[strum(
+        to_string = "R152a",
+        props(gwp = "124", odp = "0", safety_class = "A2")
+    )]
     R152a,
 
     #[strum(to_string = "R161")]
     R161,
 
-    #[strum(to_string = "R170")]
+    #[strum(to_string = "R170", props(gwp = "6", odp = "0", safety_class = "A3"))]
     R170,
 
-    #[strum(to_string = "R218")]
+    #[strum(
+        to_string = "DimethylEther",
+        serialize = "RE170",
+        props(gwp = "1", odp = "0", safety_class = "A3")
+    )]
+    RE170,
+
+    #[strum(
+        to_string = "R218",
+        props(gwp = "8830", odp = "0", safety_class = "A1")
+    )]
     R218,
 
-    #[strum(to_string = "R227ea")]
+    #[strum(
+        to_string = "R227ea",
+        props(gwp = "3220", odp = "0", safety_class = "A1")
+    )]
     R227ea,
 
-    #[strum(to_string = "R236ea")]
+    #[strum(to_string = "R236ea", props(odp = "0"))]
     R236ea,
 
-    #[strum(to_string = "R236fa")]
+    #[strum(
+        to_string = "R236fa",
+        props(gwp = "9810", odp = "0", safety_class = "A1")
+    )]
     R236fa,
 
-    #[strum(to_string = "R245ca")]
+    #[strum(to_string = "R245ca", props(odp = "0"))]
     R245ca,
 
-    #[strum(to_string = "R245fa")]
+    #[strum(
+        to_string = "R245fa",
+        props(gwp = "1030", odp = "0", safety_class = "B1")
+    )]
     R245fa,
 
-    #[strum(to_string = "R290")]
+    #[strum(to_string = "R290", props(gwp = "3", odp = "0", safety_class = "A3"))]
     R290,
 
-    #[strum(to_string = "RC318")]
+    #[strum(
+        to_string = "RC318",
+        props(gwp = "10300", odp = "0", safety_class = "A1")
+    )]
     RC318,
 
-    #[strum(to_string = "R365mfc")]
+    #[strum(to_string = "R365mfc", props(gwp = "794", odp = "0"))]
     R365mfc,
 
-    #[strum(to_string = "R404A")]
+    #[strum(
+        to_string = "R404A",
+        props(gwp = "3922", odp = "0", safety_class = "A1")
+    )]
     R404A,
 
     #[strum(
         to_string = "R404A.mix",
         serialize = "R404AMix",
-        serialize = "R404A-mix"
+        serialize = "R404A-mix",
+        props(gwp = "3922", odp = "0", safety_class = "A1")
     )]
     R404AMix,
 
-    #[strum(to_string = "R407A.mix", serialize = "R407A")]
+    #[strum(
+        to_string = "R407A.mix",
+        serialize = "R407A",
+        props(gwp = "2107", odp = "0", safety_class = "A1")
+    )]
     R407A,
 
-    #[strum(to_string = "R407B.mix", serialize = "R407B")]
+    #[strum(
+        to_string = "R407B.mix",
+        serialize = "R407B",
+        props(gwp = "2804", odp = "0", safety_class = "A1")
+    )]
     R407B,
 
-    #[strum(to_string = "R407C")]
+    #[strum(
+        to_string = "R407C",
+        props(gwp = "1774", odp = "0", safety_class = "A1")
+    )]
     R407C,
 
     #[strum(
         to_string = "R407C.mix",
         serialize = "R407CMix",
-        serialize = "R407C-mix"
+        serialize = "R407C-mix",
+        props(gwp = "1774", odp = "0", safety_class = "A1")
     )]
     R407CMix,
 
-    #[strum(to_string = "R407D.mix", serialize = "R407D")]
+    #[strum(
+        to_string = "R407D.mix",
+        serialize = "R407D",
+        props(gwp = "1627", odp = "0", safety_class = "A1")
+    )]
     R407D,
 
-    #[strum(to_string = "R407E.mix", serialize = "R407E")]
+    #[strum(
+        to_string = "R407E.mix",
+        serialize = "R407E",
+        props(gwp = "1552", odp = "0", safety_class = "A1")
+    )]
     R407E,
 
-    #[strum(to_string = "R407F.mix", serialize = "R407F")]
+    #[strum(
+        to_string = "R407F.mix",
+        serialize = "R407F",
+        props(gwp = "1825", odp = "0", safety_class = "A1")
+    )]
     R407F,
 
-    #[strum(to_string = "R410A")]
+    #[strum(
+        to_string = "R410A",
+        props(gwp = "2088", odp = "0", safety_class = "A1")
+    )]
     R410A,
 
     #[strum(
         to_string = "R410A.mix",
         serialize = "R410AMix",
-        serialize = "R410A-mix"
+        serialize = "R410A-mix",
+        props(gwp = "2088", odp = "0", safety_class = "A1")
     )]
     R410AMix,
 
-    #[strum(to_string = "R410B.mix", serialize = "R410B")]
+    #[strum(
+        to_string = "R410B.mix",
+        serialize = "R410B",
+        props(gwp = "2229", odp = "0", safety_class = "A1")
+    )]
     R410B,
 
-    #[strum(to_string = "R411A.mix", serialize = "R411A")]
+    #[strum(
+        to_string = "R411A.mix",
+        serialize = "R411A",
+        props(safety_class = "A2")
+    )]
     R411A,
 
-    #[strum(to_string = "R411B.mix", serialize = "R411B")]
+    #[strum(
+        to_string = "R411B.mix",
+        serialize = "R411B",
+        props(safety_class = "A2")
+    )]
     R411B,
 
-    #[strum(to_string = "R415A.mix", serialize = "R415A")]
+    #[strum(
+        to_string = "R415A.mix",
+        serialize = "R415A",
+        props(safety_class = "A2")
+    )]
     R415A,
 
-    #[strum(to_string = "R415B.mix", serialize = "R415B")]
+    #[strum(
+        to_string = "R415B.mix",
+        serialize = "R415B",
+        props(safety_class = "A2")
+    )]
     R415B,
 
-    #[strum(to_string = "R417A.mix", serialize = "R417A")]
+    #[strum(
+        to_string = "R417A.mix",
+        serialize = "R417A",
+        props(gwp = "2346", odp = "0", safety_class = "A1")
+    )]
     R417A,
 
-    #[strum(to_string = "R417B.mix", serialize = "R417B")]
+    #[strum(
+        to_string = "R417B.mix",
+        serialize = "R417B",
+        props(gwp = "3027", odp = "0", safety_class = "A1")
+    )]
     R417B,
 
-    #[strum(to_string = "R417C.mix", serialize = "R417C")]
+    #[strum(
+        to_string = "R417C.mix",
+        serialize = "R417C",
+        props(odp = "0", safety_class = "A1")
+    )]
     R417C,
 
-    #[strum(to_string = "R419A.mix", serialize = "R419A")]
+    #[strum(
+        to_string = "R419A.mix",
+        serialize = "R419A",
+        props(gwp = "2967", odp = "0", safety_class = "A2")
+    )]
     R419A,
 
-    #[strum(to_string = "R419B.mix", serialize = "R419B")]
+    #[strum(
+        to_string = "R419B.mix",
+        serialize = "R419B",
+        props(odp = "0", safety_class = "A2")
+    )]
     R419B,
 
-    #[strum(to_string = "R420A.mix", serialize = "R420A")]
+    #[strum(
+        to_string = "R420A.mix",
+        serialize = "R420A",
+        props(gwp = "1258", odp = "0", safety_class = "A1")
+    )]
     R420A,
 
-    #[strum(to_string = "R421A.mix", serialize = "R421A")]
+    #[strum(
+        to_string = "R421A.mix",
+        serialize = "R421A",
+        props(gwp = "2631", odp = "0", safety_class = "A1")
+    )]
     R421A,
 
-    #[strum(to_string = "R421B.mix", serialize = "R421B")]
+    #[strum(
+        to_string = "R421B.mix",
+        serialize = "R421B",
+        props(gwp = "3190", odp = "0", safety_class = "A1")
+    )]
     R421B,
 
-    #[strum(to_string = "R422A.mix", serialize = "R422A")]
+    #[strum(
+        to_string = "R422A.mix",
+        serialize = "R422A",
+        props(gwp = "3143", odp = "0", safety_class = "A1")
+    )]
     R422A,
 
-    #[strum(to_string = "R422B.mix", serialize = "R422B")]
+    #[strum(
+        to_string = "R422B.mix",
+        serialize = "R422B",
+        props(gwp = "2526", odp = "0", safety_class = "A1")
+    )]
     R422B,
 
-    #[strum(to_string = "R422C.mix", serialize = "R422C")]
+    #[strum(
+        to_string = "R422C.mix",
+        serialize = "R422C",
+        props(gwp = "3085", odp = "0", safety_class = "A1")
+    )]
     R422C,
 
-    #[strum(to_string = "R422D.mix", serialize = "R422D")]
+    #[strum(
+        to_string = "R422D.mix",
+        serialize = "R422D",
+        props(gwp = "2729", odp = "0", safety_class = "A1")
+    )]
     R422D,
 
-    #[strum(to_string = "R422E.mix", serialize = "R422E")]
+    #[strum(
+        to_string = "R422E.mix",
+        serialize = "R422E",
+        props(odp = "0", safety_class = "A1")
+    )]
     R422E,
 
-    #[strum(to_string = "R423A.mix", serialize = "R423A")]
+    #[strum(
+        to_string = "R423A.mix",
+        serialize = "R423A",
+        props(gwp = "2280", odp = "0", safety_class = "A1")
+    )]
     R423A,
 
-    #[strum(to_string = "R425A.mix", serialize = "R425A")]
+    #[strum(
+        to_string = "R425A.mix",
+        serialize = "R425A",
+        props(gwp = "1505", odp = "0", safety_class = "A1")
+    )]
     R425A,
 
-    #[strum(to_string = "R427A.mix", serialize = "R427A")]
+    #[strum(
+        to_string = "R427A.mix",
+        serialize = "R427A",
+        props(gwp = "2138", odp = "0", safety_class = "A1")
+    )]
     R427A,
 
-    #[strum(to_string = "R428A.mix", serialize = "R428A")]
+    #[strum(
+        to_string = "R428A.mix",
+        serialize = "R428A",
+        props(gwp = "3607", odp = "0", safety_class = "A1")
+    )]
     R428A,
 
-    #[strum(to_string = "R430A.mix", serialize = "R430A")]
+    #[strum(
+        to_string = "R430A.mix",
+        serialize = "R430A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R430A,
 
-    #[strum(to_string = "R431A.mix", serialize = "R431A")]
+    #[strum(
+        to_string = "R431A.mix",
+        serialize = "R431A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R431A,
 
-    #[strum(to_string = "R432A.mix", serialize = "R432A")]
+    #[strum(
+        to_string = "R432A.mix",
+        serialize = "R432A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R432A,
 
-    #[strum(to_string = "R433A.mix", serialize = "R433A")]
+    #[strum(
+        to_string = "R433A.mix",
+        serialize = "R433A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R433A,
 
-    #[strum(to_string = "R433B.mix", serialize = "R433B")]
+    #[strum(
+        to_string = "R433B.mix",
+        serialize = "R433B",
+        props(odp = "0", safety_class = "A3")
+    )]
     R433B,
 
-    #[strum(to_string = "R433C.mix", serialize = "R433C")]
+    #[strum(
+        to_string = "R433C.mix",
+        serialize = "R433C",
+        props(odp = "0", safety_class = "A3")
+    )]
     R433C,
 
-    #[strum(to_string = "R434A.mix", serialize = "R434A")]
+    #[strum(
+        to_string = "R434A.mix",
+        serialize = "R434A",
+        props(gwp = "3245", odp = "0", safety_class = "A1")
+    )]
     R434A,
 
-    #[strum(to_string = "R436A.mix", serialize = "R436A")]
+    #[strum(
+        to_string = "R436A.mix",
+        serialize = "R436A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R436A,
 
-    #[strum(to_string = "R436B.mix", serialize = "R436B")]
+    #[strum(
+        to_string = "R436B.mix",
+        serialize = "R436B",
+        props(odp = "0", safety_class = "A3")
+    )]
     R436B,
 
-    #[strum(to_string = "R439A.mix", serialize = "R439A")]
+    #[strum(
+        to_string = "R439A.mix",
+        serialize = "R439A",
+        props(odp = "0", safety_class = "A2")
+    )]
     R439A,
 
-    #[strum(to_string = "R440A.mix", serialize = "R440A")]
+    #[strum(
+        to_string = "R440A.mix",
+        serialize = "R440A",
+        props(odp = "0", safety_class = "A2")
+    )]
     R440A,
 
-    #[strum(to_string = "R441A.mix", serialize = "R441A")]
+    #[strum(
+        to_string = "R441A.mix",
+        serialize = "R441A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R441A,
 
-    #[strum(to_string = "R442A.mix", serialize = "R442A")]
+    #[strum(
+        to_string = "R442A.mix",
+        serialize = "R442A",
+        props(gwp = "1888", odp = "0", safety_class = "A1")
+    )]
     R442A,
 
-    #[strum(to_string = "R443A.mix", serialize = "R443A")]
+    #[strum(
+        to_string = "R443A.mix",
+        serialize = "R443A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R443A,
 
-    #[strum(to_string = "R444A.mix", serialize = "R444A")]
+    #[strum(
+        to_string = "R444A.mix",
+        serialize = "R444A",
+        props(gwp = "92", odp = "0", safety_class = "A2L")
+    )]
     R444A,
 
-    #[strum(to_string = "R444B.mix", serialize = "R444B")]
+    #[strum(
+        to_string = "R444B.mix",
+        serialize = "R444B",
+        props(gwp = "296", odp = "0", safety_class = "A2L")
+    )]
     R444B,
 
-    #[strum(to_string = "R449A.mix", serialize = "R449A")]
+    #[strum(
+        to_string = "R449A.mix",
+        serialize = "R449A",
+        props(gwp = "1397", odp = "0", safety_class = "A1")
+    )]
     R449A,
 
-    #[strum(to_string = "R449B.mix", serialize = "R449B")]
+    #[strum(
+        to_string = "R449B.mix",
+        serialize = "R449B",
+        props(gwp = "1412", odp = "0", safety_class = "A1")
+    )]
     R449B,
 
-    #[strum(to_string = "R451A.mix", serialize = "R451A")]
+    #[strum(
+        to_string = "R451A.mix",
+        serialize = "R451A",
+        props(gwp = "149", odp = "0", safety_class = "A2L")
+    )]
     R451A,
 
-    #[strum(to_string = "R451B.mix", serialize = "R451B")]
+    #[strum(
+        to_string = "R451B.mix",
+        serialize = "R451B",
+        props(gwp = "164", odp = "0", safety_class = "A2L")
+    )]
     R451B,
 
-    #[strum(to_string = "R452A.mix", serialize = "R452A")]
+    #[strum(
+        to_string = "R452A.mix",
+        serialize = "R452A",
+        props(gwp = "2140", odp = "0", safety_class = "A1")
+    )]
     R452A,
 
-    #[strum(to_string = "R454A.mix", serialize = "R454A")]
+    #[strum(
+        to_string = "R454A.mix",
+        serialize = "R454A",
+        props(gwp = "239", odp = "0", safety_class = "A2L")
+    )]
     R454A,
 
-    #[strum(to_string = "R454B.mix", serialize = "R454B")]
+    #[strum(
+        to_string = "R454B.mix",
+        serialize = "R454B",
+        props(gwp = "466", odp = "0", safety_class = "A2L")
+    )]
     R454B,
 
-    #[strum(to_string = "R500.mix", serialize = "R500")]
+    #[strum(
+        to_string = "R500.mix",
+        serialize = "R500",
+        props(gwp = "8077", safety_class = "A1")
+    )]
     R500,
 
-    #[strum(to_string = "R501.mix", serialize = "R501")]
+    #[strum(to_string = "R501.mix", serialize = "R501", props(safety_class = "A1"))]
     R501,
 
-    #[strum(to_string = "R502.mix", serialize = "R502")]
+    #[strum(
+        to_string = "R502.mix",
+        serialize = "R502",
+        props(gwp = "4657", safety_class = "A1")
+    )]
     R502,
 
-    #[strum(to_string = "R503.mix", serialize = "R503")]
+    #[strum(
+        to_string = "R503.mix",
+        serialize = "R503",
+        props(gwp = "14560", safety_class = "A1")
+    )]
     R503,
 
-    #[strum(to_string = "R507A")]
+    #[strum(
+        to_string = "R507A",
+        props(gwp = "3985", odp = "0", safety_class = "A1")
+    )]
     R507A,
 
     #[strum(
         to_string = "R507A.mix",
         serialize = "R507AMix",
-        serialize = "R507A-mix"
+        serialize = "R507A-mix",
+        props(gwp = "3985", odp = "0", safety_class = "A1")
     )]
     R507AMix,
 
-    #[strum(to_string = "R508A.mix", serialize = "R508A")]
+    #[strum(
+        to_string = "R508A.mix",
+        serialize = "R508A",
+        props(gwp = "13214", odp = "0", safety_class = "A1")
+    )]
     R508A,
 
-    #[strum(to_string = "R508B.mix", serialize = "R508B")]
+    #[strum(
+        to_string = "R508B.mix",
+        serialize = "R508B",
+        props(gwp = "13396", odp = "0", safety_class = "A1")
+    )]
     R508B,
 
-    #[strum(to_string = "R509A.mix", serialize = "R509A")]
+    #[strum(
+        to_string = "R509A.mix",
+        serialize = "R509A",
+        props(safety_class = "A1")
+    )]
     R509A,
 
-    #[strum(to_string = "R510A.mix", serialize = "R510A")]
+    #[strum(
+        to_string = "R510A.mix",
+        serialize = "R510A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R510A,
 
-    #[strum(to_string = "R511A.mix", serialize = "R511A")]
+    #[strum(
+        to_string = "R511A.mix",
+        serialize = "R511A",
+        props(odp = "0", safety_class = "A3")
+    )]
     R511A,
 
-    #[strum(to_string = "R512A.mix", serialize = "R512A")]
+    #[strum(
+        to_string = "R512A.mix",
+        serialize = "R512A",
+        props(odp = "0", safety_class = "A2")
+    )]
     R512A,
 
-    #[strum(to_string = "R513A.mix", serialize = "R513A")]
+    #[strum(
+        to_string = "R513A.mix",
+        serialize = "R513A",
+        props(gwp = "631", odp = "0", safety_class = "A1")
+    )]
     R513A,
 
-    #[strum(to_string = "R600")]
+    #[strum(to_string = "R600", props(gwp = "4", odp = "0", safety_class = "A3"))]
     R600,
 
-    #[strum(to_string = "R600a")]
+    #[strum(to_string = "R600a", props(gwp = "3", odp = "0", safety_class = "A3"))]
     R600a,
 
-    #[strum(to_string = "R601")]
+    #[strum(to_string = "R601", props(gwp = "5", odp = "0", safety_class = "A3"))]
     R601,
 
-    #[strum(to_string = "R601a")]
+    #[strum(to_string = "R601a", props(gwp = "5", odp = "0", safety_class = "A3"))]
     R601a,
 
-    #[strum(to_string = "R702")]
+    #[strum(to_string = "R702", props(odp = "0", safety_class = "A3"))]
     R702,
 
-    #[strum(to_string = "R704")]
+    #[strum(to_string = "R704", props(gwp = "0", odp = "0", safety_class = "A1"))]
     R704,
 
-    #[strum(to_string = "R717")]
+    #[strum(to_string = "R717", props(gwp = "0", odp = "0", safety_class = "B2L"))]
     R717,
 
-    #[strum(to_string = "R718")]
+    #[strum(to_string = "R718", props(gwp = "0", odp = "0", safety_class = "A1"))]
     R718,
 
-    #[strum(to_string = "R720")]
+    #[strum(to_string = "R720", props(gwp = "0", odp = "0", safety_class = "A1"))]
     R720,
 
-    #[strum(to_string = "R728")]
+    #[strum(to_string = "R728", props(gwp = "0", odp = "0", safety_class = "A1"))]
     R728,
 
-    #[strum(to_string = "R729")]
+    #[strum(to_string = "R729", props(gwp = "0", odp = "0", safety_class = "A1"))]
     R729,
 
-    #[strum(to_string = "R732")]
+    #[strum(to_string = "R732", props(gwp = "0", odp = "0"))]
     R732,
 
-    #[strum(to_string = "R740")]
+    #[strum(to_string = "R740", props(gwp = "0", odp = "0", safety_class = "A1"))]
     R740,
 
-    #[strum(to_string = "R744")]
+    #[strum(to_string = "R744", props(gwp = "1", odp = "0", safety_class = "A1"))]
     R744,
 
-    #[strum(to_string = "SulfurDioxide", serialize = "R764")]
+    #[strum(
+        to_string = "SulfurDioxide",
+        serialize = "R764",
+        props(gwp = "0", odp = "0", safety_class = "B1")
+    )]
     R764,
 
-    #[strum(to_string = "SulfurHexafluoride", serialize = "R846")]
+    #[strum(
+        to_string = "SulfurHexafluoride",
+        serialize = "R846",
+        props(gwp = "22800", odp = "0")
+    )]
     R846,
 
-    #[strum(to_string = "R1150")]
+    #[strum(to_string = "R1150", props(gwp = "4", odp = "0", safety_class = "A3"))]
     R1150,
 
-    #[strum(to_string = "R1233zd(E)", serialize = "R1233zdE")]
+    #[strum(
+        to_string = "R1233zd(E)",
+        serialize = "R1233zdE",
+        props(gwp = "1", safety_class = "A1")
+    )]
     R1233zdE,
 
-    #[strum(to_string = "R1234yf")]
+    #[strum(
+        to_string = "R1234yf",
+        props(gwp = "4", odp = "0", safety_class = "A2L")
+    )]
     R1234yf,
 
-    #[strum(to_string = "R1234ze(E)", serialize = "R1234zeE")]
+    #[strum(
+        to_string = "R1234ze(E)",
+        serialize = "R1234zeE",
+        props(gwp = "7", odp = "0", safety_class = "A2L")
+    )]
     R1234zeE,
 
-    #[strum(to_string = "R1234ze(Z)", serialize = "R1234zeZ")]
+    #[strum(to_string = "R1234ze(Z)", serialize = "R1234zeZ", props(odp = "0"))]
     R1234zeZ,
 
-    #[strum(to_string = "R1243zf")]
+    #[strum(to_string = "R1243zf", props(odp = "0", safety_class = "A2"))]
     R1243zf,
 
-    #[strum(to_string = "R1270")]
+    #[strum(to_string = "R1270", props(gwp = "2", odp = "0", safety_class = "A3"))]
     R1270,
 }
 
@@ -450,6 +806,190 @@ impl Refrigerant {
             _ => RefrigerantCategory::Pure,
         }
     }
+
+    /// All pure refrigerants
+    /// _(i.e., with [`Pure`](RefrigerantCategory::Pure) category,
+    /// which can be used as [`CustomMix`](crate::substance::CustomMix) components)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{Refrigerant, RefrigerantCategory};
+    ///
+    /// assert!(Refrigerant::pure().any(|r| r == Refrigerant::R32));
+    /// assert!(Refrigerant::pure().all(|r| r.category() == RefrigerantCategory::Pure));
+    /// ```
+    pub fn pure() -> impl Iterator<Item = Refrigerant> {
+        Self::iter().filter(|r| r.category() == RefrigerantCategory::Pure)
+    }
+
+    /// All refrigerant blends
+    /// _(i.e., with [`ZeotropicMix`](RefrigerantCategory::ZeotropicMix)
+    /// or [`AzeotropicMix`](RefrigerantCategory::AzeotropicMix) category)_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{Refrigerant, RefrigerantCategory};
+    ///
+    /// assert!(Refrigerant::blends().any(|r| r == Refrigerant::R407C));
+    /// assert!(Refrigerant::blends().all(|r| r.category() != RefrigerantCategory::Pure));
+    /// ```
+    pub fn blends() -> impl Iterator<Item = Refrigerant> {
+        Self::iter().filter(|r| r.category() != RefrigerantCategory::Pure)
+    }
+
+    /// Components and their mass fractions
+    /// _(for blends, or an empty slice for pure refrigerants)_.
+    ///
+    /// Pseudo-pure blends _(e.g., [`R407C`](Refrigerant::R407C))_
+    /// have the same components as their mixture counterparts
+    /// _(e.g., [`R407CMix`](Refrigerant::R407CMix))_.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use rfluids::substance::Refrigerant;
+    /// use rfluids::uom::si::ratio::percent;
+    ///
+    /// let components = Refrigerant::R407C.components();
+    /// assert_eq!(components.len(), 3);
+    /// assert_eq!(components[0].0, Refrigerant::R32);
+    /// assert_relative_eq!(components[0].1.get::<percent>(), 23.0);
+    /// assert!(Refrigerant::R32.components().is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Predefined mixtures](https://coolprop.github.io/CoolProp/coolprop/HighLevelAPI.html#predefined-mixtures)
+    pub fn components(&self) -> &'static [(Refrigerant, Ratio)] {
+        match self {
+            Refrigerant::R404A | Refrigerant::R404AMix => R404A_COMPONENTS,
+            Refrigerant::R407A => R407A_COMPONENTS,
+            Refrigerant::R407B => R407B_COMPONENTS,
+            Refrigerant::R407C | Refrigerant::R407CMix => R407C_COMPONENTS,
+            Refrigerant::R407D => R407D_COMPONENTS,
+            Refrigerant::R407E => R407E_COMPONENTS,
+            Refrigerant::R407F => R407F_COMPONENTS,
+            Refrigerant::R410A | Refrigerant::R410AMix => R410A_COMPONENTS,
+            Refrigerant::R410B => R410B_COMPONENTS,
+            Refrigerant::R411A => R411A_COMPONENTS,
+            Refrigerant::R411B => R411B_COMPONENTS,
+            Refrigerant::R415A => R415A_COMPONENTS,
+            Refrigerant::R415B => R415B_COMPONENTS,
+            Refrigerant::R417A => R417A_COMPONENTS,
+            Refrigerant::R417B => R417B_COMPONENTS,
+            Refrigerant::R417C => R417C_COMPONENTS,
+            Refrigerant::R419A => R419A_COMPONENTS,
+            Refrigerant::R419B => R419B_COMPONENTS,
+            Refrigerant::R420A => R420A_COMPONENTS,
+            Refrigerant::R421A => R421A_COMPONENTS,
+            Refrigerant::R421B => R421B_COMPONENTS,
+            Refrigerant::R422A => R422A_COMPONENTS,
+            Refrigerant::R422B => R422B_COMPONENTS,
+            Refrigerant::R422C => R422C_COMPONENTS,
+            Refrigerant::R422D => R422D_COMPONENTS,
+            Refrigerant::R422E => R422E_COMPONENTS,
+            Refrigerant::R423A => R423A_COMPONENTS,
+            Refrigerant::R425A => R425A_COMPONENTS,
+            Refrigerant::R427A => R427A_COMPONENTS,
+            Refrigerant::R428A => R428A_COMPONENTS,
+            Refrigerant::R430A => R430A_COMPONENTS,
+            Refrigerant::R431A => R431A_COMPONENTS,
+            Refrigerant::R432A => R432A_COMPONENTS,
+            Refrigerant::R433A => R433A_COMPONENTS,
+            Refrigerant::R433B => R433B_COMPONENTS,
+            Refrigerant::R433C => R433C_COMPONENTS,
+            Refrigerant::R434A => R434A_COMPONENTS,
+            Refrigerant::R436A => R436A_COMPONENTS,
+            Refrigerant::R436B => R436B_COMPONENTS,
+            Refrigerant::R439A => R439A_COMPONENTS,
+            Refrigerant::R440A => R440A_COMPONENTS,
+            Refrigerant::R441A => R441A_COMPONENTS,
+            Refrigerant::R442A => R442A_COMPONENTS,
+            Refrigerant::R443A => R443A_COMPONENTS,
+            Refrigerant::R444A => R444A_COMPONENTS,
+            Refrigerant::R444B => R444B_COMPONENTS,
+            Refrigerant::R449A => R449A_COMPONENTS,
+            Refrigerant::R449B => R449B_COMPONENTS,
+            Refrigerant::R451A => R451A_COMPONENTS,
+            Refrigerant::R451B => R451B_COMPONENTS,
+            Refrigerant::R452A => R452A_COMPONENTS,
+            Refrigerant::R454A => R454A_COMPONENTS,
+            Refrigerant::R454B => R454B_COMPONENTS,
+            Refrigerant::R500 => R500_COMPONENTS,
+            Refrigerant::R501 => R501_COMPONENTS,
+            Refrigerant::R502 => R502_COMPONENTS,
+            Refrigerant::R503 => R503_COMPONENTS,
+            Refrigerant::R507A | Refrigerant::R507AMix => R507A_COMPONENTS,
+            Refrigerant::R508A => R508A_COMPONENTS,
+            Refrigerant::R508B => R508B_COMPONENTS,
+            Refrigerant::R509A => R509A_COMPONENTS,
+            Refrigerant::R510A => R510A_COMPONENTS,
+            Refrigerant::R511A => R511A_COMPONENTS,
+            Refrigerant::R512A => R512A_COMPONENTS,
+            Refrigerant::R513A => R513A_COMPONENTS,
+            _ => &[],
+        }
+    }
+
+    /// Global warming potential _(100-year time horizon, relative to CO₂)_
+    /// or [`None`] if there is no published value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::Refrigerant;
+    ///
+    /// assert_eq!(Refrigerant::R134a.global_warming_potential(), Some(1430.0));
+    /// assert_eq!(Refrigerant::R744.global_warming_potential(), Some(1.0));
+    /// assert_eq!(Refrigerant::R161.global_warming_potential(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [IPCC Fourth Assessment Report (AR4)](https://www.ipcc.ch/report/ar4/wg1/)
+    pub fn global_warming_potential(&self) -> Option<f64> {
+        self.get_str("gwp").map(|s| f64::from_str(s).unwrap())
+    }
+
+    /// Ozone depletion potential _(relative to R11)_
+    /// or [`None`] if there is no published value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::Refrigerant;
+    ///
+    /// assert_eq!(Refrigerant::R22.ozone_depletion_potential(), Some(0.055));
+    /// assert_eq!(Refrigerant::R32.ozone_depletion_potential(), Some(0.0));
+    /// assert_eq!(Refrigerant::R161.ozone_depletion_potential(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [Montreal Protocol](https://ozone.unep.org/treaties/montreal-protocol)
+    pub fn ozone_depletion_potential(&self) -> Option<f64> {
+        self.get_str("odp").map(|s| f64::from_str(s).unwrap())
+    }
+
+    /// ASHRAE 34 safety class
+    /// or [`None`] if the refrigerant is not classified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rfluids::substance::{Refrigerant, SafetyClass};
+    ///
+    /// assert_eq!(Refrigerant::R32.safety_class(), Some(SafetyClass::A2L));
+    /// assert_eq!(Refrigerant::R717.safety_class(), Some(SafetyClass::B2L));
+    /// assert_eq!(Refrigerant::R161.safety_class(), None);
+    /// ```
+    pub fn safety_class(&self) -> Option<SafetyClass> {
+        self.get_str("safety_class")
+            .map(|s| SafetyClass::from_str(s).unwrap())
+    }
 }
 
 impl BackendName for Refrigerant {
@@ -471,11 +1011,410 @@ pub enum RefrigerantCategory {
     AzeotropicMix,
 }
 
+/// ASHRAE 34 safety classes of [`Refrigerant`]s.
+///
+/// The letter denotes toxicity _(`A` -- lower, `B` -- higher)_,
+/// the number denotes flammability
+/// _(`1` -- no flame propagation, `2L` -- lower flammability,
+/// `2` -- flammable, `3` -- higher flammability)_.
+#[derive(AsRefStr, EnumString, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(test, derive(EnumIter))]
+pub enum SafetyClass {
+    A1,
+    A2L,
+    A2,
+    A3,
+    B1,
+    B2L,
+    B2,
+    B3,
+}
+
+const fn mass_fraction(value: f64) -> Ratio {
+    Ratio {
+        dimension: PhantomData,
+        units: PhantomData,
+        value,
+    }
+}
+
+const R404A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.44)),
+    (Refrigerant::R134a, mass_fraction(0.04)),
+    (Refrigerant::R143a, mass_fraction(0.52)),
+];
+
+const R407A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.2)),
+    (Refrigerant::R125, mass_fraction(0.4)),
+    (Refrigerant::R134a, mass_fraction(0.4)),
+];
+
+const R407B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.1)),
+    (Refrigerant::R125, mass_fraction(0.7)),
+    (Refrigerant::R134a, mass_fraction(0.2)),
+];
+
+const R407C_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.23)),
+    (Refrigerant::R125, mass_fraction(0.25)),
+    (Refrigerant::R134a, mass_fraction(0.52)),
+];
+
+const R407D_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.15)),
+    (Refrigerant::R125, mass_fraction(0.15)),
+    (Refrigerant::R134a, mass_fraction(0.7)),
+];
+
+const R407E_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.25)),
+    (Refrigerant::R125, mass_fraction(0.15)),
+    (Refrigerant::R134a, mass_fraction(0.6)),
+];
+
+const R407F_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.3)),
+    (Refrigerant::R125, mass_fraction(0.3)),
+    (Refrigerant::R134a, mass_fraction(0.4)),
+];
+
+const R410A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.5)),
+    (Refrigerant::R125, mass_fraction(0.5)),
+];
+
+const R410B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.45)),
+    (Refrigerant::R125, mass_fraction(0.55)),
+];
+
+const R411A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1270, mass_fraction(0.015)),
+    (Refrigerant::R22, mass_fraction(0.875)),
+    (Refrigerant::R152a, mass_fraction(0.11)),
+];
+
+const R411B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1270, mass_fraction(0.03)),
+    (Refrigerant::R22, mass_fraction(0.94)),
+    (Refrigerant::R152a, mass_fraction(0.03)),
+];
+
+const R415A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R22, mass_fraction(0.82)),
+    (Refrigerant::R152a, mass_fraction(0.18)),
+];
+
+const R415B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R22, mass_fraction(0.25)),
+    (Refrigerant::R152a, mass_fraction(0.75)),
+];
+
+const R417A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.466)),
+    (Refrigerant::R134a, mass_fraction(0.5)),
+    (Refrigerant::R600, mass_fraction(0.034)),
+];
+
+const R417B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.79)),
+    (Refrigerant::R134a, mass_fraction(0.183)),
+    (Refrigerant::R600, mass_fraction(0.027)),
+];
+
+const R417C_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.195)),
+    (Refrigerant::R134a, mass_fraction(0.788)),
+    (Refrigerant::R600, mass_fraction(0.017)),
+];
+
+const R419A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.77)),
+    (Refrigerant::R134a, mass_fraction(0.19)),
+    (Refrigerant::RE170, mass_fraction(0.04)),
+];
+
+const R419B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.485)),
+    (Refrigerant::R134a, mass_fraction(0.48)),
+    (Refrigerant::RE170, mass_fraction(0.035)),
+];
+
+const R420A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R134a, mass_fraction(0.88)),
+    (Refrigerant::R142b, mass_fraction(0.12)),
+];
+
+const R421A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.58)),
+    (Refrigerant::R134a, mass_fraction(0.42)),
+];
+
+const R421B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.85)),
+    (Refrigerant::R134a, mass_fraction(0.15)),
+];
+
+const R422A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.851)),
+    (Refrigerant::R134a, mass_fraction(0.115)),
+    (Refrigerant::R600a, mass_fraction(0.034)),
+];
+
+const R422B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.55)),
+    (Refrigerant::R134a, mass_fraction(0.42)),
+    (Refrigerant::R600a, mass_fraction(0.03)),
+];
+
+const R422C_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.82)),
+    (Refrigerant::R134a, mass_fraction(0.15)),
+    (Refrigerant::R600a, mass_fraction(0.03)),
+];
+
+const R422D_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.651)),
+    (Refrigerant::R134a, mass_fraction(0.315)),
+    (Refrigerant::R600a, mass_fraction(0.034)),
+];
+
+const R422E_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.58)),
+    (Refrigerant::R134a, mass_fraction(0.393)),
+    (Refrigerant::R600a, mass_fraction(0.027)),
+];
+
+const R423A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R134a, mass_fraction(0.525)),
+    (Refrigerant::R227ea, mass_fraction(0.475)),
+];
+
+const R425A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.185)),
+    (Refrigerant::R134a, mass_fraction(0.695)),
+    (Refrigerant::R227ea, mass_fraction(0.12)),
+];
+
+const R427A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.15)),
+    (Refrigerant::R125, mass_fraction(0.25)),
+    (Refrigerant::R143a, mass_fraction(0.1)),
+    (Refrigerant::R134a, mass_fraction(0.5)),
+];
+
+const R428A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.775)),
+    (Refrigerant::R143a, mass_fraction(0.2)),
+    (Refrigerant::R290, mass_fraction(0.006)),
+    (Refrigerant::R600a, mass_fraction(0.019)),
+];
+
+const R430A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R152a, mass_fraction(0.76)),
+    (Refrigerant::R600a, mass_fraction(0.24)),
+];
+
+const R431A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R290, mass_fraction(0.71)),
+    (Refrigerant::R152a, mass_fraction(0.29)),
+];
+
+const R432A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1270, mass_fraction(0.8)),
+    (Refrigerant::RE170, mass_fraction(0.2)),
+];
+
+const R433A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1270, mass_fraction(0.3)),
+    (Refrigerant::R290, mass_fraction(0.7)),
+];
+
+const R433B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1270, mass_fraction(0.05)),
+    (Refrigerant::R290, mass_fraction(0.95)),
+];
+
+const R433C_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1270, mass_fraction(0.25)),
+    (Refrigerant::R290, mass_fraction(0.75)),
+];
+
+const R434A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.632)),
+    (Refrigerant::R143a, mass_fraction(0.18)),
+    (Refrigerant::R134a, mass_fraction(0.16)),
+    (Refrigerant::R600a, mass_fraction(0.028)),
+];
+
+const R436A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R290, mass_fraction(0.56)),
+    (Refrigerant::R600a, mass_fraction(0.44)),
+];
+
+const R436B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R290, mass_fraction(0.52)),
+    (Refrigerant::R600a, mass_fraction(0.48)),
+];
+
+const R439A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.5)),
+    (Refrigerant::R125, mass_fraction(0.47)),
+    (Refrigerant::R600a, mass_fraction(0.03)),
+];
+
+const R440A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R290, mass_fraction(0.006)),
+    (Refrigerant::R134a, mass_fraction(0.016)),
+    (Refrigerant::R152a, mass_fraction(0.978)),
+];
+
+const R441A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R170, mass_fraction(0.031)),
+    (Refrigerant::R290, mass_fraction(0.548)),
+    (Refrigerant::R600a, mass_fraction(0.06)),
+    (Refrigerant::R600, mass_fraction(0.361)),
+];
+
+const R442A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.31)),
+    (Refrigerant::R125, mass_fraction(0.31)),
+    (Refrigerant::R134a, mass_fraction(0.3)),
+    (Refrigerant::R152a, mass_fraction(0.03)),
+    (Refrigerant::R227ea, mass_fraction(0.05)),
+];
+
+const R443A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1270, mass_fraction(0.55)),
+    (Refrigerant::R290, mass_fraction(0.4)),
+    (Refrigerant::R600a, mass_fraction(0.05)),
+];
+
+const R444A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.12)),
+    (Refrigerant::R152a, mass_fraction(0.05)),
+    (Refrigerant::R1234zeE, mass_fraction(0.83)),
+];
+
+const R444B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.415)),
+    (Refrigerant::R152a, mass_fraction(0.1)),
+    (Refrigerant::R1234zeE, mass_fraction(0.485)),
+];
+
+const R449A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.243)),
+    (Refrigerant::R125, mass_fraction(0.247)),
+    (Refrigerant::R1234yf, mass_fraction(0.253)),
+    (Refrigerant::R134a, mass_fraction(0.257)),
+];
+
+const R449B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.252)),
+    (Refrigerant::R125, mass_fraction(0.243)),
+    (Refrigerant::R1234yf, mass_fraction(0.232)),
+    (Refrigerant::R134a, mass_fraction(0.273)),
+];
+
+const R451A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1234yf, mass_fraction(0.898)),
+    (Refrigerant::R134a, mass_fraction(0.102)),
+];
+
+const R451B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1234yf, mass_fraction(0.888)),
+    (Refrigerant::R134a, mass_fraction(0.112)),
+];
+
+const R452A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.11)),
+    (Refrigerant::R125, mass_fraction(0.59)),
+    (Refrigerant::R1234yf, mass_fraction(0.3)),
+];
+
+const R454A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.35)),
+    (Refrigerant::R1234yf, mass_fraction(0.65)),
+];
+
+const R454B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R32, mass_fraction(0.689)),
+    (Refrigerant::R1234yf, mass_fraction(0.311)),
+];
+
+const R500_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R12, mass_fraction(0.738)),
+    (Refrigerant::R152a, mass_fraction(0.262)),
+];
+
+const R501_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R22, mass_fraction(0.75)),
+    (Refrigerant::R12, mass_fraction(0.25)),
+];
+
+const R502_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R22, mass_fraction(0.488)),
+    (Refrigerant::R115, mass_fraction(0.512)),
+];
+
+const R503_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R23, mass_fraction(0.401)),
+    (Refrigerant::R13, mass_fraction(0.599)),
+];
+
+const R507A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R125, mass_fraction(0.5)),
+    (Refrigerant::R143a, mass_fraction(0.5)),
+];
+
+const R508A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R23, mass_fraction(0.39)),
+    (Refrigerant::R116, mass_fraction(0.61)),
+];
+
+const R508B_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R23, mass_fraction(0.46)),
+    (Refrigerant::R116, mass_fraction(0.54)),
+];
+
+const R509A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R22, mass_fraction(0.44)),
+    (Refrigerant::R218, mass_fraction(0.56)),
+];
+
+const R510A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::RE170, mass_fraction(0.88)),
+    (Refrigerant::R600a, mass_fraction(0.12)),
+];
+
+const R511A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R290, mass_fraction(0.95)),
+    (Refrigerant::RE170, mass_fraction(0.05)),
+];
+
+const R512A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R134a, mass_fraction(0.05)),
+    (Refrigerant::R152a, mass_fraction(0.95)),
+];
+
+const R513A_COMPONENTS: &[(Refrigerant, Ratio)] = &[
+    (Refrigerant::R1234yf, mass_fraction(0.56)),
+    (Refrigerant::R134a, mass_fraction(0.44)),
+];
+
+#[cfg(feature = "serde")]
+serde_via_str!(Refrigerant);
+
 #[cfg(test)]
 mod tests {
     use super::Refrigerant::*;
     use super::RefrigerantCategory::*;
     use super::*;
+    use crate::fluid::Fluid;
+    use crate::io::FluidTrivialParam;
+    use approx::assert_relative_eq;
     use rstest::*;
     use std::str::FromStr;
     use strum::IntoEnumIterator;
@@ -508,6 +1447,7 @@ mod tests {
     #[case(R152a, Pure)]
     #[case(R161, Pure)]
     #[case(R170, Pure)]
+    #[case(RE170, Pure)]
     #[case(R218, Pure)]
     #[case(R227ea, Pure)]
     #[case(R236ea, Pure)]
@@ -616,6 +1556,107 @@ mod tests {
         assert_eq!(substance.category(), expected);
     }
 
+    #[test]
+    fn pure_returns_only_pure_refrigerants() {
+        let result = Refrigerant::pure().collect::<Vec<_>>();
+        assert!(result.iter().all(|r| r.category() == Pure));
+        assert!(result.contains(&R32));
+        assert!(!result.contains(&R407C));
+    }
+
+    #[test]
+    fn blends_returns_only_zeotropic_and_azeotropic_mixes() {
+        let result = Refrigerant::blends().collect::<Vec<_>>();
+        assert!(result.iter().all(|r| r.category() != Pure));
+        assert!(result.contains(&R407C));
+        assert!(result.contains(&R507A));
+        assert!(!result.contains(&R32));
+    }
+
+    #[test]
+    fn pure_and_blends_cover_all_refrigerants() {
+        assert_eq!(
+            Refrigerant::pure().count() + Refrigerant::blends().count(),
+            Refrigerant::iter().count()
+        );
+    }
+
+    #[rstest]
+    #[case(R11, Some(4750.0), Some(1.0), Some(SafetyClass::A1))]
+    #[case(R22, Some(1810.0), Some(0.055), Some(SafetyClass::A1))]
+    #[case(R32, Some(675.0), Some(0.0), Some(SafetyClass::A2L))]
+    #[case(R161, None, None, None)]
+    #[case(R290, Some(3.0), Some(0.0), Some(SafetyClass::A3))]
+    #[case(R410A, Some(2088.0), Some(0.0), Some(SafetyClass::A1))]
+    #[case(R410AMix, Some(2088.0), Some(0.0), Some(SafetyClass::A1))]
+    #[case(R502, Some(4657.0), None, Some(SafetyClass::A1))]
+    #[case(R717, Some(0.0), Some(0.0), Some(SafetyClass::B2L))]
+    #[case(R1234yf, Some(4.0), Some(0.0), Some(SafetyClass::A2L))]
+    fn metadata_returns_expected_values(
+        #[case] substance: Refrigerant,
+        #[case] gwp: Option<f64>,
+        #[case] odp: Option<f64>,
+        #[case] safety_class: Option<SafetyClass>,
+    ) {
+        assert_eq!(substance.global_warming_potential(), gwp);
+        assert_eq!(substance.ozone_depletion_potential(), odp);
+        assert_eq!(substance.safety_class(), safety_class);
+    }
+
+    #[test]
+    fn components_of_pure_refrigerants_are_empty() {
+        for substance in Refrigerant::pure() {
+            assert!(substance.components().is_empty());
+        }
+    }
+
+    #[test]
+    fn components_of_blends_are_pure_and_fractions_sum_to_one() {
+        for substance in Refrigerant::blends() {
+            let components = substance.components();
+            assert!(components.len() > 1);
+            assert!(components
+                .iter()
+                .all(|c| c.0.category() == RefrigerantCategory::Pure));
+            let sum: f64 = components.iter().map(|c| c.1.value).sum();
+            assert_relative_eq!(sum, 1.0, max_relative = 1e-9);
+        }
+    }
+
+    #[test]
+    fn components_of_blends_are_consistent_with_coolprop() {
+        let molar_mass = |substance: Refrigerant| {
+            Fluid::from(substance)
+                .trivial_output(FluidTrivialParam::MolarMass)
+                .unwrap()
+        };
+        for substance in Refrigerant::blends() {
+            let expected = 1.0
+                / substance
+                    .components()
+                    .iter()
+                    .map(|c| c.1.value / molar_mass(c.0))
+                    .sum::<f64>();
+            assert_relative_eq!(molar_mass(substance), expected, max_relative = 1e-6);
+        }
+    }
+
+    #[test]
+    fn metadata_of_each_refrigerant_does_not_panic() {
+        for substance in Refrigerant::iter() {
+            let _gwp = substance.global_warming_potential();
+            let _odp = substance.ozone_depletion_potential();
+            let _safety_class = substance.safety_class();
+        }
+    }
+
+    #[test]
+    fn safety_class_as_ref_round_trips() {
+        for class in SafetyClass::iter() {
+            assert_eq!(SafetyClass::from_str(class.as_ref()), Ok(class));
+        }
+    }
+
     #[test]
     fn backend_name_returns_heos() {
         for substance in Refrigerant::iter() {
@@ -652,6 +1693,7 @@ mod tests {
     #[case(R152a, "R152a")]
     #[case(R161, "R161")]
     #[case(R170, "R170")]
+    #[case(RE170, "DimethylEther")]
     #[case(R218, "R218")]
     #[case(R227ea, "R227ea")]
     #[case(R236ea, "R236ea")]
@@ -786,6 +1828,7 @@ mod tests {
     #[case(vec!["R152a"], R152a)]
     #[case(vec!["R161"], R161)]
     #[case(vec!["R170"], R170)]
+    #[case(vec!["RE170", "DimethylEther"], RE170)]
     #[case(vec!["R218"], R218)]
     #[case(vec!["R227ea"], R227ea)]
     #[case(vec!["R236ea"], R236ea)]
@@ -0,0 +1,8 @@
+// Compiler output (e.g., suggestions of similar names) differs between toolchains,
+// so the snapshots are checked only with the toolchain they were generated with.
+#[rustversion::attr(not(stable(1.95)), ignore = "snapshots are generated with Rust 1.95")]
+#[test]
+fn typestate() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,6 @@
+use rfluids::humid_air::HumidAir;
+
+fn main() {
+    let mut humid_air = HumidAir::new();
+    let _ = humid_air.relative_humidity();
+}
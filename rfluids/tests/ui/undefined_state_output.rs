@@ -0,0 +1,7 @@
+use rfluids::fluid::Fluid;
+use rfluids::substance::Pure;
+
+fn main() {
+    let mut water = Fluid::from(Pure::Water);
+    let _ = water.temperature();
+}